@@ -37,6 +37,9 @@ echo ""
 echo "사용법:"
 echo "  hoego                    # Daily Log 모드 (기본)"
 echo "  hoego --session \"세션명\"  # 세션과 함께 시작"
+echo "  hoego log \"내용\"         # 한 줄 바로 기록"
+echo "  hoego today | show | list | retro | feedback | stats | export"
+echo "  hoego --help             # 전체 명령과 옵션"
 echo "  :q                       # 종료"
 echo ""
 echo "💡 이제 어디서든 'hoego' 명령어를 사용할 수 있습니다!"
@@ -0,0 +1,11 @@
+// bin/hoego_cli.rs
+// 터미널용 hoego 명령 (설치 시 `hoego`로 복사됨)
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Err(error) = hoego::cli::run(&args) {
+        eprintln!("오류: {}", error);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,431 @@
+// cli/args.rs
+// Subcommand parsing for the hoego-cli binary
+
+use std::collections::{HashMap, HashSet};
+use time::macros::format_description;
+use time::{Date, Duration};
+
+use super::LogCliArgs;
+use crate::services::session_service::SessionMarker;
+
+/// How command results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colored output for terminals
+    Pretty,
+    /// No colors or decorations, for pipes and scripts
+    Plain,
+    /// Machine-readable JSON
+    Json,
+}
+
+/// Parsed subcommand
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliCommand {
+    /// Interactive daily log (`hoego`, `hoego cli`, `hoego --session "제목"`)
+    Tui(LogCliArgs),
+    /// Append entries; `None` reads one entry per line from stdin
+    Log {
+        text: Option<String>,
+    },
+    Today,
+    Show {
+        date: String,
+    },
+    List {
+        limit: Option<usize>,
+    },
+    Retro {
+        date: Option<String>,
+        edit: bool,
+    },
+    Feedback {
+        date: Option<String>,
+        limit: usize,
+        /// Generate new feedback instead of showing saved ones
+        generate: bool,
+    },
+    /// Write a session marker to today's dump (`title` only for start)
+    Session {
+        marker: SessionMarker,
+        title: Option<String>,
+    },
+    /// Sessions of a day with duration and entry count
+    Sessions {
+        date: Option<String>,
+    },
+    Stats {
+        preset: String,
+        date: Option<String>,
+        end_date: Option<String>,
+        week_start: String,
+    },
+    Export {
+        start: String,
+        end: Option<String>,
+        merge: bool,
+        output: Option<String>,
+    },
+    /// Serve the local daemon socket until Ctrl+C
+    Daemon,
+    /// Configured hooks and their recent runs
+    Hooks {
+        limit: Option<usize>,
+    },
+    /// MCP server for AI assistants over stdio
+    Mcp {
+        read_only: bool,
+        mask_pii: bool,
+    },
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliArgs {
+    pub command: CliCommand,
+    pub format: OutputFormat,
+}
+
+/// Options that take a value
+const VALUE_OPTIONS: &[&str] = &[
+    "--session",
+    "--date",
+    "--end",
+    "--week-start",
+    "--output",
+    "--limit",
+];
+
+/// Options without a value
+const FLAG_OPTIONS: &[&str] = &[
+    "--json",
+    "--plain",
+    "--help",
+    "--edit",
+    "--merge",
+    "--generate",
+    "--read-only",
+    "--mask-pii",
+];
+
+#[derive(Debug, Default)]
+struct RawArgs {
+    positionals: Vec<String>,
+    values: HashMap<&'static str, String>,
+    flags: HashSet<&'static str>,
+}
+
+fn canonical_option(arg: &str) -> &str {
+    match arg {
+        "-h" => "--help",
+        "-o" => "--output",
+        "-n" => "--limit",
+        "-s" => "--session",
+        other => other,
+    }
+}
+
+/// Separate positionals from options; everything after `--` is positional
+fn split_args(args: &[String]) -> Result<RawArgs, String> {
+    let mut raw = RawArgs::default();
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            raw.positionals.extend(iter.by_ref().cloned());
+            break;
+        }
+
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if arg.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let name = canonical_option(name);
+
+        if let Some(option) = VALUE_OPTIONS.iter().find(|o| **o == name) {
+            let value = match inline_value {
+                Some(value) => value,
+                None => iter
+                    .next()
+                    .cloned()
+                    .ok_or_else(|| format!("{} 옵션에 값이 필요합니다", option))?,
+            };
+            raw.values.insert(option, value);
+        } else if let Some(flag) = FLAG_OPTIONS.iter().find(|f| **f == name) {
+            raw.flags.insert(flag);
+        } else if arg.starts_with('-') && arg != "-" && arg.len() > 1 {
+            return Err(format!("알 수 없는 옵션: {}", arg));
+        } else {
+            raw.positionals.push(arg.clone());
+        }
+    }
+
+    Ok(raw)
+}
+
+/// Parse command-line arguments (without the program name)
+pub fn parse_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut raw = split_args(args)?;
+
+    let format = if raw.flags.contains("--json") {
+        OutputFormat::Json
+    } else if raw.flags.contains("--plain") {
+        OutputFormat::Plain
+    } else {
+        OutputFormat::Pretty
+    };
+
+    if raw.flags.contains("--help") {
+        return Ok(CliArgs {
+            command: CliCommand::Help,
+            format,
+        });
+    }
+
+    let subcommand = if raw.positionals.is_empty() {
+        None
+    } else {
+        Some(raw.positionals.remove(0))
+    };
+    let mut positionals = raw.positionals.into_iter();
+    let limit = raw
+        .values
+        .get("--limit")
+        .map(|value| {
+            value
+                .parse::<usize>()
+                .map_err(|_| format!("--limit 값이 올바르지 않습니다: {}", value))
+        })
+        .transpose()?;
+
+    let command = match subcommand.as_deref() {
+        None | Some("cli") => CliCommand::Tui(LogCliArgs {
+            session_title: raw.values.remove("--session"),
+            show_help: false,
+        }),
+        Some("log") => {
+            let words: Vec<String> = positionals.collect();
+            let text = words.join(" ");
+            CliCommand::Log {
+                text: (!text.trim().is_empty() && text != "-").then_some(text),
+            }
+        }
+        Some("today") => CliCommand::Today,
+        Some("show") => CliCommand::Show {
+            date: positionals
+                .next()
+                .ok_or("show 명령에는 날짜가 필요합니다 (예: hoego show 2024-01-02)")?,
+        },
+        Some("list") => CliCommand::List { limit },
+        Some("retro") => CliCommand::Retro {
+            date: positionals.next(),
+            edit: raw.flags.contains("--edit"),
+        },
+        Some("feedback") => CliCommand::Feedback {
+            date: positionals.next(),
+            limit: limit.unwrap_or(1),
+            generate: raw.flags.contains("--generate"),
+        },
+        Some("session") => {
+            let action = positionals
+                .next()
+                .ok_or("session 명령에는 start, pause, resume, end 중 하나가 필요합니다")?;
+            let marker = match action.as_str() {
+                "start" => SessionMarker::Start,
+                "pause" => SessionMarker::Pause,
+                "resume" => SessionMarker::Resume,
+                "end" | "stop" => SessionMarker::End,
+                other => return Err(format!("알 수 없는 session 동작: {} (start, pause, resume, end)", other)),
+            };
+            let title = positionals.collect::<Vec<_>>().join(" ");
+            if marker == SessionMarker::Start && title.trim().is_empty() {
+                return Err("session start에는 제목이 필요합니다 (예: hoego session start \"Phase 1 구현\")".to_string());
+            }
+            CliCommand::Session {
+                marker,
+                title: (!title.trim().is_empty()).then_some(title),
+            }
+        }
+        Some("sessions") => CliCommand::Sessions {
+            date: positionals.next(),
+        },
+        Some("stats") => CliCommand::Stats {
+            preset: positionals.next().unwrap_or_else(|| "week".to_string()),
+            date: raw.values.remove("--date"),
+            end_date: raw.values.remove("--end"),
+            week_start: raw
+                .values
+                .remove("--week-start")
+                .unwrap_or_else(|| "monday".to_string()),
+        },
+        Some("export") => CliCommand::Export {
+            start: positionals.next().ok_or(
+                "export 명령에는 시작 날짜가 필요합니다 (예: hoego export 2024-01-01 2024-01-07)",
+            )?,
+            end: positionals.next(),
+            merge: raw.flags.contains("--merge"),
+            output: raw.values.remove("--output"),
+        },
+        Some("daemon") => CliCommand::Daemon,
+        Some("hooks") => CliCommand::Hooks { limit },
+        Some("mcp") => CliCommand::Mcp {
+            read_only: raw.flags.contains("--read-only"),
+            mask_pii: raw.flags.contains("--mask-pii"),
+        },
+        Some("help") => CliCommand::Help,
+        Some(other) => return Err(format!("알 수 없는 명령: {} (hoego --help 참고)", other)),
+    };
+
+    Ok(CliArgs { command, format })
+}
+
+/// Resolve "today", "yesterday", "YYYY-MM-DD" or "YYYYMMDD" relative to `today`
+pub fn resolve_date(value: &str, today: Date) -> Result<Date, String> {
+    match value.trim() {
+        "today" | "오늘" => return Ok(today),
+        "yesterday" | "어제" => return Ok(today - Duration::days(1)),
+        _ => {}
+    }
+
+    Date::parse(value.trim(), &format_description!("[year]-[month]-[day]"))
+        .or_else(|_| Date::parse(value.trim(), &format_description!("[year][month][day]")))
+        .map_err(|_| format!("날짜 형식이 올바르지 않습니다: {} (YYYY-MM-DD)", value))
+}
+
+/// Usage text for `hoego --help`
+pub fn usage() -> &'static str {
+    "사용법: hoego [명령] [옵션]
+
+명령:
+  (없음), cli [--session 제목]   Daily Log TUI
+  log \"내용\"                     항목 추가 (내용이 없거나 '-'이면 stdin에서 한 줄씩)
+  today                          오늘 기록 보기
+  show <날짜>                    특정 날짜 기록 보기
+  list [--limit N]               기록 파일 목록
+  retro [날짜] [--edit]          회고 보기 ($EDITOR로 편집)
+  feedback [날짜] [--limit N]    저장된 AI 피드백 보기
+  feedback [날짜] --generate     AI 피드백 생성 (스트리밍 출력 후 저장)
+  session start <제목>           세션 시작 (열려 있던 세션은 종료)
+  session pause|resume|end       진행 중인 세션 일시정지 / 재개 / 종료
+  sessions [날짜]                세션별 시간과 항목 수
+  stats [week|month|year|custom] [--date 날짜] [--end 날짜] [--week-start monday]
+                                 기간 통계
+  export <시작> [끝] [--merge] [--output 경로]
+                                 시간 기록을 .ics 캘린더로 내보내기
+  daemon                         스크립트·에디터용 로컬 데몬 실행 (앱이 켜져 있으면 앱이 대신 실행)
+  hooks [--limit N]              이벤트 훅 목록과 최근 실행 기록 (종료 코드)
+  mcp [--read-only] [--mask-pii] AI 어시스턴트용 MCP 서버 (stdio)
+
+공통 옵션:
+  --json     JSON 출력
+  --plain    색상 없는 텍스트 출력 (파이프로 연결하면 자동)
+  -h, --help 도움말
+
+날짜: today, yesterday, YYYY-MM-DD, YYYYMMDD"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn parses_subcommands_and_global_flags() {
+        assert_eq!(
+            parse(&["--session", "Phase 1"]).unwrap().command,
+            CliCommand::Tui(LogCliArgs {
+                session_title: Some("Phase 1".to_string()),
+                show_help: false,
+            })
+        );
+
+        let log = parse(&["log", "PR", "리뷰", "--json"]).unwrap();
+        assert_eq!(log.format, OutputFormat::Json);
+        assert_eq!(
+            log.command,
+            CliCommand::Log {
+                text: Some("PR 리뷰".to_string())
+            }
+        );
+        assert_eq!(
+            parse(&["log", "-"]).unwrap().command,
+            CliCommand::Log { text: None }
+        );
+        assert_eq!(
+            parse(&["log", "--", "--force 옵션 정리"]).unwrap().command,
+            CliCommand::Log {
+                text: Some("--force 옵션 정리".to_string())
+            }
+        );
+
+        let stats = parse(&["stats", "month", "--date=2024-02-10", "--plain"]).unwrap();
+        assert_eq!(stats.format, OutputFormat::Plain);
+        assert_eq!(
+            stats.command,
+            CliCommand::Stats {
+                preset: "month".to_string(),
+                date: Some("2024-02-10".to_string()),
+                end_date: None,
+                week_start: "monday".to_string(),
+            }
+        );
+
+        assert_eq!(
+            parse(&["session", "start", "Phase", "1"]).unwrap().command,
+            CliCommand::Session {
+                marker: SessionMarker::Start,
+                title: Some("Phase 1".to_string()),
+            }
+        );
+        assert_eq!(
+            parse(&["session", "pause"]).unwrap().command,
+            CliCommand::Session {
+                marker: SessionMarker::Pause,
+                title: None,
+            }
+        );
+        assert!(parse(&["session", "start"]).is_err());
+        assert!(parse(&["session", "nap"]).is_err());
+
+        assert_eq!(parse(&["daemon"]).unwrap().command, CliCommand::Daemon);
+        assert_eq!(
+            parse(&["hooks", "-n", "5"]).unwrap().command,
+            CliCommand::Hooks { limit: Some(5) }
+        );
+        assert_eq!(
+            parse(&["mcp", "--read-only"]).unwrap().command,
+            CliCommand::Mcp {
+                read_only: true,
+                mask_pii: false,
+            }
+        );
+
+        assert!(parse(&["show"]).is_err());
+        assert!(parse(&["list", "--limit", "many"]).is_err());
+        assert!(parse(&["today", "--verbose"]).is_err());
+        assert_eq!(parse(&["stats", "-h"]).unwrap().command, CliCommand::Help);
+    }
+
+    #[test]
+    fn resolves_relative_and_absolute_dates() {
+        let today = date!(2024 - 03 - 01);
+        assert_eq!(
+            resolve_date("yesterday", today).unwrap(),
+            date!(2024 - 02 - 29)
+        );
+        assert_eq!(
+            resolve_date("2024-01-02", today).unwrap(),
+            date!(2024 - 01 - 02)
+        );
+        assert_eq!(
+            resolve_date("20240102", today).unwrap(),
+            date!(2024 - 01 - 02)
+        );
+        assert!(resolve_date("01/02", today).is_err());
+    }
+}
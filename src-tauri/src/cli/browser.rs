@@ -0,0 +1,378 @@
+// cli/browser.rs
+// TUI 기록 브라우저: 날짜 목록 / 선택한 날의 덤프 / AI 피드백·회고
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use time::macros::format_description;
+
+use super::args::resolve_date;
+use super::tui::format_log_line;
+use crate::models::dump::{HistoryFileInfo, HistoryState};
+use crate::services::{feedback_service, history_service, weekly_service};
+use crate::utils::{current_local_time, format_date_key, parse_date_key, short_weekday_label};
+
+/// 브라우저에서 어느 창을 스크롤하는지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserPane {
+    Dump,
+    Notes,
+}
+
+pub struct HistoryBrowser {
+    state: HistoryState,
+    pub days: Vec<HistoryFileInfo>,
+    pub selected: usize,
+    pub dump_lines: Vec<String>,
+    pub feedback: Option<String>,
+    pub retro: String,
+    pub focus: BrowserPane,
+    pub dump_scroll: u16,
+    pub notes_scroll: u16,
+    /// 날짜 이동 입력 중이면 Some (`g`)
+    pub jump_input: Option<String>,
+    pub status: Option<String>,
+}
+
+impl HistoryBrowser {
+    /// 기록 목록을 읽고 오늘 (없으면 가장 최근 날)을 선택합니다
+    pub fn open(state: HistoryState) -> Result<Self, String> {
+        let days = history_service::collect_history(&state)?.files;
+        let mut browser = Self {
+            state,
+            days,
+            selected: 0,
+            dump_lines: Vec::new(),
+            feedback: None,
+            retro: String::new(),
+            focus: BrowserPane::Dump,
+            dump_scroll: 0,
+            notes_scroll: 0,
+            jump_input: None,
+            status: None,
+        };
+
+        browser.jump_to_today();
+        Ok(browser)
+    }
+
+    pub fn selected_day(&self) -> Option<&HistoryFileInfo> {
+        self.days.get(self.selected)
+    }
+
+    /// `index`번째 날을 선택하고 세 창의 내용을 다시 읽습니다
+    pub fn select(&mut self, index: usize) {
+        if self.days.is_empty() {
+            self.status = Some("기록이 없습니다".to_string());
+            return;
+        }
+        self.selected = index.min(self.days.len() - 1);
+        self.dump_scroll = 0;
+        self.notes_scroll = 0;
+        if let Err(error) = self.load_selected() {
+            self.status = Some(error);
+        }
+    }
+
+    fn load_selected(&mut self) -> Result<(), String> {
+        let date_key = match self.selected_day() {
+            Some(day) => day.date.clone(),
+            None => return Ok(()),
+        };
+        let date = parse_date_key(&date_key)?;
+
+        self.dump_lines = weekly_service::load_daily_dump(&self.state, &date)?
+            .lines()
+            .map(str::to_string)
+            .collect();
+        self.feedback = feedback_service::list_summaries(&date_key, 1)?
+            .into_iter()
+            .next()
+            .map(|summary| summary.content);
+        self.retro = history_service::load_retrospect(&self.state, &date_key)?;
+        Ok(())
+    }
+
+    /// 목록은 최신순이므로 "이전 날"은 인덱스가 커지는 쪽입니다
+    pub fn previous_day(&mut self) {
+        if self.selected + 1 < self.days.len() {
+            self.select(self.selected + 1);
+        }
+    }
+
+    pub fn next_day(&mut self) {
+        if self.selected > 0 {
+            self.select(self.selected - 1);
+        }
+    }
+
+    pub fn scroll(&mut self, delta: i32) {
+        let offset = match self.focus {
+            BrowserPane::Dump => &mut self.dump_scroll,
+            BrowserPane::Notes => &mut self.notes_scroll,
+        };
+        *offset = (*offset as i32 + delta).clamp(0, u16::MAX as i32) as u16;
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            BrowserPane::Dump => BrowserPane::Notes,
+            BrowserPane::Notes => BrowserPane::Dump,
+        };
+    }
+
+    pub fn start_jump(&mut self) {
+        self.jump_input = Some(String::new());
+        self.status = None;
+    }
+
+    pub fn finish_jump(&mut self) {
+        if let Some(input) = self.jump_input.take() {
+            self.jump_to(&input);
+        }
+    }
+
+    /// 입력한 날짜로 이동합니다 (기록이 없으면 그 이전의 가장 가까운 날)
+    pub fn jump_to(&mut self, input: &str) {
+        if input.trim().is_empty() {
+            return;
+        }
+
+        let today = match current_local_time() {
+            Ok(now) => now.date(),
+            Err(error) => {
+                self.status = Some(error);
+                return;
+            }
+        };
+        let target = match resolve_date(input, today)
+            .and_then(|date| format_date_key(&weekly_service::local_midnight(date)))
+        {
+            Ok(key) => key,
+            Err(error) => {
+                self.status = Some(error);
+                return;
+            }
+        };
+
+        let keys: Vec<&str> = self.days.iter().map(|day| day.date.as_str()).collect();
+        match nearest_day_index(&keys, &target) {
+            Some(index) => {
+                if self.days[index].date != target {
+                    self.status = Some(format!(
+                        "{} 기록이 없어 {}로 이동했습니다",
+                        target, self.days[index].date
+                    ));
+                }
+                self.select(index);
+            }
+            None => self.status = Some(format!("{} 이전 기록이 없습니다", target)),
+        }
+    }
+
+    /// 오늘 파일을 고르거나 (없으면) 가장 최근 날로 이동합니다
+    pub fn jump_to_today(&mut self) {
+        let today = current_local_time()
+            .ok()
+            .and_then(|now| format_date_key(&now).ok());
+        let index = today
+            .and_then(|key| self.days.iter().position(|day| day.date == key))
+            .unwrap_or(0);
+        self.select(index);
+    }
+}
+
+/// 최신순으로 정렬된 날짜 키에서 `target`과 같거나 그 이전의 가장 가까운 날
+pub fn nearest_day_index(keys_desc: &[&str], target: &str) -> Option<usize> {
+    keys_desc.iter().position(|key| *key <= target)
+}
+
+/// 브라우저 화면을 그립니다
+pub fn render(f: &mut Frame, area: Rect, browser: &HistoryBrowser) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(18),     // 날짜 목록
+            Constraint::Percentage(55), // 덤프
+            Constraint::Min(20),        // 피드백/회고
+        ])
+        .split(rows[0]);
+
+    render_day_list(f, panes[0], browser);
+    render_dump(f, panes[1], browser);
+    render_notes(f, panes[2], browser);
+    render_status_line(f, rows[1], browser);
+}
+
+fn pane_block(title: String, focused: bool) -> Block<'static> {
+    let border = if focused {
+        Color::Cyan
+    } else {
+        Color::DarkGray
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+}
+
+fn render_day_list(f: &mut Frame, area: Rect, browser: &HistoryBrowser) {
+    let items: Vec<ListItem> = browser
+        .days
+        .iter()
+        .map(|day| {
+            let label = parse_date_key(&day.date)
+                .map(|date| {
+                    format!(
+                        "{} {}",
+                        date.format(&format_description!("[year]-[month]-[day]"))
+                            .unwrap_or_default(),
+                        short_weekday_label(&date)
+                    )
+                })
+                .unwrap_or_else(|_| day.date.clone());
+            ListItem::new(label)
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    if !browser.days.is_empty() {
+        list_state.select(Some(browser.selected));
+    }
+
+    let list = List::new(items)
+        .block(pane_block(format!(" 기록 {} ", browser.days.len()), false))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn render_dump(f: &mut Frame, area: Rect, browser: &HistoryBrowser) {
+    let title = browser
+        .selected_day()
+        .map(|day| format!(" {} ", day.title))
+        .unwrap_or_else(|| " 덤프 ".to_string());
+    let lines: Vec<Line> = browser
+        .dump_lines
+        .iter()
+        .map(|line| format_log_line(line))
+        .collect();
+
+    let paragraph = Paragraph::new(Text::from(lines))
+        .wrap(Wrap { trim: false })
+        .scroll((browser.dump_scroll, 0))
+        .block(pane_block(title, browser.focus == BrowserPane::Dump));
+    f.render_widget(paragraph, area);
+}
+
+fn render_notes(f: &mut Frame, area: Rect, browser: &HistoryBrowser) {
+    let heading = |text: &str| {
+        Line::from(Span::styled(
+            text.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ))
+    };
+    let dim = |text: &str| {
+        Line::from(Span::styled(
+            text.to_string(),
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+
+    let mut lines = vec![heading("AI 피드백")];
+    match &browser.feedback {
+        Some(feedback) => lines.extend(feedback.lines().map(|line| Line::from(line.to_string()))),
+        None => lines.push(dim("(없음)")),
+    }
+    lines.push(Line::from(""));
+    lines.push(heading("회고"));
+    if browser.retro.trim().is_empty() {
+        lines.push(dim("(없음)"));
+    } else {
+        lines.extend(
+            browser
+                .retro
+                .lines()
+                .map(|line| Line::from(line.to_string())),
+        );
+    }
+
+    let paragraph = Paragraph::new(Text::from(lines))
+        .wrap(Wrap { trim: false })
+        .scroll((browser.notes_scroll, 0))
+        .block(pane_block(
+            " 피드백 · 회고 ".to_string(),
+            browser.focus == BrowserPane::Notes,
+        ));
+    f.render_widget(paragraph, area);
+}
+
+fn render_status_line(f: &mut Frame, area: Rect, browser: &HistoryBrowser) {
+    let line = if let Some(input) = &browser.jump_input {
+        Line::from(vec![
+            Span::styled(" 이동할 날짜: ", Style::default().fg(Color::Cyan)),
+            Span::raw(input.clone()),
+            Span::styled(
+                "  (YYYY-MM-DD, today, yesterday · Enter 이동 · Esc 취소)",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])
+    } else if let Some(status) = &browser.status {
+        Line::from(Span::styled(
+            format!(" {}", status),
+            Style::default().fg(Color::Yellow),
+        ))
+    } else {
+        Line::from(Span::styled(
+            " [/] 이전·다음 날  ↑↓ 스크롤  Tab 창 전환  g 날짜 이동  t 오늘  Esc 입력으로  q 종료",
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+    f.render_widget(Paragraph::new(line), area);
+
+    if let Some(input) = &browser.jump_input {
+        use unicode_width::UnicodeWidthStr;
+        let x = area.x + " 이동할 날짜: ".width() as u16 + input.width() as u16;
+        f.set_cursor_position((x.min(area.right().saturating_sub(1)), area.y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jump_picks_same_or_closest_earlier_day() {
+        let keys = ["20240310", "20240305", "20240301"];
+        assert_eq!(nearest_day_index(&keys, "20240305"), Some(1));
+        assert_eq!(nearest_day_index(&keys, "20240307"), Some(1));
+        assert_eq!(nearest_day_index(&keys, "20240401"), Some(0));
+        assert_eq!(nearest_day_index(&keys, "20240201"), None);
+    }
+
+    #[test]
+    fn dump_lines_with_unbalanced_parentheses_render_as_is() {
+        let text = |line: &str| format_log_line(line).to_string();
+        assert_eq!(text("- a) b (c"), "  - a) b (c");
+        assert_eq!(text("- 회의 (10:00:00)"), "  - 회의 (10:00:00)");
+    }
+}
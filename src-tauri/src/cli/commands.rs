@@ -0,0 +1,752 @@
+// cli/commands.rs
+// hoego-cli 하위 명령 실행 (GUI와 같은 서비스/저장 코드를 사용)
+
+use serde::Serialize;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+#[cfg(unix)]
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+use super::args::{self, CliCommand, OutputFormat};
+use super::daily_log;
+use super::feedback_stream::{FeedbackStream, StreamEvent};
+use super::mcp::{self, McpOptions};
+use crate::models::dump::HistoryState;
+use crate::models::hooks::{HookConfig, HookRun};
+use crate::models::settings::AppSettings;
+use crate::models::weekly::PeriodData;
+#[cfg(unix)]
+use crate::services::daemon_service::{self, DaemonServer};
+use crate::services::session_service::{self, SessionMarker};
+use crate::services::{
+    calendar_service, feedback_service, history_service, hook_service, storage_service,
+    weekly_service,
+};
+use crate::utils::*;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+
+/// `log` 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LogOutput {
+    file_path: String,
+    entries: Vec<String>,
+}
+
+/// 하루 기록 파일 (`show`, `retro`)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DayOutput {
+    date: String,
+    file_path: String,
+    content: String,
+}
+
+/// `export` 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportOutput {
+    path: String,
+}
+
+/// 하위 명령을 실행합니다
+pub fn run(command: CliCommand, format: OutputFormat) -> Result<(), String> {
+    let state = HistoryState::default();
+    let settings = storage_service::load_settings().unwrap_or_default();
+
+    match command {
+        CliCommand::Log { text } => log(&state, text, format),
+        CliCommand::Today => today(&state, &settings, format),
+        CliCommand::Show { date } => show(&state, &date, format),
+        CliCommand::List { limit } => list(&state, limit, format),
+        CliCommand::Retro { date, edit } => retro(&state, date.as_deref(), edit, format),
+        CliCommand::Feedback {
+            date,
+            generate: true,
+            ..
+        } => generate_feedback(date.as_deref(), format),
+        CliCommand::Feedback { date, limit, .. } => feedback(date.as_deref(), limit, format),
+        CliCommand::Session { marker, title } => session(&state, marker, title.as_deref(), format),
+        CliCommand::Sessions { date } => sessions(&state, date.as_deref(), format),
+        CliCommand::Stats {
+            preset,
+            date,
+            end_date,
+            week_start,
+        } => stats(
+            &state,
+            &settings,
+            &preset,
+            date.as_deref(),
+            end_date.as_deref(),
+            &week_start,
+            format,
+        ),
+        CliCommand::Export {
+            start,
+            end,
+            merge,
+            output,
+        } => export(
+            &state,
+            &settings,
+            &start,
+            end.as_deref(),
+            merge,
+            output,
+            format,
+        ),
+        CliCommand::Daemon => daemon(state, format),
+        CliCommand::Hooks { limit } => hooks(limit, format),
+        CliCommand::Mcp {
+            read_only,
+            mask_pii,
+        } => mcp::serve(
+            state,
+            settings,
+            McpOptions {
+                read_only,
+                mask_pii,
+            },
+        ),
+        CliCommand::Help | CliCommand::Tui(_) => {
+            println!("{}", args::usage());
+            Ok(())
+        }
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| format!("JSON 변환 실패: {}", e))?;
+    println!("{}", json);
+    Ok(())
+}
+
+/// 마크다운 내용을 출력 형식에 맞게 출력합니다
+fn print_markdown(content: &str, format: OutputFormat) {
+    match format {
+        OutputFormat::Pretty => content.lines().for_each(daily_log::print_formatted_line),
+        _ => print!("{}", content),
+    }
+}
+
+/// 인자로 받은 날짜를 로컬 자정으로 변환합니다 (없으면 오늘)
+pub(super) fn resolve_day(value: Option<&str>) -> Result<OffsetDateTime, String> {
+    let today = current_local_time()?.date();
+    let date = match value {
+        Some(value) => args::resolve_date(value, today)?,
+        None => today,
+    };
+    Ok(weekly_service::local_midnight(date))
+}
+
+fn format_seconds(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let minutes = seconds.abs() / 60;
+    if minutes >= 60 {
+        format!("{}{}h {:02}m", sign, minutes / 60, minutes % 60)
+    } else {
+        format!("{}{}m", sign, minutes)
+    }
+}
+
+fn format_signed_seconds(seconds: i64) -> String {
+    if seconds > 0 {
+        format!("+{}", format_seconds(seconds))
+    } else {
+        format_seconds(seconds)
+    }
+}
+
+fn log(state: &HistoryState, text: Option<String>, format: OutputFormat) -> Result<(), String> {
+    let entries: Vec<String> = match text {
+        Some(text) => vec![text.trim().to_string()],
+        None => {
+            let stdin = io::stdin();
+            if stdin.is_terminal() {
+                return Err("기록할 내용을 입력하세요 (예: hoego log \"PR 리뷰\")".to_string());
+            }
+            stdin
+                .lock()
+                .lines()
+                .map_while(Result::ok)
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect()
+        }
+    };
+    if entries.is_empty() {
+        return Err("기록할 내용이 없습니다".to_string());
+    }
+
+    let mut file_path = PathBuf::new();
+    for entry in &entries {
+        file_path = daily_log::append_entry(state, entry)?.0;
+    }
+
+    match format {
+        OutputFormat::Json => print_json(&LogOutput {
+            file_path: file_path.to_string_lossy().to_string(),
+            entries,
+        }),
+        OutputFormat::Plain => {
+            entries.iter().for_each(|entry| println!("{}", entry));
+            Ok(())
+        }
+        OutputFormat::Pretty => {
+            for entry in &entries {
+                println!("  {}✓{} {}", GREEN, RESET, entry);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn today(state: &HistoryState, settings: &AppSettings, format: OutputFormat) -> Result<(), String> {
+    let today = history_service::load_today(state, &settings.calendar)?;
+    if format == OutputFormat::Json {
+        return print_json(&today);
+    }
+
+    print_markdown(&today.content, format);
+    if let Some(section) = calendar_service::prompt_section(&today.calendar_events) {
+        println!();
+        print_markdown(&section, format);
+    }
+    Ok(())
+}
+
+fn show(state: &HistoryState, date: &str, format: OutputFormat) -> Result<(), String> {
+    let day = resolve_day(Some(date))?;
+    let date_key = format_date_key(&day)?;
+    let content = weekly_service::load_daily_dump(state, &day)?;
+    if content.is_empty() && format != OutputFormat::Json {
+        return Err(format!("{} 기록이 없습니다", date_key));
+    }
+
+    match format {
+        OutputFormat::Json => print_json(&DayOutput {
+            file_path: state
+                .directory
+                .join(format!("{}.md", date_key))
+                .to_string_lossy()
+                .to_string(),
+            date: date_key,
+            content,
+        }),
+        _ => {
+            print_markdown(&content, format);
+            Ok(())
+        }
+    }
+}
+
+fn list(state: &HistoryState, limit: Option<usize>, format: OutputFormat) -> Result<(), String> {
+    let mut overview = history_service::collect_history(state)?;
+    if let Some(limit) = limit {
+        overview.files.truncate(limit);
+    }
+    if format == OutputFormat::Json {
+        return print_json(&overview);
+    }
+
+    for file in &overview.files {
+        let preview = file.preview.as_deref().unwrap_or("");
+        match format {
+            OutputFormat::Pretty => println!(
+                "  {}{}{}  {}  {}{}{}",
+                BOLD, file.date, RESET, file.title, DIM, preview, RESET
+            ),
+            _ => println!("{}\t{}\t{}", file.date, file.title, preview),
+        }
+    }
+    Ok(())
+}
+
+fn session(
+    state: &HistoryState,
+    marker: SessionMarker,
+    title: Option<&str>,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let result = daily_log::record_session(state, marker, title)?;
+    if format == OutputFormat::Json {
+        return print_json(&result.session);
+    }
+
+    let summary = result
+        .session
+        .as_ref()
+        .map(session_service::format_session_summary)
+        .unwrap_or(result.title);
+    match format {
+        OutputFormat::Pretty => println!("  {}{}{} · {}", BOLD, marker.label(), RESET, summary),
+        _ => println!("{}\t{}", marker.label(), summary),
+    }
+    Ok(())
+}
+
+fn sessions(state: &HistoryState, date: Option<&str>, format: OutputFormat) -> Result<(), String> {
+    let day = resolve_day(date)?;
+    let sessions = session_service::load_sessions(state, &day)?;
+    if format == OutputFormat::Json {
+        return print_json(&sessions);
+    }
+    if sessions.is_empty() {
+        return Err(format!("{} 세션 기록이 없습니다", format_date_key(&day)?));
+    }
+
+    for session in &sessions {
+        let ended_at = session.ended_at.as_deref().unwrap_or("");
+        match format {
+            OutputFormat::Pretty => println!(
+                "  {}{}–{:<8}{}  {}",
+                DIM,
+                session.started_at,
+                ended_at,
+                RESET,
+                session_service::format_session_summary(session)
+            ),
+            _ => println!(
+                "{}\t{}\t{}\t{}\t{}",
+                session.started_at, ended_at, session.title, session.active_seconds, session.entry_count
+            ),
+        }
+    }
+    if format == OutputFormat::Pretty {
+        let total: i64 = sessions.iter().map(|session| session.active_seconds).sum();
+        println!("\n  {}합계{} {}", BOLD, RESET, session_service::format_duration(total));
+    }
+    Ok(())
+}
+
+/// $EDITOR (기본 vi)로 파일을 엽니다
+fn open_in_editor(path: &Path) -> Result<(), String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .map_err(|e| format!("편집기 실행 실패 ({}): {}", editor, e))?;
+    if !status.success() {
+        return Err(format!("편집기가 오류로 종료되었습니다: {}", status));
+    }
+    Ok(())
+}
+
+/// 회고 파일을 (없으면 제목을 채워 만든 뒤) $EDITOR로 엽니다
+pub fn edit_retrospect(state: &HistoryState, day: &OffsetDateTime) -> Result<PathBuf, String> {
+    let date_key = format_date_key(day)?;
+    let path = history_service::retrospect_path(state, &date_key);
+    if !path.exists() {
+        history_service::save_retrospect(
+            state,
+            &date_key,
+            &format!("# {} 회고\n\n", format_date_label(day)),
+        )?;
+    }
+    open_in_editor(&path)?;
+    Ok(path)
+}
+
+fn retro(
+    state: &HistoryState,
+    date: Option<&str>,
+    edit: bool,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let day = resolve_day(date)?;
+    let date_key = format_date_key(&day)?;
+    let path = history_service::retrospect_path(state, &date_key);
+
+    if edit {
+        edit_retrospect(state, &day)?;
+    }
+
+    let content = history_service::load_retrospect(state, &date_key)?;
+    match format {
+        OutputFormat::Json => print_json(&DayOutput {
+            date: date_key,
+            file_path: path.to_string_lossy().to_string(),
+            content,
+        }),
+        _ if content.trim().is_empty() => Err(format!(
+            "{} 회고가 없습니다 (hoego retro {} --edit 로 작성)",
+            date_key,
+            date.unwrap_or("today")
+        )),
+        _ => {
+            print_markdown(&content, format);
+            Ok(())
+        }
+    }
+}
+
+fn feedback(date: Option<&str>, limit: usize, format: OutputFormat) -> Result<(), String> {
+    let day = resolve_day(date)?;
+    let date_key = format_date_key(&day)?;
+    let summaries = feedback_service::list_summaries(&date_key, limit)?;
+    if format == OutputFormat::Json {
+        return print_json(&summaries);
+    }
+    if summaries.is_empty() {
+        return Err(format!("{} AI 피드백이 없습니다", date_key));
+    }
+
+    for (index, summary) in summaries.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        if format == OutputFormat::Pretty {
+            println!("  {}{}{}\n", DIM, summary.filename, RESET);
+        }
+        print_markdown(&summary.content, format);
+        if !summary.content.ends_with('\n') {
+            println!();
+        }
+    }
+    Ok(())
+}
+
+/// Generate feedback with the app's pipeline, printing deltas as they arrive
+fn generate_feedback(date: Option<&str>, format: OutputFormat) -> Result<(), String> {
+    let day = match date {
+        Some(_) => resolve_day(date)?,
+        None => current_local_time()?,
+    };
+
+    let stream = FeedbackStream::start(day);
+    while let Some(event) = stream.recv() {
+        match event {
+            StreamEvent::Status(status) => match format {
+                OutputFormat::Pretty => eprintln!("  {}{}{}", DIM, status, RESET),
+                OutputFormat::Plain => eprintln!("{}", status),
+                OutputFormat::Json => {}
+            },
+            StreamEvent::Delta(delta) => {
+                if format != OutputFormat::Json {
+                    print!("{}", delta);
+                    let _ = io::stdout().flush();
+                }
+            }
+            StreamEvent::Saved(saved) => {
+                if format == OutputFormat::Json {
+                    return print_json(&saved);
+                }
+                println!();
+                if format == OutputFormat::Pretty {
+                    println!("\n  {}저장: {}{}", DIM, saved.path, RESET);
+                }
+                return Ok(());
+            }
+            StreamEvent::Cancelled => return Err("AI 피드백 생성이 취소되었습니다".to_string()),
+            StreamEvent::Failed(error) => return Err(error),
+        }
+    }
+    Err("AI 피드백 생성이 중단되었습니다".to_string())
+}
+
+/// `hooks` 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HooksOutput {
+    hooks_path: String,
+    hooks: Vec<HookConfig>,
+    runs: Vec<HookRun>,
+}
+
+/// 설정된 훅과 최근 실행 기록을 보여줍니다
+fn hooks(limit: Option<usize>, format: OutputFormat) -> Result<(), String> {
+    let output = HooksOutput {
+        hooks_path: hook_service::get_hooks_path()?.to_string_lossy().to_string(),
+        hooks: hook_service::load_hooks()?,
+        runs: hook_service::load_runs(limit.unwrap_or(20))?,
+    };
+    if format == OutputFormat::Json {
+        return print_json(&output);
+    }
+
+    let pretty = format == OutputFormat::Pretty;
+    if pretty {
+        println!("  {}훅{} {}{}{}", BOLD, RESET, DIM, output.hooks_path, RESET);
+    }
+    if output.hooks.is_empty() && pretty {
+        println!("  {}설정된 훅이 없습니다{}", DIM, RESET);
+    }
+    for hook in &output.hooks {
+        let enabled = if hook.enabled { "on" } else { "off" };
+        let timeout = hook
+            .timeout_seconds
+            .unwrap_or(hook_service::DEFAULT_TIMEOUT_SECONDS);
+        let name = if hook.name.is_empty() { &hook.command } else { &hook.name };
+        match format {
+            OutputFormat::Pretty => println!(
+                "  {:<3} {:<18} {}  {}{} ({}s){}",
+                enabled,
+                hook.event.as_str(),
+                name,
+                DIM,
+                hook.command,
+                timeout,
+                RESET
+            ),
+            _ => println!(
+                "hook\t{}\t{}\t{}\t{}\t{}",
+                enabled,
+                hook.event.as_str(),
+                name,
+                hook.command,
+                timeout
+            ),
+        }
+    }
+
+    if pretty {
+        println!("\n  {}최근 실행{}", BOLD, RESET);
+        if output.runs.is_empty() {
+            println!("  {}실행 기록이 없습니다{}", DIM, RESET);
+        }
+    }
+    for run in &output.runs {
+        let status = match (run.exit_code, run.timed_out, &run.error) {
+            (_, true, _) => "timeout".to_string(),
+            (_, _, Some(error)) => error.clone(),
+            (Some(code), _, _) => format!("exit {}", code),
+            (None, _, _) => "killed".to_string(),
+        };
+        match format {
+            OutputFormat::Pretty => {
+                let color = if run.succeeded() { GREEN } else { RED };
+                println!(
+                    "  {}{}{}  {}{:<10}{} {:<18} {} {}({}ms){}",
+                    DIM,
+                    run.started_at,
+                    RESET,
+                    color,
+                    status,
+                    RESET,
+                    run.event.as_str(),
+                    run.name,
+                    DIM,
+                    run.duration_ms,
+                    RESET
+                );
+                if !run.succeeded() {
+                    let lines: Vec<&str> = run.stderr.lines().collect();
+                    for line in &lines[lines.len().saturating_sub(3)..] {
+                        println!("      {}{}{}", DIM, line, RESET);
+                    }
+                }
+            }
+            _ => println!(
+                "run\t{}\t{}\t{}\t{}\t{}",
+                run.started_at,
+                status,
+                run.event.as_str(),
+                run.name,
+                run.duration_ms
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// `daemon` 결과 (시작 시 한 번 출력)
+#[cfg(unix)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DaemonOutput {
+    socket_path: String,
+    token_path: String,
+}
+
+/// 앱 없이 데몬 소켓을 열고 Ctrl+C까지 요청을 처리합니다
+#[cfg(unix)]
+fn daemon(state: HistoryState, format: OutputFormat) -> Result<(), String> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("런타임 생성 실패: {}", e))?;
+
+    let result = runtime.block_on(async {
+        let server = DaemonServer::bind(
+            Arc::new(state),
+            Arc::new(daemon_service::publish_history_update),
+        )?;
+
+        let output = DaemonOutput {
+            socket_path: daemon_service::socket_path()?.to_string_lossy().to_string(),
+            token_path: daemon_service::token_path()?.to_string_lossy().to_string(),
+        };
+        match format {
+            OutputFormat::Json => print_json(&output)?,
+            OutputFormat::Plain => println!("{}", output.socket_path),
+            OutputFormat::Pretty => {
+                println!("  {}데몬 실행 중{} {}", BOLD, RESET, output.socket_path);
+                println!("  {}토큰: {} · Ctrl+C로 종료{}", DIM, output.token_path, RESET);
+            }
+        }
+
+        tokio::select! {
+            _ = server.run() => Ok(()),
+            signal = tokio::signal::ctrl_c() => signal.map_err(|e| format!("종료 신호 대기 실패: {}", e)),
+        }
+    });
+
+    daemon_service::cleanup();
+    result
+}
+
+/// 데몬 소켓은 Unix에서만 지원합니다
+#[cfg(not(unix))]
+fn daemon(_state: HistoryState, _format: OutputFormat) -> Result<(), String> {
+    Err("이 플랫폼에서는 데몬을 지원하지 않습니다".to_string())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn stats(
+    state: &HistoryState,
+    settings: &AppSettings,
+    preset: &str,
+    date: Option<&str>,
+    end_date: Option<&str>,
+    week_start: &str,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let preset_kind = weekly_service::parse_period_preset(preset)?;
+    let week_start_day = weekly_service::parse_week_start_day(week_start)?;
+    let date = resolve_day(date)?;
+    let end_date = end_date.map(|value| resolve_day(Some(value))).transpose()?;
+
+    let (start, end) =
+        weekly_service::resolve_period(preset_kind, &date, end_date.as_ref(), week_start_day)?;
+    let data = weekly_service::build_period_data(
+        state,
+        preset_kind,
+        &start,
+        &end,
+        week_start_day,
+        &settings.time_tracking,
+    )?;
+
+    match format {
+        OutputFormat::Json => print_json(&data),
+        _ => {
+            print_stats(&data, format == OutputFormat::Pretty);
+            Ok(())
+        }
+    }
+}
+
+fn print_stats(data: &PeriodData, color: bool) {
+    let paint = |code: &str, text: String| {
+        if color {
+            format!("{}{}{}", code, text, RESET)
+        } else {
+            text
+        }
+    };
+    let stats = &data.aggregated_stats;
+    let tracked: i64 = stats.total_categories.values().sum();
+
+    println!(
+        "{}",
+        paint(
+            BOLD,
+            format!("{} ~ {} ({})", data.start_date, data.end_date, data.preset)
+        )
+    );
+    println!(
+        "기록 {} · 미기록 {} · 생산적 {:.1}% · 낭비 {:.1}%",
+        format_seconds(tracked),
+        format_seconds(stats.untracked_seconds),
+        stats.productivity_vs_waste.productive_percentage,
+        stats.productivity_vs_waste.waste_percentage,
+    );
+    println!();
+
+    let width = data
+        .comparison
+        .category_deltas
+        .iter()
+        .map(|d| unicode_width::UnicodeWidthStr::width(d.category.as_str()))
+        .max()
+        .unwrap_or(0);
+    for delta in &data.comparison.category_deltas {
+        if delta.current_seconds == 0 && delta.previous_seconds == 0 {
+            continue;
+        }
+        let padding = width - unicode_width::UnicodeWidthStr::width(delta.category.as_str());
+        let change = match delta.delta_percentage {
+            Some(percentage) => format!(
+                "{} ({:+.0}%)",
+                format_signed_seconds(delta.delta_seconds),
+                percentage
+            ),
+            None => "new".to_string(),
+        };
+        let change = if delta.delta_seconds > 0 {
+            paint(GREEN, change)
+        } else if delta.delta_seconds < 0 {
+            paint(RED, change)
+        } else {
+            paint(DIM, change)
+        };
+        println!(
+            "{}{}  {:>8}  {}",
+            delta.category,
+            " ".repeat(padding),
+            format_seconds(delta.current_seconds),
+            change
+        );
+    }
+}
+
+fn export(
+    state: &HistoryState,
+    settings: &AppSettings,
+    start: &str,
+    end: Option<&str>,
+    merge: bool,
+    output: Option<String>,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let start = resolve_day(Some(start))?;
+    let end = match end {
+        Some(end) => resolve_day(Some(end))?,
+        None => start,
+    };
+    if end < start {
+        return Err("종료일이 시작일보다 빠릅니다".to_string());
+    }
+
+    let path = calendar_service::export_ics_file(
+        state,
+        &start,
+        &end,
+        &settings.time_tracking,
+        merge,
+        output.map(PathBuf::from),
+    )?;
+    let path = path.to_string_lossy().to_string();
+
+    match format {
+        OutputFormat::Json => print_json(&ExportOutput { path }),
+        _ => {
+            println!("{}", path);
+            Ok(())
+        }
+    }
+}
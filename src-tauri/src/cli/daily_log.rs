@@ -1,10 +1,16 @@
-use std::fs::{File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use time::OffsetDateTime;
 
+use serde_json::{json, Value};
+
+use crate::models::daemon::{AppendResult, SessionResult, HOOK_ORIGIN};
 use crate::models::dump::HistoryState;
-use crate::services::history_service::ensure_daily_file;
+use crate::services::daemon_service::{self, DaemonClient};
+use crate::services::history_service::{self, ensure_daily_file};
+use crate::services::hook_service;
+use crate::services::session_service::SessionMarker;
 use crate::utils::{current_local_time, format_date_label, format_time_with_seconds};
 
 // ANSI 색상 코드
@@ -29,6 +35,45 @@ pub fn get_today_file_path() -> Result<(PathBuf, OffsetDateTime), String> {
     Ok((file_path, now))
 }
 
+/// 데몬 요청 인자 (훅 안에서 실행됐으면 데몬도 훅을 다시 부르지 않도록 표시)
+fn daemon_params(mut params: Value) -> Value {
+    if hook_service::running_in_hook() {
+        params["origin"] = json!(HOOK_ORIGIN);
+    }
+    params
+}
+
+/// 항목을 추가하고 (파일 경로, 기록된 줄)을 돌려줍니다
+///
+/// 데몬이 실행 중이면 데몬을 통해 기록해서 쓰기가 한 프로세스에서 일어나게 합니다.
+pub fn append_entry(state: &HistoryState, text: &str) -> Result<(PathBuf, String), String> {
+    if let Some(mut client) = DaemonClient::connect() {
+        let params = daemon_params(json!({ "text": text }));
+        let result: AppendResult = client.call_as("append", params)?;
+        return Ok((PathBuf::from(result.file_path), result.line));
+    }
+
+    let now = current_local_time()?;
+    let file_path = history_service::append_entry(state, &now, text)?;
+    let line = history_service::entry_line(text, &format_time_with_seconds(&now)?);
+    Ok((file_path, line))
+}
+
+/// 세션 마커를 기록합니다 (데몬이 실행 중이면 데몬을 통해)
+pub fn record_session(
+    state: &HistoryState,
+    marker: SessionMarker,
+    title: Option<&str>,
+) -> Result<SessionResult, String> {
+    match DaemonClient::connect() {
+        Some(mut client) => client.call_as(
+            daemon_service::session_method(marker),
+            daemon_params(json!({ "title": title })),
+        ),
+        None => daemon_service::record_session(state, marker, title),
+    }
+}
+
 /// 파일의 마지막 N줄을 읽어옵니다 (전체 파일 읽기)
 pub fn read_last_n_lines(file_path: &std::path::Path, n: usize) -> Result<Vec<String>, String> {
     if !file_path.exists() {
@@ -51,46 +96,6 @@ pub fn read_last_n_lines(file_path: &std::path::Path, n: usize) -> Result<Vec<St
     Ok(lines[start_idx..].to_vec())
 }
 
-/// 로그 항목을 파일에 추가합니다
-pub fn append_log_entry(file_path: &Path, content: &str) -> Result<(), String> {
-    let now = current_local_time()?;
-    let time_label = format_time_with_seconds(&now)?;
-
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(file_path)
-        .map_err(|e| format!("파일 열기 실패: {}", e))?;
-
-    writeln!(file, "- {} ({})", content, time_label)
-        .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
-
-    file.flush()
-        .map_err(|e| format!("파일 flush 실패: {}", e))?;
-
-    Ok(())
-}
-
-/// 세션 헤더를 파일에 추가합니다
-pub fn append_session_header(file_path: &Path, session_title: &str) -> Result<(), String> {
-    let now = current_local_time()?;
-    let time_label = format_time_with_seconds(&now)?;
-
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(file_path)
-        .map_err(|e| format!("파일 열기 실패: {}", e))?;
-
-    writeln!(file, "\n## 세션: {} ({})\n", session_title, time_label)
-        .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
-
-    file.flush()
-        .map_err(|e| format!("파일 flush 실패: {}", e))?;
-
-    Ok(())
-}
-
 /// 화면을 지웁니다
 pub fn clear_screen() {
     print!("\x1b[2J\x1b[H");
@@ -130,7 +135,7 @@ pub fn print_recent_lines(file_path: &Path, n: usize) -> Result<(), String> {
 }
 
 /// 로그 라인을 포맷팅해서 출력합니다
-fn print_formatted_line(line: &str) {
+pub fn print_formatted_line(line: &str) {
     if line.starts_with("# ") {
         // 헤더
         println!("  {}{}{}", BOLD, line, RESET);
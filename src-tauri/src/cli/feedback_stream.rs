@@ -0,0 +1,199 @@
+// cli/feedback_stream.rs
+// 터미널에서 AI 피드백 생성 (앱과 같은 feedback_service 파이프라인을 백그라운드 스레드에서 실행)
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+use crate::models::dump::HistoryState;
+use crate::models::feedback::{AiSummaryFile, StreamCancellationState};
+use crate::services::feedback_service::{self, FeedbackBackend, DEFAULT_CLOUD_MODEL};
+use crate::services::llm::security::SecureKeyStore;
+use crate::services::llm::{CloudLLMState, LLMManager};
+use crate::services::storage_service;
+
+/// 로컬 모델 서버가 준비될 때까지 기다리는 최대 횟수 (0.5초 간격)
+const READY_ATTEMPTS: usize = 120;
+
+/// 생성 스레드가 보내는 진행 상황
+#[derive(Debug)]
+pub enum StreamEvent {
+    Status(String),
+    Delta(String),
+    Saved(AiSummaryFile),
+    Cancelled,
+    Failed(String),
+}
+
+/// 백그라운드에서 진행 중인 AI 피드백 생성
+pub struct FeedbackStream {
+    events: Receiver<StreamEvent>,
+    cancellation: StreamCancellationState,
+}
+
+impl FeedbackStream {
+    /// `date`의 기록으로 피드백 생성을 시작합니다
+    pub fn start(date: OffsetDateTime) -> Self {
+        let (sender, events) = mpsc::channel();
+        let cancellation = StreamCancellationState::default();
+        let thread_cancellation = cancellation.clone();
+
+        thread::spawn(move || {
+            let event = match generate(date, &thread_cancellation, &sender) {
+                Ok(Some(saved)) => StreamEvent::Saved(saved),
+                Ok(None) => StreamEvent::Cancelled,
+                Err(error) => StreamEvent::Failed(error),
+            };
+            // 받는 쪽이 이미 닫혔으면 무시
+            let _ = sender.send(event);
+        });
+
+        Self {
+            events,
+            cancellation,
+        }
+    }
+
+    /// 생성을 취소합니다 (로컬 모델은 응답이 끝날 때까지 스레드가 남아 있을 수 있음)
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
+    /// 지금까지 도착한 이벤트
+    pub fn drain(&self) -> Vec<StreamEvent> {
+        self.events.try_iter().collect()
+    }
+
+    /// 다음 이벤트를 기다립니다 (스레드가 끝나면 None)
+    pub fn recv(&self) -> Option<StreamEvent> {
+        self.events.recv().ok()
+    }
+}
+
+/// 앱에서 고른 외부 서버나 로컬 모델이 있으면 로컬, 없으면 API 키가 있는 클라우드 모델
+async fn choose_backend(llm_manager: &LLMManager) -> Result<FeedbackBackend, String> {
+    if llm_manager.endpoint().await.is_some() {
+        return Ok(FeedbackBackend::Local);
+    }
+    let default_model = llm_manager
+        .model_manager
+        .get_default_model()
+        .await
+        .map_err(|e| e.to_string())?;
+    if default_model.is_some() {
+        return Ok(FeedbackBackend::Local);
+    }
+    if SecureKeyStore::has_api_key("openai") {
+        return Ok(FeedbackBackend::Cloud {
+            model_id: DEFAULT_CLOUD_MODEL.to_string(),
+        });
+    }
+    Err("사용할 수 있는 AI 모델이 없습니다. 앱에서 로컬 모델을 내려받거나 OPENAI_API_KEY를 설정하세요".to_string())
+}
+
+/// 기본 로컬 모델로 llama-server를 띄우고 응답할 때까지 기다립니다
+async fn start_local_engine(
+    llm_manager: &LLMManager,
+    cancellation: &StreamCancellationState,
+) -> Result<bool, String> {
+    llm_manager
+        .initialize()
+        .await
+        .map_err(|e| format!("로컬 모델 시작 실패: {}", e))?;
+
+    for _ in 0..READY_ATTEMPTS {
+        if cancellation.is_cancelled() {
+            return Ok(false);
+        }
+        let ready = llm_manager
+            .engine
+            .lock()
+            .await
+            .wait_for_ready()
+            .await
+            .unwrap_or(false);
+        if ready {
+            return Ok(true);
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    Err("로컬 모델 서버가 응답하지 않습니다".to_string())
+}
+
+fn generate(
+    date: OffsetDateTime,
+    cancellation: &StreamCancellationState,
+    sender: &Sender<StreamEvent>,
+) -> Result<Option<AiSummaryFile>, String> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("런타임 생성 실패: {}", e))?;
+
+    runtime.block_on(async {
+        let settings = storage_service::load_settings().unwrap_or_default();
+        let llm_manager = LLMManager::new().map_err(|e| format!("LLM 초기화 실패: {}", e))?;
+        let backend = choose_backend(&llm_manager).await?;
+
+        let endpoint = llm_manager.endpoint().await;
+        let cloud = match &backend {
+            FeedbackBackend::Local => {
+                match &endpoint {
+                    Some(endpoint) => {
+                        let _ = sender.send(StreamEvent::Status(format!(
+                            "{} 연결 확인 중…",
+                            endpoint.base_url()
+                        )));
+                        endpoint
+                            .health()
+                            .await
+                            .map_err(|e| format!("로컬 서버에 연결할 수 없습니다: {}", e))?;
+                    }
+                    None => {
+                        let _ = sender.send(StreamEvent::Status("로컬 모델 시작 중…".to_string()));
+                        if !start_local_engine(&llm_manager, cancellation).await? {
+                            return Ok(None);
+                        }
+                    }
+                }
+                CloudLLMState::new()
+            }
+            FeedbackBackend::Cloud { .. } => CloudLLMState::from_saved_key("openai")?,
+        };
+
+        let input = feedback_service::prepare_feedback_input(
+            &HistoryState::default(),
+            &date,
+            &settings.calendar,
+            backend.is_cloud(),
+        )?;
+
+        let status = match (&backend, &endpoint) {
+            (FeedbackBackend::Local, Some(endpoint)) => format!("{}로 생성 중…", endpoint.model()),
+            (FeedbackBackend::Local, None) => "로컬 모델로 생성 중…".to_string(),
+            (FeedbackBackend::Cloud { model_id }, _) if input.pii_masked => {
+                format!("{}로 생성 중… (개인정보 마스킹 적용)", model_id)
+            }
+            (FeedbackBackend::Cloud { model_id }, _) => format!("{}로 생성 중…", model_id),
+        };
+        let _ = sender.send(StreamEvent::Status(status));
+
+        let text = feedback_service::stream_feedback(
+            &input,
+            &backend,
+            &llm_manager,
+            &cloud,
+            cancellation,
+            |delta| {
+                let _ = sender.send(StreamEvent::Delta(delta.to_string()));
+            },
+        )
+        .await?;
+
+        match text {
+            Some(text) => feedback_service::save_feedback(&date, &text, input.pii_masked).map(Some),
+            None => Ok(None),
+        }
+    })
+}
@@ -0,0 +1,166 @@
+// cli/input_history.rs
+// Daily Log 입력 기록 (실행 간 유지, ↑↓ 탐색, Ctrl+R 역방향 검색)
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// 파일에 남기는 최대 입력 수
+const MAX_ENTRIES: usize = 1000;
+
+#[derive(Debug, Default)]
+pub struct InputHistory {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+    /// ↑↓로 보고 있는 항목 (None이면 새 입력 중)
+    position: Option<usize>,
+    /// 탐색을 시작하기 전에 입력하던 내용
+    draft: String,
+}
+
+/// 입력 기록 파일 경로 (데이터 디렉토리/hoego/cli_history)
+fn history_file_path() -> Result<PathBuf, String> {
+    let data_dir =
+        dirs::data_dir().ok_or_else(|| "데이터 디렉토리를 찾을 수 없습니다".to_string())?;
+    let app_data_dir = data_dir.join("hoego");
+    fs::create_dir_all(&app_data_dir).map_err(|e| format!("데이터 디렉토리 생성 실패: {}", e))?;
+    Ok(app_data_dir.join("cli_history"))
+}
+
+impl InputHistory {
+    /// 저장된 입력 기록을 읽습니다 (실패하면 빈 기록으로 시작)
+    pub fn load() -> Self {
+        let path = history_file_path().ok();
+        let entries = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        Self {
+            entries,
+            path,
+            ..Self::default()
+        }
+    }
+
+    #[cfg(test)]
+    fn from_entries(entries: &[&str]) -> Self {
+        Self {
+            entries: entries.iter().map(|entry| entry.to_string()).collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// 제출한 입력을 기록합니다 (바로 앞과 같으면 건너뜀)
+    pub fn push(&mut self, entry: &str) {
+        self.reset_navigation();
+        let entry = entry.trim();
+        if entry.is_empty()
+            || entry.contains('\n')
+            || self.entries.last().is_some_and(|last| last == entry)
+        {
+            return;
+        }
+        self.entries.push(entry.to_string());
+
+        let overflow = self.entries.len() > MAX_ENTRIES;
+        if overflow {
+            let excess = self.entries.len() - MAX_ENTRIES;
+            self.entries.drain(..excess);
+        }
+        if let Err(error) = self.persist(entry, overflow) {
+            tracing::warn!("입력 기록 저장 실패: {}", error);
+        }
+    }
+
+    fn persist(&self, entry: &str, rewrite: bool) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if rewrite {
+            let mut content = self.entries.join("\n");
+            content.push('\n');
+            return fs::write(path, content).map_err(|e| format!("파일 쓰기 실패: {}", e));
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("파일 열기 실패: {}", e))?;
+        writeln!(file, "{}", entry).map_err(|e| format!("파일 쓰기 실패: {}", e))
+    }
+
+    pub fn reset_navigation(&mut self) {
+        self.position = None;
+        self.draft.clear();
+    }
+
+    /// ↑: 한 단계 이전 입력 (처음 누르면 지금 입력을 보관)
+    pub fn previous(&mut self, current: &str) -> Option<&str> {
+        let index = match self.position {
+            None => {
+                self.draft = current.to_string();
+                self.entries.len().checked_sub(1)?
+            }
+            Some(0) => 0,
+            Some(index) => index - 1,
+        };
+        self.position = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// ↓: 한 단계 다음 입력 (끝을 지나면 보관한 입력으로 돌아감)
+    pub fn next(&mut self) -> Option<String> {
+        let index = self.position?;
+        if index + 1 < self.entries.len() {
+            self.position = Some(index + 1);
+            return self.entries.get(index + 1).cloned();
+        }
+        self.position = None;
+        Some(std::mem::take(&mut self.draft))
+    }
+
+    /// `before`보다 앞쪽에서 `query`를 포함하는 가장 최근 입력
+    pub fn search(&self, query: &str, before: Option<usize>) -> Option<(usize, &str)> {
+        let end = before.unwrap_or(self.entries.len()).min(self.entries.len());
+        let query = query.to_lowercase();
+        self.entries[..end]
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, entry)| entry.to_lowercase().contains(&query))
+            .map(|(index, entry)| (index, entry.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn navigates_back_and_restores_draft() {
+        let mut history = InputHistory::from_entries(&["첫 번째", "두 번째"]);
+        assert_eq!(history.previous("작성 중"), Some("두 번째"));
+        assert_eq!(history.previous(""), Some("첫 번째"));
+        assert_eq!(history.previous(""), Some("첫 번째"));
+        assert_eq!(history.next().as_deref(), Some("두 번째"));
+        assert_eq!(history.next().as_deref(), Some("작성 중"));
+        assert_eq!(history.next(), None);
+
+        history.push("두 번째");
+        assert_eq!(history.entries().len(), 2);
+    }
+
+    #[test]
+    fn reverse_search_walks_older_matches() {
+        let history = InputHistory::from_entries(&["PR 리뷰", "점심", "pr 머지"]);
+        assert_eq!(history.search("pr", None), Some((2, "pr 머지")));
+        assert_eq!(history.search("pr", Some(2)), Some((0, "PR 리뷰")));
+        assert_eq!(history.search("pr", Some(0)), None);
+    }
+}
@@ -0,0 +1,652 @@
+// cli/mcp.rs
+// `hoego mcp`: AI 어시스턴트용 MCP(Model Context Protocol) 서버
+//
+// stdin/stdout으로 한 줄에 JSON-RPC 메시지 하나를 주고받습니다. 도구(append_entry, get_day,
+// search, get_week_stats, get_feedback, write_retro)와 하루·기간 리소스를 제공합니다.
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::io::{self, BufRead, Write};
+use time::OffsetDateTime;
+
+use super::commands::resolve_day;
+use super::daily_log;
+use crate::models::daemon::{
+    RpcError, RpcRequest, RpcResponse, INVALID_PARAMS, INVALID_REQUEST, JSONRPC_VERSION,
+    METHOD_NOT_FOUND, PARSE_ERROR, SERVICE_ERROR,
+};
+use crate::models::dump::HistoryState;
+use crate::models::settings::AppSettings;
+use crate::models::weekly::PeriodData;
+use crate::services::weekly_service::{self, PeriodPreset};
+use crate::services::{feedback_service, history_service};
+use crate::utils::{format_date_key, mask_pii, parse_date_key};
+
+/// 지원하는 MCP 프로토콜 버전
+const PROTOCOL_VERSION: &str = "2024-11-05";
+const DEFAULT_SEARCH_LIMIT: usize = 50;
+const DEFAULT_FEEDBACK_LIMIT: usize = 1;
+/// `resources/list`에 보여줄 최근 날짜 수
+const LISTED_DAYS: usize = 30;
+
+const DAY_URI_PREFIX: &str = "hoego://day/";
+const PERIOD_URI_PREFIX: &str = "hoego://period/";
+
+/// `hoego mcp` 옵션
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct McpOptions {
+    /// 기록을 바꾸는 도구(append_entry, write_retro)를 숨김
+    pub read_only: bool,
+    /// 돌려주는 모든 내용에 개인정보 마스킹 적용
+    pub mask_pii: bool,
+}
+
+struct ToolSpec {
+    name: &'static str,
+    description: &'static str,
+    /// 기록을 바꾸는 도구 (읽기 전용 모드에서 제외)
+    writes: bool,
+}
+
+const TOOLS: [ToolSpec; 6] = [
+    ToolSpec {
+        name: "append_entry",
+        description: "오늘 기록에 항목 한 줄을 추가합니다 (시각은 자동으로 붙음)",
+        writes: true,
+    },
+    ToolSpec {
+        name: "get_day",
+        description: "하루 기록(마크다운)을 읽습니다",
+        writes: false,
+    },
+    ToolSpec {
+        name: "search",
+        description: "전체 기록에서 검색어가 들어간 항목을 최신순으로 찾습니다",
+        writes: false,
+    },
+    ToolSpec {
+        name: "get_week_stats",
+        description: "한 주의 카테고리별 시간 통계와 지난주 대비 변화를 가져옵니다",
+        writes: false,
+    },
+    ToolSpec {
+        name: "get_feedback",
+        description: "저장된 AI 피드백을 최신순으로 가져옵니다",
+        writes: false,
+    },
+    ToolSpec {
+        name: "write_retro",
+        description: "하루 회고를 작성합니다 (append가 true면 기존 회고 뒤에 덧붙임)",
+        writes: true,
+    },
+];
+
+const DATE_DESCRIPTION: &str = "today, yesterday, YYYY-MM-DD (기본: 오늘)";
+
+fn input_schema(tool: &str) -> Value {
+    let date = json!({ "type": "string", "description": DATE_DESCRIPTION });
+    match tool {
+        "append_entry" => json!({
+            "type": "object",
+            "properties": { "text": { "type": "string", "description": "기록할 내용 (#태그, @사람 사용 가능)" } },
+            "required": ["text"],
+        }),
+        "search" => json!({
+            "type": "object",
+            "properties": {
+                "query": { "type": "string" },
+                "limit": { "type": "integer", "minimum": 1, "default": DEFAULT_SEARCH_LIMIT },
+            },
+            "required": ["query"],
+        }),
+        "get_week_stats" => json!({
+            "type": "object",
+            "properties": {
+                "date": date,
+                "weekStart": { "type": "string", "description": "주 시작 요일 (기본: monday)" },
+            },
+        }),
+        "get_feedback" => json!({
+            "type": "object",
+            "properties": {
+                "date": date,
+                "limit": { "type": "integer", "minimum": 1, "default": DEFAULT_FEEDBACK_LIMIT },
+            },
+        }),
+        "write_retro" => json!({
+            "type": "object",
+            "properties": {
+                "date": date,
+                "content": { "type": "string", "description": "회고 내용 (마크다운)" },
+                "append": { "type": "boolean", "default": false },
+            },
+            "required": ["content"],
+        }),
+        _ => json!({
+            "type": "object",
+            "properties": { "date": date },
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolCallParams {
+    name: String,
+    #[serde(default)]
+    arguments: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResourceReadParams {
+    uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AppendArgs {
+    text: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DayArgs {
+    date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WeekStatsArgs {
+    date: Option<String>,
+    week_start: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FeedbackArgs {
+    date: Option<String>,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RetroArgs {
+    date: Option<String>,
+    content: String,
+    #[serde(default)]
+    append: bool,
+}
+
+fn parse_params<T: DeserializeOwned>(params: &Value) -> Result<T, RpcError> {
+    let params = if params.is_null() {
+        json!({})
+    } else {
+        params.clone()
+    };
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("인자가 올바르지 않습니다: {}", e)))
+}
+
+fn iso_date(date: &OffsetDateTime) -> String {
+    date.date().to_string()
+}
+
+/// MCP 요청을 처리하는 서버 (전송 방식과 무관)
+pub struct McpServer {
+    state: HistoryState,
+    settings: AppSettings,
+    options: McpOptions,
+}
+
+impl McpServer {
+    pub fn new(state: HistoryState, settings: AppSettings, options: McpOptions) -> Self {
+        Self {
+            state,
+            settings,
+            options,
+        }
+    }
+
+    /// 텍스트 결과는 여기서, JSON 결과는 `json_text`에서 한 번만 마스킹
+    fn mask(&self, text: String) -> String {
+        if self.options.mask_pii {
+            mask_pii(&text, false)
+        } else {
+            text
+        }
+    }
+
+    /// JSON 안의 문자열 값만 마스킹 (구조는 유지)
+    fn mask_value(&self, value: Value) -> Value {
+        if !self.options.mask_pii {
+            return value;
+        }
+        match value {
+            Value::String(text) => Value::String(mask_pii(&text, false)),
+            Value::Array(items) => {
+                Value::Array(items.into_iter().map(|v| self.mask_value(v)).collect())
+            }
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, v)| (key, self.mask_value(v)))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    fn json_text<T: serde::Serialize>(&self, value: &T) -> Result<String, String> {
+        let value = serde_json::to_value(value).map_err(|e| format!("JSON 변환 실패: {}", e))?;
+        serde_json::to_string_pretty(&self.mask_value(value))
+            .map_err(|e| format!("JSON 변환 실패: {}", e))
+    }
+
+    fn tool(&self, name: &str) -> Option<&'static ToolSpec> {
+        TOOLS
+            .iter()
+            .find(|tool| tool.name == name && !(tool.writes && self.options.read_only))
+    }
+
+    /// 한 줄을 처리하고 보낼 응답을 돌려줍니다 (알림에는 응답 없음)
+    pub fn handle_line(&self, line: &str) -> Option<RpcResponse> {
+        let request: RpcRequest = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(error) => {
+                return Some(RpcResponse::new(
+                    Value::Null,
+                    Err(RpcError::new(
+                        PARSE_ERROR,
+                        format!("JSON 파싱 실패: {}", error),
+                    )),
+                ))
+            }
+        };
+        let result = self.handle_request(&request);
+        request.id.map(|id| RpcResponse::new(id, result))
+    }
+
+    fn handle_request(&self, request: &RpcRequest) -> Result<Value, RpcError> {
+        if !request.jsonrpc.is_empty() && request.jsonrpc != JSONRPC_VERSION {
+            return Err(RpcError::new(
+                INVALID_REQUEST,
+                "jsonrpc는 \"2.0\"이어야 합니다",
+            ));
+        }
+
+        match request.method.as_str() {
+            "initialize" => Ok(self.initialize()),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": self.list_tools() })),
+            "tools/call" => {
+                let params: ToolCallParams = parse_params(&request.params)?;
+                self.call_tool(&params.name, &params.arguments)
+            }
+            "resources/list" => self.list_resources(),
+            "resources/templates/list" => Ok(json!({ "resourceTemplates": resource_templates() })),
+            "resources/read" => {
+                let params: ResourceReadParams = parse_params(&request.params)?;
+                self.read_resource(&params.uri)
+            }
+            method if method.starts_with("notifications/") => Ok(Value::Null),
+            method => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("알 수 없는 메서드: {}", method),
+            )),
+        }
+    }
+
+    fn initialize(&self) -> Value {
+        let mut instructions = String::from(
+            "Hoego 업무 기록입니다. 하루 기록은 \"- 내용 (HH:MM:SS)\" 형식의 마크다운이며 \
+             #태그와 @사람이 들어갈 수 있습니다.",
+        );
+        if self.options.read_only {
+            instructions.push_str(" 읽기 전용 모드라 기록을 바꾸는 도구는 없습니다.");
+        }
+        if self.options.mask_pii {
+            instructions.push_str(" 개인정보는 [EMAIL] 같은 표시로 가려져 있습니다.");
+        }
+
+        json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": { "tools": {}, "resources": {} },
+            "serverInfo": { "name": "hoego", "version": env!("CARGO_PKG_VERSION") },
+            "instructions": instructions,
+        })
+    }
+
+    fn list_tools(&self) -> Vec<Value> {
+        TOOLS
+            .iter()
+            .filter(|tool| !(tool.writes && self.options.read_only))
+            .map(|tool| {
+                json!({
+                    "name": tool.name,
+                    "description": tool.description,
+                    "inputSchema": input_schema(tool.name),
+                })
+            })
+            .collect()
+    }
+
+    /// 도구 실행 결과; 서비스 오류는 `isError`로 어시스턴트에게 그대로 전달
+    fn call_tool(&self, name: &str, arguments: &Value) -> Result<Value, RpcError> {
+        let tool = self.tool(name).ok_or_else(|| {
+            let reason = if self.options.read_only && TOOLS.iter().any(|t| t.name == name) {
+                "읽기 전용 모드에서는 쓸 수 없는 도구입니다"
+            } else {
+                "알 수 없는 도구입니다"
+            };
+            RpcError::new(INVALID_PARAMS, format!("{}: {}", reason, name))
+        })?;
+
+        let output = match tool.name {
+            "append_entry" => self.append_entry(parse_params(arguments)?),
+            "get_day" => self.get_day(parse_params(arguments)?),
+            "search" => self.search(parse_params(arguments)?),
+            "get_week_stats" => self.get_week_stats(parse_params(arguments)?),
+            "get_feedback" => self.get_feedback(parse_params(arguments)?),
+            _ => self.write_retro(parse_params(arguments)?),
+        };
+
+        let (text, is_error) = match output {
+            Ok(text) => (text, false),
+            Err(error) => (self.mask(error), true),
+        };
+        Ok(json!({
+            "content": [{ "type": "text", "text": text }],
+            "isError": is_error,
+        }))
+    }
+
+    fn append_entry(&self, args: AppendArgs) -> Result<String, String> {
+        let text = args.text.trim();
+        if text.is_empty() {
+            return Err("text가 비어 있습니다".to_string());
+        }
+        // 데몬(앱)이 실행 중이면 데몬을 통해 기록해 화면도 바로 갱신
+        let (_, line) = daily_log::append_entry(&self.state, text)?;
+        Ok(self.mask(format!("기록했습니다: {}", line)))
+    }
+
+    fn day_markdown(&self, day: &OffsetDateTime) -> Result<String, String> {
+        let content = weekly_service::load_daily_dump(&self.state, day)?;
+        if content.trim().is_empty() {
+            return Ok(format!("{} 기록이 없습니다", iso_date(day)));
+        }
+        Ok(self.mask(content))
+    }
+
+    fn get_day(&self, args: DayArgs) -> Result<String, String> {
+        self.day_markdown(&resolve_day(args.date.as_deref())?)
+    }
+
+    fn search(&self, args: SearchArgs) -> Result<String, String> {
+        let limit = args.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        let hits = history_service::search_entries(&self.state, &args.query, limit)?;
+        self.json_text(&hits)
+    }
+
+    fn period_stats(
+        &self,
+        preset: PeriodPreset,
+        date: Option<&str>,
+        week_start: Option<&str>,
+    ) -> Result<PeriodData, String> {
+        let week_start_day = weekly_service::parse_week_start_day(week_start.unwrap_or("monday"))?;
+        let date = resolve_day(date)?;
+        let (start, end) = weekly_service::resolve_period(preset, &date, None, week_start_day)?;
+        weekly_service::build_period_data(
+            &self.state,
+            preset,
+            &start,
+            &end,
+            week_start_day,
+            &self.settings.time_tracking,
+        )
+    }
+
+    fn get_week_stats(&self, args: WeekStatsArgs) -> Result<String, String> {
+        let data = self.period_stats(
+            PeriodPreset::Week,
+            args.date.as_deref(),
+            args.week_start.as_deref(),
+        )?;
+        self.json_text(&data)
+    }
+
+    fn get_feedback(&self, args: FeedbackArgs) -> Result<String, String> {
+        let day = resolve_day(args.date.as_deref())?;
+        let limit = args.limit.unwrap_or(DEFAULT_FEEDBACK_LIMIT);
+        let summaries = feedback_service::list_summaries(&format_date_key(&day)?, limit)?;
+        if summaries.is_empty() {
+            return Ok(format!("{} AI 피드백이 없습니다", iso_date(&day)));
+        }
+        let content = summaries
+            .into_iter()
+            .map(|summary| summary.content)
+            .collect::<Vec<_>>()
+            .join("\n\n---\n\n");
+        Ok(self.mask(content))
+    }
+
+    fn write_retro(&self, args: RetroArgs) -> Result<String, String> {
+        let day = resolve_day(args.date.as_deref())?;
+        let date_key = format_date_key(&day)?;
+        let mut content = args.content;
+        if args.append {
+            let existing = history_service::load_retrospect(&self.state, &date_key)?;
+            if !existing.trim().is_empty() {
+                content = format!("{}\n\n{}", existing.trim_end(), content);
+            }
+        }
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        history_service::save_retrospect(&self.state, &date_key, &content)?;
+        Ok(format!("{} 회고를 저장했습니다", iso_date(&day)))
+    }
+
+    fn list_resources(&self) -> Result<Value, RpcError> {
+        let internal = |e: String| RpcError::new(SERVICE_ERROR, e);
+        let today = resolve_day(None).map_err(internal)?;
+        let mut resources = vec![
+            json!({
+                "uri": format!("{}week/{}", PERIOD_URI_PREFIX, iso_date(&today)),
+                "name": "이번 주 통계",
+                "mimeType": "application/json",
+            }),
+            json!({
+                "uri": format!("{}month/{}", PERIOD_URI_PREFIX, iso_date(&today)),
+                "name": "이번 달 통계",
+                "mimeType": "application/json",
+            }),
+        ];
+
+        let overview = history_service::collect_history(&self.state).map_err(internal)?;
+        for file in overview.files.into_iter().take(LISTED_DAYS) {
+            let Ok(day) = parse_date_key(&file.date) else {
+                continue;
+            };
+            resources.push(json!({
+                "uri": format!("{}{}", DAY_URI_PREFIX, iso_date(&day)),
+                "name": file.title,
+                "description": file.preview.map(|preview| self.mask(preview)),
+                "mimeType": "text/markdown",
+            }));
+        }
+        Ok(json!({ "resources": resources }))
+    }
+
+    fn read_resource(&self, uri: &str) -> Result<Value, RpcError> {
+        let (text, mime_type) = match parse_resource_uri(uri) {
+            Some(ResourceUri::Day(date)) => (
+                resolve_day(Some(date)).and_then(|day| self.day_markdown(&day)),
+                "text/markdown",
+            ),
+            Some(ResourceUri::Period(preset, date)) => (
+                self.period_stats(preset, Some(date), None)
+                    .and_then(|data| self.json_text(&data)),
+                "application/json",
+            ),
+            None => {
+                return Err(RpcError::new(
+                    INVALID_PARAMS,
+                    format!("알 수 없는 리소스: {}", uri),
+                ))
+            }
+        };
+        let text = text.map_err(|e| RpcError::new(SERVICE_ERROR, self.mask(e)))?;
+
+        Ok(json!({
+            "contents": [{ "uri": uri, "mimeType": mime_type, "text": text }],
+        }))
+    }
+}
+
+fn resource_templates() -> Value {
+    json!([
+        {
+            "uriTemplate": format!("{}{{date}}", DAY_URI_PREFIX),
+            "name": "하루 기록",
+            "description": "YYYY-MM-DD 날짜의 기록 (마크다운)",
+            "mimeType": "text/markdown",
+        },
+        {
+            "uriTemplate": format!("{}{{preset}}/{{date}}", PERIOD_URI_PREFIX),
+            "name": "기간 통계",
+            "description": "preset은 week, month, year; date가 들어간 기간의 통계",
+            "mimeType": "application/json",
+        },
+    ])
+}
+
+#[derive(Debug, PartialEq)]
+enum ResourceUri<'a> {
+    Day(&'a str),
+    Period(PeriodPreset, &'a str),
+}
+
+fn parse_resource_uri(uri: &str) -> Option<ResourceUri<'_>> {
+    if let Some(date) = uri.strip_prefix(DAY_URI_PREFIX) {
+        return (!date.is_empty()).then_some(ResourceUri::Day(date));
+    }
+    let (preset, date) = uri.strip_prefix(PERIOD_URI_PREFIX)?.split_once('/')?;
+    let preset = match weekly_service::parse_period_preset(preset).ok()? {
+        PeriodPreset::Custom => return None,
+        preset => preset,
+    };
+    (!date.is_empty()).then_some(ResourceUri::Period(preset, date))
+}
+
+/// stdin이 닫힐 때까지 MCP 요청을 처리합니다 (stdout에는 응답만 씀)
+pub fn serve(
+    state: HistoryState,
+    settings: AppSettings,
+    options: McpOptions,
+) -> Result<(), String> {
+    let server = McpServer::new(state, settings, options);
+    let stdin = io::stdin();
+    let mut stdout = io::stdout().lock();
+
+    for line in stdin.lock().lines() {
+        let line = line.map_err(|e| format!("입력 읽기 실패: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some(response) = server.handle_line(&line) else {
+            continue;
+        };
+        let message =
+            serde_json::to_string(&response).map_err(|e| format!("JSON 변환 실패: {}", e))?;
+        writeln!(stdout, "{}", message)
+            .and_then(|_| stdout.flush())
+            .map_err(|e| format!("출력 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(options: McpOptions) -> McpServer {
+        McpServer::new(
+            HistoryState {
+                directory: std::env::temp_dir().join("hoego-mcp-test"),
+            },
+            AppSettings::default(),
+            options,
+        )
+    }
+
+    fn call(server: &McpServer, method: &str, params: Value) -> RpcResponse {
+        let line = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        server.handle_line(&line.to_string()).unwrap()
+    }
+
+    #[test]
+    fn read_only_hides_write_tools() {
+        let names = |server: &McpServer| -> Vec<String> {
+            call(server, "tools/list", Value::Null).result.unwrap()["tools"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|tool| tool["name"].as_str().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(names(&server(McpOptions::default())).len(), 6);
+
+        let read_only = server(McpOptions {
+            read_only: true,
+            mask_pii: false,
+        });
+        let tools = names(&read_only);
+        assert!(!tools.contains(&"append_entry".to_string()));
+        assert!(!tools.contains(&"write_retro".to_string()));
+
+        let response = call(
+            &read_only,
+            "tools/call",
+            json!({ "name": "append_entry", "arguments": { "text": "x" } }),
+        );
+        assert_eq!(response.error.unwrap().code, INVALID_PARAMS);
+
+        // 알림에는 응답하지 않음
+        assert!(read_only
+            .handle_line(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#)
+            .is_none());
+    }
+
+    #[test]
+    fn masks_string_values_only() {
+        let masking = server(McpOptions {
+            read_only: false,
+            mask_pii: true,
+        });
+        let masked =
+            masking.mask_value(json!({ "line": "- mail kim@example.com (10:00:00)", "count": 2 }));
+        assert_eq!(masked["count"], 2);
+        assert!(!masked["line"].as_str().unwrap().contains("kim@example.com"));
+
+        let plain = server(McpOptions::default());
+        assert_eq!(plain.mask("kim@example.com".to_string()), "kim@example.com");
+    }
+
+    #[test]
+    fn parses_resource_uris() {
+        assert_eq!(
+            parse_resource_uri("hoego://day/2024-01-02"),
+            Some(ResourceUri::Day("2024-01-02"))
+        );
+        assert_eq!(
+            parse_resource_uri("hoego://period/month/2024-01-02"),
+            Some(ResourceUri::Period(PeriodPreset::Month, "2024-01-02"))
+        );
+        assert_eq!(parse_resource_uri("hoego://period/custom/2024-01-02"), None);
+        assert_eq!(parse_resource_uri("hoego://day/"), None);
+        assert_eq!(parse_resource_uri("file:///etc/passwd"), None);
+    }
+}
@@ -1,48 +1,253 @@
+pub mod args;
+pub mod browser;
+pub mod commands;
 pub mod daily_log;
+pub mod feedback_stream;
+pub mod input_history;
+pub mod mcp;
+pub mod palette;
+pub mod stderr_log;
 pub mod tui;
 
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
+use crate::models::dump::HistoryState;
+use crate::services::session_service::{self, SessionMarker};
+use crate::services::{
+    feedback_service, focus_service, history_service, hook_service, storage_service,
+};
+use crate::utils::{current_local_time, format_date_key};
+use args::{CliCommand, OutputFormat};
+use palette::PaletteCommand;
+
 /// CLI 인자 구조체
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct LogCliArgs {
     pub session_title: Option<String>,
     pub show_help: bool,
 }
 
-impl LogCliArgs {
-    /// 환경 변수에서 CLI 인자를 파싱합니다
-    pub fn from_env() -> Self {
-        let args: Vec<String> = std::env::args().collect();
-        let mut session_title = None;
-        let mut show_help = false;
-
-        // 간단한 인자 파싱
-        // hoego
-        // hoego --session "세션 제목"
-        // hoego --help
-        let mut i = 1; // 프로그램 이름 다음부터 시작
-        while i < args.len() {
-            match args[i].as_str() {
-                "--help" | "-h" => {
-                    show_help = true;
-                    i += 1;
+/// TUI가 실행되는 동안 유지하는 기록 상태
+struct DailyLog {
+    state: HistoryState,
+    file_path: PathBuf,
+    /// `:undo`로 지울 수 있는 마지막으로 추가한 항목
+    last_entry: Option<String>,
+}
+
+impl DailyLog {
+    /// 평문 입력 → 로그 항목 추가 (GUI와 같은 저장 로직, 자정이 지나면 새 파일)
+    fn append(&mut self, text: &str) -> Result<Option<String>, String> {
+        let (file_path, line) = daily_log::append_entry(&self.state, text)?;
+        self.file_path = file_path;
+        self.last_entry = Some(line);
+        Ok(None)
+    }
+
+    /// 뒤에서 `nth`번째 (1부터) 항목 줄
+    fn recent_entry(&self, nth: usize) -> Result<String, String> {
+        let lines = daily_log::read_last_n_lines(&self.file_path, usize::MAX)?;
+        lines
+            .into_iter()
+            .rev()
+            .filter(|line| history_service::entry_time_label(line).is_some())
+            .nth(nth - 1)
+            .ok_or_else(|| format!("{}번째 항목이 없습니다", nth))
+    }
+
+    fn replace_line(&mut self, old_line: &str, new_line: Option<&str>) -> Result<(), String> {
+        if !history_service::replace_entry_line(&self.file_path, old_line, new_line)? {
+            return Err("항목이 이미 바뀌어 수정할 수 없습니다".to_string());
+        }
+        if self.last_entry.as_deref() == Some(old_line) {
+            self.last_entry = new_line.map(str::to_string);
+        }
+        Ok(())
+    }
+
+    /// 세션 마커를 기록합니다 (일시정지·재개·종료는 진행 중인 세션 제목을 씀)
+    fn record_session(&mut self, marker: SessionMarker, title: Option<&str>) -> Result<Option<String>, String> {
+        let result = daily_log::record_session(&self.state, marker, title)?;
+        self.file_path = daily_log::get_today_file_path()?.0;
+
+        let title = result.title;
+        let message = match marker {
+            SessionMarker::Start => format!("세션 시작: {}", title),
+            SessionMarker::Pause => format!("세션 일시정지: {}", title),
+            SessionMarker::Resume => format!("세션 재개: {}", title),
+            SessionMarker::End => match result.session {
+                Some(session) => format!("세션 종료 · {}", session_service::format_session_summary(&session)),
+                None => format!("세션 종료: {}", title),
+            },
+        };
+        Ok(Some(message))
+    }
+
+    /// `:edit`로 고친 내용을 원래 시간 그대로 저장합니다
+    fn finish_edit(&mut self, target: &tui::EditTarget, text: &str) -> Result<Option<String>, String> {
+        let new_line = history_service::entry_line(text.trim(), &target.time_label);
+        self.replace_line(&target.line, Some(&new_line))?;
+        Ok(Some("항목을 수정했습니다".to_string()))
+    }
+
+    fn run_command(
+        &mut self,
+        command: PaletteCommand,
+        app: &mut tui::TuiApp,
+        terminal: &mut tui::TuiTerminal,
+    ) -> Result<Option<String>, String> {
+        match command {
+            PaletteCommand::Quit => Ok(None),
+            PaletteCommand::Help => {
+                app.show_help = true;
+                Ok(None)
+            }
+            PaletteCommand::Session(title) => self.record_session(SessionMarker::Start, Some(&title)),
+            PaletteCommand::PauseSession => self.record_session(SessionMarker::Pause, None),
+            PaletteCommand::ResumeSession => self.record_session(SessionMarker::Resume, None),
+            PaletteCommand::EndSession => self.record_session(SessionMarker::End, None),
+            PaletteCommand::Sessions => {
+                let sessions = session_service::load_sessions(&self.state, &current_local_time()?)?;
+                if sessions.is_empty() {
+                    return Err("오늘 기록된 세션이 없습니다".to_string());
                 }
-                "--session" => {
-                    if i + 1 < args.len() {
-                        session_title = Some(args[i + 1].clone());
-                        i += 2;
-                    } else {
-                        eprintln!("경고: --session 옵션에 제목이 필요합니다");
-                        i += 1;
+                let total: i64 = sessions.iter().map(|session| session.active_seconds).sum();
+                let mut lines: Vec<String> = sessions
+                    .iter()
+                    .map(|session| {
+                        format!(
+                            "- {} ({}–{})",
+                            session_service::format_session_summary(session),
+                            session.started_at,
+                            session.ended_at.as_deref().unwrap_or("지금")
+                        )
+                    })
+                    .collect();
+                lines.push(String::new());
+                lines.push(format!("합계 {}", session_service::format_duration(total)));
+                app.open_side_pane(format!("오늘 세션 ({}개)", sessions.len()), lines);
+                Ok(None)
+            }
+            PaletteCommand::Undo => {
+                let line = self.last_entry.clone().ok_or("되돌릴 항목이 없습니다")?;
+                self.replace_line(&line, None)?;
+                Ok(Some(format!("삭제됨: {}", line)))
+            }
+            PaletteCommand::Edit(nth) => {
+                let line = self.recent_entry(nth)?;
+                let time_label = history_service::entry_time_label(&line)
+                    .unwrap_or_default()
+                    .to_string();
+                let text = line
+                    .strip_prefix("- ")
+                    .and_then(|body| body.trim_end().strip_suffix(&format!(" ({})", time_label)))
+                    .unwrap_or(&line)
+                    .to_string();
+                app.start_edit(tui::EditTarget { line, time_label }, &text);
+                Ok(None)
+            }
+            PaletteCommand::Tag(tags) => {
+                let line = self.recent_entry(1)?;
+                let time_label = history_service::entry_time_label(&line).unwrap_or_default();
+                let suffix = format!(" ({})", time_label);
+                let text = line
+                    .trim_end()
+                    .strip_prefix("- ")
+                    .and_then(|body| body.strip_suffix(&suffix))
+                    .unwrap_or(&line);
+                let tagged = history_service::entry_line(&format!("{} {}", text, tags.join(" ")), time_label);
+                self.replace_line(&line, Some(&tagged))?;
+                Ok(Some(format!("태그 추가: {}", tags.join(" "))))
+            }
+            PaletteCommand::Retro => {
+                // 편집기가 터미널을 쓰도록 TUI를 잠시 내려놓음
+                tui::restore_terminal(terminal)?;
+                let edited = current_local_time()
+                    .and_then(|now| commands::edit_retrospect(&self.state, &now));
+                *terminal = tui::setup_terminal()?;
+                let path = edited?;
+                Ok(Some(format!("회고 저장: {}", path.display())))
+            }
+            PaletteCommand::Feedback { generate: true } => {
+                app.start_feedback_stream(current_local_time()?);
+                Ok(Some("AI 피드백 생성 시작 · Esc 취소".to_string()))
+            }
+            PaletteCommand::Feedback { generate: false } => {
+                let date_key = format_date_key(&current_local_time()?)?;
+                let summary = feedback_service::list_summaries(&date_key, 1)?
+                    .into_iter()
+                    .next()
+                    .ok_or("오늘 저장된 AI 피드백이 없습니다")?;
+                app.open_side_pane("AI 피드백", summary.content.lines().map(str::to_string).collect());
+                Ok(None)
+            }
+            PaletteCommand::Focus { minutes, intent } => {
+                let timer = focus_service::start_focus(&self.state, &intent, minutes)?;
+                Ok(Some(format!("🍅 {} {}분 집중 시작 · :focus stop", timer.intent, minutes)))
+            }
+            PaletteCommand::FocusBreak(minutes) => {
+                focus_service::start_break(&self.state, minutes)?;
+                Ok(Some(format!("☕ {}분 휴식 시작", minutes)))
+            }
+            PaletteCommand::FocusInterrupt(note) => {
+                let timer = focus_service::record_interruption(&self.state, note.as_deref())?;
+                self.file_path = daily_log::get_today_file_path()?.0;
+                Ok(Some(format!("방해 기록 ({}회째)", timer.interruptions.len())))
+            }
+            PaletteCommand::FocusStop => {
+                let record = focus_service::stop(&self.state)?;
+                self.file_path = daily_log::get_today_file_path()?.0;
+                Ok(Some(format!("기록됨: {}", record.line)))
+            }
+            PaletteCommand::Search(query) => {
+                let hits = history_service::search_entries(&self.state, &query, 200)?;
+                if hits.is_empty() {
+                    return Err(format!("'{}' 검색 결과가 없습니다", query));
+                }
+
+                // 날짜별로 묶어서 표시
+                let mut lines = Vec::new();
+                let mut current_date = "";
+                for hit in &hits {
+                    if hit.date != current_date {
+                        if !lines.is_empty() {
+                            lines.push(String::new());
+                        }
+                        lines.push(format!("## {}", hit.date));
+                        current_date = &hit.date;
                     }
+                    lines.push(hit.line.clone());
                 }
-                _ => {
-                    i += 1;
+                app.open_side_pane(format!("검색: {} ({}건)", query, hits.len()), lines);
+                Ok(None)
+            }
+            PaletteCommand::Goto(date) => {
+                app.open_browser();
+                if let Some(browser) = app.browser.as_mut() {
+                    browser.jump_to(&date);
                 }
+                Ok(None)
+            }
+            PaletteCommand::Browse => {
+                app.open_browser();
+                Ok(None)
             }
         }
+    }
 
-        Self {
-            session_title,
-            show_help,
+    /// 파일을 다시 읽어 로그 창과 진행 중인 세션·집중 타이머 표시를 갱신합니다
+    fn refresh(&self, app: &mut tui::TuiApp) {
+        // 로그 읽기 실패 시 기존 로그 유지
+        if let Ok(updated_logs) = daily_log::read_last_n_lines(&self.file_path, 100) {
+            app.update_logs(updated_logs);
+        }
+        let content = std::fs::read_to_string(&self.file_path).unwrap_or_default();
+        app.session = session_service::current_session(&content, None);
+        // 다른 프로그램에서 시작·종료한 타이머도 반영
+        match focus_service::current_timer(&self.state) {
+            Ok(timer) => app.focus = timer,
+            Err(error) => app.set_error(error),
         }
     }
 }
@@ -51,18 +256,27 @@ impl LogCliArgs {
 pub fn run_daily_log(args: LogCliArgs) -> Result<(), String> {
     // 1. 오늘 파일 경로 확보
     let (file_path, now) = daily_log::get_today_file_path()?;
+    let mut log = DailyLog {
+        state: HistoryState::default(),
+        file_path,
+        last_entry: None,
+    };
 
-    // 2. 세션 제목이 있으면 세션 헤더 추가
-    if let Some(ref title) = args.session_title {
-        daily_log::append_session_header(&file_path, title)?;
+    // 2. 세션 제목이 있으면 세션 시작 (열려 있던 세션은 닫힘)
+    if let Some(title) = args.session_title {
+        daily_log::record_session(&log.state, SessionMarker::Start, Some(&title))?;
     }
 
     // 3. 초기 로그 읽기
-    let initial_logs = daily_log::read_last_n_lines(&file_path, 100)
+    let initial_logs = daily_log::read_last_n_lines(&log.file_path, 100)
         .unwrap_or_else(|_| Vec::new());
 
     // 4. TUI 앱 생성 (should_scroll_to_bottom이 자동으로 true로 설정됨)
-    let mut app = tui::TuiApp::new(&now, file_path.to_string_lossy().to_string(), initial_logs);
+    let mut app = tui::TuiApp::new(&now, log.file_path.to_string_lossy().to_string(), initial_logs);
+    app.history = input_history::InputHistory::load();
+    app.mentions = history_service::collect_mentions(&log.state).unwrap_or_default();
+    app.snippets = storage_service::load_settings().unwrap_or_default().snippets;
+    log.refresh(&mut app);
 
     // 5. 터미널 설정
     let mut terminal = tui::setup_terminal()?;
@@ -71,42 +285,26 @@ pub fn run_daily_log(args: LogCliArgs) -> Result<(), String> {
     let result = loop {
         match tui::run_tui_loop(&mut terminal, &mut app) {
             Ok(Some(input)) => {
-                // 명령 처리
-                if input.starts_with(':') {
-                    match input.as_str() {
-                        ":q" | ":quit" => {
-                            break Ok(());
-                        }
-                        ":help" | ":h" => {
-                            // TUI 모드에서는 헬프 메시지를 로그에 추가
-                            continue;
-                        }
-                        _ => {
-                            // 알 수 없는 명령은 무시
-                            continue;
-                        }
+                let outcome = if let Some(target) = app.editing.take() {
+                    log.finish_edit(&target, &input)
+                } else if input.starts_with(':') {
+                    // 명령 처리 (오류는 입력창 위에 표시)
+                    match palette::parse_command(&input) {
+                        Ok(PaletteCommand::Quit) => break Ok(()),
+                        Ok(command) => log.run_command(command, &mut app, &mut terminal),
+                        Err(error) => Err(error),
                     }
                 } else {
-                    // 평문 입력 → 로그 항목 추가
-                    match daily_log::append_log_entry(&file_path, &input) {
-                        Ok(_) => {
-                            // 로그 갱신 (update_logs가 자동으로 should_scroll_to_bottom을 설정)
-                            match daily_log::read_last_n_lines(&file_path, 100) {
-                                Ok(updated_logs) => {
-                                    app.update_logs(updated_logs);
-                                }
-                                Err(_e) => {
-                                    // 로그 읽기 실패 시 기존 로그 유지
-                                    continue;
-                                }
-                            }
-                        }
-                        Err(_e) => {
-                            // 에러 처리 (TUI에서는 무시)
-                            continue;
-                        }
-                    }
+                    log.append(&input)
+                };
+
+                match outcome {
+                    Ok(Some(message)) => app.set_info(message),
+                    Ok(None) => {}
+                    Err(error) => app.set_error(error),
                 }
+                // 로그 갱신 (update_logs가 자동으로 should_scroll_to_bottom을 설정)
+                log.refresh(&mut app);
             }
             Ok(None) => {
                 // 종료
@@ -125,3 +323,21 @@ pub fn run_daily_log(args: LogCliArgs) -> Result<(), String> {
 
     result
 }
+
+/// hoego-cli 진입점: 인자를 해석해 TUI 또는 하위 명령을 실행합니다
+pub fn run(raw_args: &[String]) -> Result<(), String> {
+    let mut args = args::parse_args(raw_args)?;
+
+    // 파이프로 연결된 출력에는 색상을 쓰지 않음
+    if args.format == OutputFormat::Pretty && !std::io::stdout().is_terminal() {
+        args.format = OutputFormat::Plain;
+    }
+
+    let result = match args.command {
+        CliCommand::Tui(log_args) => run_daily_log(log_args),
+        command => commands::run(command, args.format),
+    };
+    // 기록이 일으킨 훅이 끝날 때까지 기다림 (훅마다 제한 시간이 있음)
+    hook_service::wait_pending();
+    result
+}
@@ -0,0 +1,262 @@
+// cli/palette.rs
+// Daily Log TUI의 `:` 명령 해석과 자동 완성
+
+use crate::services::focus_service::{DEFAULT_BREAK_MINUTES, DEFAULT_FOCUS_MINUTES};
+
+/// TUI에서 실행할 수 있는 명령
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteCommand {
+    Quit,
+    Help,
+    Session(String),
+    PauseSession,
+    ResumeSession,
+    EndSession,
+    /// 오늘 세션 목록과 시간
+    Sessions,
+    /// 마지막으로 추가한 항목 삭제
+    Undo,
+    /// 최근 N번째 항목 수정 (1 = 가장 최근)
+    Edit(usize),
+    /// 가장 최근 항목에 태그 추가
+    Tag(Vec<String>),
+    Retro,
+    /// AI 피드백 생성 (false면 저장된 피드백 보기)
+    Feedback { generate: bool },
+    /// 집중 타이머 시작 (분, 작업명)
+    Focus { minutes: i64, intent: String },
+    /// 휴식 타이머 시작 (분)
+    FocusBreak(i64),
+    /// 집중 중 방해 기록 (메모)
+    FocusInterrupt(Option<String>),
+    /// 타이머를 일찍 끝내고 지금까지의 시간을 기록
+    FocusStop,
+    Search(String),
+    Goto(String),
+    Browse,
+}
+
+/// 명령 이름, 사용법, 설명 (도움말과 자동 완성에 사용)
+pub const COMMANDS: &[(&str, &str, &str)] = &[
+    ("session", ":session <제목>", "새 세션 시작"),
+    ("pause", ":pause", "현재 세션 일시정지"),
+    ("resume", ":resume", "일시정지한 세션 재개"),
+    ("end", ":end", "현재 세션 종료"),
+    ("sessions", ":sessions", "오늘 세션별 시간과 항목 수"),
+    ("undo", ":undo", "마지막으로 추가한 항목 삭제"),
+    ("edit", ":edit [N]", "최근 N번째 항목 수정 (기본 1)"),
+    ("tag", ":tag <태그...>", "가장 최근 항목에 #태그 추가"),
+    ("retro", ":retro", "오늘 회고를 $EDITOR로 작성"),
+    ("feedback", ":feedback [last]", "AI 피드백 생성 (last: 저장된 피드백)"),
+    ("focus", ":focus [분] <작업명>", "집중 타이머 (break [분] · interrupt [메모] · stop)"),
+    ("search", ":search <검색어>", "모든 기록에서 검색"),
+    ("goto", ":goto <날짜>", "기록 브라우저에서 날짜로 이동"),
+    ("browse", ":browse", "기록 브라우저 (Ctrl+B)"),
+    ("help", ":help", "도움말"),
+    ("quit", ":quit", "종료"),
+];
+
+/// 짧은 별칭
+fn canonical_name(name: &str) -> &str {
+    match name {
+        "q" => "quit",
+        "h" | "?" => "help",
+        "s" => "session",
+        "u" => "undo",
+        "e" => "edit",
+        "t" => "tag",
+        "f" => "feedback",
+        "b" => "browse",
+        "/" => "search",
+        other => other,
+    }
+}
+
+/// `:`로 시작하는 입력을 명령으로 해석합니다
+pub fn parse_command(input: &str) -> Result<PaletteCommand, String> {
+    let body = input.trim().strip_prefix(':').unwrap_or(input).trim();
+    let (name, rest) = match body.split_once(char::is_whitespace) {
+        Some((name, rest)) => (name, rest.trim()),
+        None => (body, ""),
+    };
+
+    let require = |what: &str| -> Result<String, String> {
+        if rest.is_empty() {
+            Err(format!(":{} 명령에는 {}이(가) 필요합니다", name, what))
+        } else {
+            Ok(rest.to_string())
+        }
+    };
+
+    match canonical_name(name) {
+        "quit" => Ok(PaletteCommand::Quit),
+        "help" => Ok(PaletteCommand::Help),
+        "session" => require("제목").map(PaletteCommand::Session),
+        "pause" => Ok(PaletteCommand::PauseSession),
+        "resume" => Ok(PaletteCommand::ResumeSession),
+        "end" => Ok(PaletteCommand::EndSession),
+        "sessions" => Ok(PaletteCommand::Sessions),
+        "undo" => Ok(PaletteCommand::Undo),
+        "edit" => {
+            if rest.is_empty() {
+                return Ok(PaletteCommand::Edit(1));
+            }
+            match rest.parse::<usize>() {
+                Ok(index) if index > 0 => Ok(PaletteCommand::Edit(index)),
+                _ => Err(format!(":edit 번호가 올바르지 않습니다: {}", rest)),
+            }
+        }
+        "tag" => {
+            let tags: Vec<String> = require("태그")?
+                .split_whitespace()
+                .map(|tag| tag.trim_start_matches('#'))
+                .filter(|tag| !tag.is_empty())
+                .map(|tag| format!("#{}", tag))
+                .collect();
+            if tags.is_empty() {
+                return Err(":tag 명령에는 태그가 필요합니다".to_string());
+            }
+            Ok(PaletteCommand::Tag(tags))
+        }
+        "retro" => Ok(PaletteCommand::Retro),
+        "feedback" => match rest {
+            "" => Ok(PaletteCommand::Feedback { generate: true }),
+            "last" => Ok(PaletteCommand::Feedback { generate: false }),
+            _ => Err(format!(":feedback 옵션이 올바르지 않습니다: {} (last)", rest)),
+        },
+        "focus" => parse_focus(rest),
+        "search" => require("검색어").map(PaletteCommand::Search),
+        "goto" => require("날짜").map(PaletteCommand::Goto),
+        "browse" => Ok(PaletteCommand::Browse),
+        "" => Err("명령을 입력하세요 (:help)".to_string()),
+        _ => Err(format!("알 수 없는 명령: :{} (:help 참고)", name)),
+    }
+}
+
+/// `:focus` 인자: `25 작업명`, `작업명` (기본 25분), `break [분]`, `interrupt [메모]`, `stop`
+fn parse_focus(rest: &str) -> Result<PaletteCommand, String> {
+    let (first, tail) = match rest.split_once(char::is_whitespace) {
+        Some((first, tail)) => (first, tail.trim()),
+        None => (rest, ""),
+    };
+    let minutes = |value: &str, default: i64| -> Result<i64, String> {
+        if value.is_empty() {
+            return Ok(default);
+        }
+        value
+            .parse::<i64>()
+            .ok()
+            .filter(|minutes| *minutes > 0)
+            .ok_or_else(|| format!(":focus 시간이 올바르지 않습니다: {}", value))
+    };
+
+    match first {
+        "" => Err(":focus 명령에는 작업명이 필요합니다".to_string()),
+        "stop" => Ok(PaletteCommand::FocusStop),
+        "break" => minutes(tail, DEFAULT_BREAK_MINUTES).map(PaletteCommand::FocusBreak),
+        "interrupt" => Ok(PaletteCommand::FocusInterrupt(
+            Some(tail.to_string()).filter(|note| !note.is_empty()),
+        )),
+        _ if first.chars().all(|c| c.is_ascii_digit()) => {
+            if tail.is_empty() {
+                return Err(":focus 명령에는 작업명이 필요합니다".to_string());
+            }
+            Ok(PaletteCommand::Focus {
+                minutes: minutes(first, DEFAULT_FOCUS_MINUTES)?,
+                intent: tail.to_string(),
+            })
+        }
+        _ => Ok(PaletteCommand::Focus {
+            minutes: DEFAULT_FOCUS_MINUTES,
+            intent: rest.to_string(),
+        }),
+    }
+}
+
+/// 입력 중인 명령 이름을 완성합니다
+///
+/// 후보가 하나면 완성된 입력을, 여럿이면 공통 접두사까지 채운 입력과 후보 목록을 돌려줍니다.
+pub fn complete_command(input: &str) -> Option<(String, Vec<&'static str>)> {
+    let prefix = input.strip_prefix(':')?;
+    if prefix.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let candidates: Vec<&'static str> = COMMANDS
+        .iter()
+        .map(|(name, _, _)| *name)
+        .filter(|name| name.starts_with(prefix))
+        .collect();
+    match candidates.as_slice() {
+        [] => None,
+        [only] => Some((format!(":{} ", only), Vec::new())),
+        [first, rest @ ..] => {
+            let common = rest.iter().fold(first.len(), |len, name| {
+                first
+                    .bytes()
+                    .zip(name.bytes())
+                    .take(len)
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            });
+            Some((format!(":{}", &first[..common]), candidates))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands_with_arguments_and_aliases() {
+        assert_eq!(parse_command(":q").unwrap(), PaletteCommand::Quit);
+        assert_eq!(
+            parse_command(":session  Phase 1 구현 ").unwrap(),
+            PaletteCommand::Session("Phase 1 구현".to_string())
+        );
+        assert_eq!(parse_command(":edit").unwrap(), PaletteCommand::Edit(1));
+        assert_eq!(parse_command(":e 3").unwrap(), PaletteCommand::Edit(3));
+        assert_eq!(
+            parse_command(":tag dev #리뷰").unwrap(),
+            PaletteCommand::Tag(vec!["#dev".to_string(), "#리뷰".to_string()])
+        );
+        assert_eq!(
+            parse_command(":f").unwrap(),
+            PaletteCommand::Feedback { generate: true }
+        );
+        assert_eq!(
+            parse_command(":feedback last").unwrap(),
+            PaletteCommand::Feedback { generate: false }
+        );
+        assert_eq!(
+            parse_command(":focus 50 API 설계").unwrap(),
+            PaletteCommand::Focus { minutes: 50, intent: "API 설계".to_string() }
+        );
+        assert_eq!(
+            parse_command(":focus 리뷰").unwrap(),
+            PaletteCommand::Focus { minutes: 25, intent: "리뷰".to_string() }
+        );
+        assert_eq!(parse_command(":focus break").unwrap(), PaletteCommand::FocusBreak(5));
+        assert_eq!(
+            parse_command(":focus interrupt 전화").unwrap(),
+            PaletteCommand::FocusInterrupt(Some("전화".to_string()))
+        );
+        assert_eq!(parse_command(":focus stop").unwrap(), PaletteCommand::FocusStop);
+        assert!(parse_command(":focus 25").is_err());
+        assert!(parse_command(":focus break 0").is_err());
+        assert!(parse_command(":edit 0").is_err());
+        assert!(parse_command(":search").is_err());
+        assert!(parse_command(":frobnicate").is_err());
+    }
+
+    #[test]
+    fn completes_unique_and_shared_prefixes() {
+        assert_eq!(complete_command(":fe"), Some((":feedback ".to_string(), Vec::new())));
+        let (completed, candidates) = complete_command(":s").unwrap();
+        assert_eq!(completed, ":se");
+        assert_eq!(candidates, vec!["session", "sessions", "search"]);
+        assert_eq!(complete_command(":x"), None);
+        assert_eq!(complete_command(":tag d"), None);
+    }
+}
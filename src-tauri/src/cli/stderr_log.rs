@@ -0,0 +1,88 @@
+// cli/stderr_log.rs
+// TUI가 터미널을 쓰는 동안 stderr를 파일로 돌림 (엔진·피드백 진단 출력이 화면에 섞이지 않게)
+
+#[cfg(unix)]
+mod imp {
+    use std::fs::{self, OpenOptions};
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    /// 이보다 크면 새로 시작
+    const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+    /// 돌려놓기 전까지 보관하는 원래 stderr
+    static SAVED_STDERR: Mutex<Option<OwnedFd>> = Mutex::new(None);
+
+    /// stderr 로그 파일 경로 (데이터 디렉토리/hoego/cli_stderr.log)
+    fn log_file_path() -> Result<PathBuf, String> {
+        let data_dir =
+            dirs::data_dir().ok_or_else(|| "데이터 디렉토리를 찾을 수 없습니다".to_string())?;
+        let app_data_dir = data_dir.join("hoego");
+        fs::create_dir_all(&app_data_dir)
+            .map_err(|e| format!("데이터 디렉토리 생성 실패: {}", e))?;
+        Ok(app_data_dir.join("cli_stderr.log"))
+    }
+
+    pub fn redirect() -> Result<(), String> {
+        let mut saved = SAVED_STDERR.lock().map_err(|e| e.to_string())?;
+        if saved.is_some() {
+            return Ok(());
+        }
+
+        let path = log_file_path()?;
+        let too_big = fs::metadata(&path).is_ok_and(|m| m.len() > MAX_LOG_BYTES);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(!too_big)
+            .write(true)
+            .truncate(too_big)
+            .open(&path)
+            .map_err(|e| format!("stderr 로그 파일 열기 실패: {}", e))?;
+
+        // SAFETY: dup/dup2 only duplicate descriptors; the duplicate is owned by OwnedFd
+        unsafe {
+            let original = libc::dup(libc::STDERR_FILENO);
+            if original < 0 {
+                return Err("stderr 복제 실패".to_string());
+            }
+            let original = OwnedFd::from_raw_fd(original);
+            if libc::dup2(file.as_raw_fd(), libc::STDERR_FILENO) < 0 {
+                return Err("stderr 전환 실패".to_string());
+            }
+            *saved = Some(original);
+        }
+        Ok(())
+    }
+
+    pub fn restore() {
+        let Ok(mut saved) = SAVED_STDERR.lock() else {
+            return;
+        };
+        if let Some(original) = saved.take() {
+            // SAFETY: original stays open until it is dropped after dup2
+            unsafe {
+                libc::dup2(original.as_raw_fd(), libc::STDERR_FILENO);
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod imp {
+    pub fn redirect() -> Result<(), String> {
+        Ok(())
+    }
+
+    pub fn restore() {}
+}
+
+/// stderr를 데이터 디렉토리의 `cli_stderr.log`로 돌립니다 (이미 돌려져 있으면 그대로)
+pub fn redirect() -> Result<(), String> {
+    imp::redirect()
+}
+
+/// 원래 stderr로 되돌립니다
+pub fn restore() {
+    imp::restore()
+}
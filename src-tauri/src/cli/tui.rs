@@ -1,5 +1,5 @@
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseEventKind},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -8,14 +8,70 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Terminal,
 };
 use std::io;
+use std::time::Duration;
 use time::OffsetDateTime;
 use unicode_width::UnicodeWidthStr;
 
-use crate::utils::format_date_label;
+use super::browser::{self, HistoryBrowser};
+use super::daily_log;
+use super::feedback_stream::{FeedbackStream, StreamEvent};
+use super::input_history::InputHistory;
+use super::palette;
+use super::stderr_log;
+use crate::models::dump::HistoryState;
+use crate::models::focus::{FocusPhase, FocusTimer};
+use crate::models::session::WorkSession;
+use crate::models::settings::Snippet;
+use crate::services::{focus_service, history_service, snippet_service};
+use crate::utils::{current_local_time, format_date_label};
+
+pub type TuiTerminal = Terminal<CrosstermBackend<io::Stdout>>;
+
+/// 화면 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuiMode {
+    /// 오늘 로그 + 입력창
+    Input,
+    /// 지난 기록 브라우저
+    Browse,
+}
+
+/// 입력창 위에 잠깐 표시하는 메시지
+#[derive(Debug, Clone, PartialEq)]
+pub enum TuiMessage {
+    Info(String),
+    Error(String),
+}
+
+/// 로그 옆에 띄우는 창 (AI 피드백, 검색 결과)
+pub struct SidePane {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub scroll: u16,
+    /// 새 내용이 들어오면 끝까지 따라 내려감 (피드백 생성 중)
+    pub follow: bool,
+}
+
+/// `:edit`로 수정 중인 항목
+#[derive(Debug, Clone)]
+pub struct EditTarget {
+    pub line: String,
+    pub time_label: String,
+}
+
+/// Ctrl+R 역방향 검색 상태
+#[derive(Debug, Clone, Default)]
+pub struct ReverseSearch {
+    pub query: String,
+    /// 현재 찾은 입력 기록 위치
+    pub match_index: Option<usize>,
+    /// 검색 전에 입력하던 내용 (Esc로 복원)
+    pub original: String,
+}
 
 pub struct TuiApp {
     pub input: String,
@@ -25,6 +81,23 @@ pub struct TuiApp {
     pub file_path: String,
     pub scroll_offset: usize,
     pub should_scroll_to_bottom: bool,
+    pub mode: TuiMode,
+    pub browser: Option<HistoryBrowser>,
+    pub message: Option<TuiMessage>,
+    pub show_help: bool,
+    pub side_pane: Option<SidePane>,
+    pub editing: Option<EditTarget>,
+    pub history: InputHistory,
+    pub reverse_search: Option<ReverseSearch>,
+    /// 기록에 나온 #태그와 @사람 (Tab 완성용, 많이 쓴 순)
+    pub mentions: Vec<String>,
+    pub snippets: Vec<Snippet>,
+    /// 옆 창으로 스트리밍 중인 AI 피드백
+    pub feedback_stream: Option<FeedbackStream>,
+    /// 진행 중인 작업 세션 (제목 줄에 표시)
+    pub session: Option<WorkSession>,
+    /// 진행 중인 집중·휴식 타이머 (제목 줄에 남은 시간 표시)
+    pub focus: Option<FocusTimer>,
 }
 
 impl TuiApp {
@@ -41,6 +114,19 @@ impl TuiApp {
             file_path: file_path_display,
             scroll_offset: 0,
             should_scroll_to_bottom: true, // 초기에는 최하단으로
+            mode: TuiMode::Input,
+            browser: None,
+            message: None,
+            show_help: false,
+            side_pane: None,
+            editing: None,
+            history: InputHistory::default(),
+            reverse_search: None,
+            mentions: Vec::new(),
+            snippets: Vec::new(),
+            feedback_stream: None,
+            session: None,
+            focus: None,
         }
     }
 
@@ -159,6 +245,329 @@ impl TuiApp {
         Some(message)
     }
 
+    pub fn set_info(&mut self, text: impl Into<String>) {
+        self.message = Some(TuiMessage::Info(text.into()));
+    }
+
+    pub fn set_error(&mut self, text: impl Into<String>) {
+        self.message = Some(TuiMessage::Error(text.into()));
+    }
+
+    /// 입력창에 기존 항목 내용을 채우고 수정 모드로 들어갑니다
+    pub fn start_edit(&mut self, target: EditTarget, text: &str) {
+        self.input = text.to_string();
+        self.cursor_position = self.input.len();
+        self.set_info(format!("{} 항목 수정 중 · Enter 저장 · Esc 취소", target.time_label));
+        self.editing = Some(target);
+    }
+
+    pub fn open_side_pane(&mut self, title: impl Into<String>, lines: Vec<String>) {
+        // 다른 창으로 바뀌면 진행 중인 피드백 생성은 멈춤
+        if let Some(stream) = self.feedback_stream.take() {
+            stream.cancel();
+        }
+        self.side_pane = Some(SidePane {
+            title: title.into(),
+            lines,
+            scroll: 0,
+            follow: false,
+        });
+    }
+
+    /// AI 피드백 생성을 시작하고 결과를 옆 창에 실시간으로 보여줍니다
+    pub fn start_feedback_stream(&mut self, date: OffsetDateTime) {
+        self.open_side_pane("AI 피드백 · 준비 중…", vec![String::new()]);
+        if let Some(pane) = self.side_pane.as_mut() {
+            pane.follow = true;
+        }
+        self.feedback_stream = Some(FeedbackStream::start(date));
+    }
+
+    /// 도착한 피드백 조각을 옆 창에 반영합니다
+    pub fn poll_feedback_stream(&mut self) {
+        let events = match &self.feedback_stream {
+            Some(stream) => stream.drain(),
+            None => return,
+        };
+
+        for event in events {
+            match event {
+                StreamEvent::Status(status) => {
+                    self.set_side_pane_title(format!("AI 피드백 · {}", status));
+                }
+                StreamEvent::Delta(delta) => self.append_to_side_pane(&delta),
+                StreamEvent::Saved(saved) => {
+                    self.set_side_pane_title("AI 피드백".to_string());
+                    self.set_info(format!("AI 피드백 저장: {}", saved.filename));
+                    self.feedback_stream = None;
+                }
+                StreamEvent::Cancelled => {
+                    self.feedback_stream = None;
+                }
+                StreamEvent::Failed(error) => {
+                    self.set_side_pane_title("AI 피드백 · 실패".to_string());
+                    self.set_error(error);
+                    self.feedback_stream = None;
+                }
+            }
+        }
+    }
+
+    /// 진행 중인 피드백 생성을 취소합니다 (없으면 false)
+    pub fn cancel_feedback_stream(&mut self) -> bool {
+        let Some(stream) = self.feedback_stream.take() else {
+            return false;
+        };
+        stream.cancel();
+        self.set_side_pane_title("AI 피드백 · 취소됨".to_string());
+        self.set_info("AI 피드백 생성을 취소했습니다");
+        true
+    }
+
+    /// 시간이 다 된 타이머를 기록하고 로그를 다시 읽습니다
+    pub fn poll_focus(&mut self) {
+        let due = match (&self.focus, current_local_time()) {
+            (Some(timer), Ok(now)) => now.unix_timestamp() >= timer.ends_at,
+            _ => false,
+        };
+        if !due {
+            return;
+        }
+
+        self.focus = None;
+        match focus_service::complete_if_due(&HistoryState::default()) {
+            Ok(Some(record)) => {
+                self.set_info(format!("기록됨: {}", record.line));
+                if let Ok(lines) = daily_log::get_today_file_path()
+                    .and_then(|(path, _)| daily_log::read_last_n_lines(&path, 100))
+                {
+                    self.update_logs(lines);
+                }
+            }
+            // 다른 프로그램이 이미 기록함
+            Ok(None) => {}
+            Err(error) => self.set_error(error),
+        }
+    }
+
+    fn set_side_pane_title(&mut self, title: String) {
+        if let Some(pane) = self.side_pane.as_mut() {
+            pane.title = title;
+        }
+    }
+
+    /// 스트리밍된 조각을 마지막 줄에 이어 붙입니다
+    fn append_to_side_pane(&mut self, text: &str) {
+        let Some(pane) = self.side_pane.as_mut() else {
+            return;
+        };
+        let mut parts = text.split('\n');
+        if let Some(first) = parts.next() {
+            match pane.lines.last_mut() {
+                Some(last) => last.push_str(first),
+                None => pane.lines.push(first.to_string()),
+            }
+        }
+        pane.lines.extend(parts.map(str::to_string));
+    }
+
+    pub fn set_input(&mut self, text: &str) {
+        self.input = text.to_string();
+        self.cursor_position = self.input.len();
+    }
+
+    /// 커서 앞 `start..cursor` 구간을 `replacement`로 바꿉니다
+    fn replace_before_cursor(&mut self, start: usize, replacement: &str) {
+        let cursor = self.ensure_char_boundary(self.cursor_position);
+        let mut new_input = String::with_capacity(self.input.len() + replacement.len());
+        new_input.push_str(&self.input[..start]);
+        new_input.push_str(replacement);
+        new_input.push_str(&self.input[cursor..]);
+        self.input = new_input;
+        self.cursor_position = start + replacement.len();
+    }
+
+    /// Tab: `:명령` 이름, 스니펫, `#태그`/`@사람` 순서로 완성합니다
+    pub fn complete_input(&mut self) {
+        if let Some((completed, candidates)) = palette::complete_command(&self.input) {
+            self.set_input(&completed);
+            if candidates.len() > 1 {
+                self.set_info(candidates.join("  "));
+            }
+            return;
+        }
+        if self.expand_snippet_at_cursor() {
+            return;
+        }
+        self.complete_mention();
+    }
+
+    /// 커서 바로 앞 단어가 스니펫 트리거면 펼칩니다
+    pub fn expand_snippet_at_cursor(&mut self) -> bool {
+        let cursor = self.ensure_char_boundary(self.cursor_position);
+        let Some((start, snippet)) =
+            snippet_service::trailing_trigger(&self.input[..cursor], &self.snippets)
+        else {
+            return false;
+        };
+        let expansion = snippet.expansion.clone();
+        self.replace_before_cursor(start, &expansion);
+        true
+    }
+
+    fn complete_mention(&mut self) {
+        let cursor = self.ensure_char_boundary(self.cursor_position);
+        let before = &self.input[..cursor];
+        let start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map(|(index, c)| index + c.len_utf8())
+            .unwrap_or(0);
+        let word = before[start..].to_string();
+        if !(word.starts_with('#') || word.starts_with('@')) {
+            return;
+        }
+
+        let candidates: Vec<String> = self
+            .mentions
+            .iter()
+            .filter(|mention| mention.starts_with(&word) && **mention != word)
+            .cloned()
+            .collect();
+        match candidates.as_slice() {
+            [] => self.set_info(format!("{}로 시작하는 태그가 없습니다", word)),
+            [only] => {
+                let completed = format!("{} ", only);
+                self.replace_before_cursor(start, &completed);
+            }
+            [first, rest @ ..] => {
+                // 공통 접두사까지 채우고 후보는 많이 쓴 순으로 표시
+                let common = rest.iter().fold(first.as_str(), |common, mention| {
+                    let len = common
+                        .char_indices()
+                        .zip(mention.chars())
+                        .take_while(|((_, a), b)| a == b)
+                        .last()
+                        .map_or(0, |((index, a), _)| index + a.len_utf8());
+                    &common[..len]
+                });
+                if common.len() > word.len() {
+                    let common = common.to_string();
+                    self.replace_before_cursor(start, &common);
+                }
+                let shown: Vec<&str> = candidates.iter().take(8).map(String::as_str).collect();
+                self.set_info(shown.join("  "));
+            }
+        }
+    }
+
+    /// 새로 입력한 #태그/@사람을 완성 목록에 추가합니다
+    pub fn learn_mentions(&mut self, text: &str) {
+        for mention in history_service::extract_mentions(text) {
+            if !self.mentions.iter().any(|known| known == mention) {
+                self.mentions.push(mention.to_string());
+            }
+        }
+    }
+
+    /// ↑: 이전 입력 불러오기
+    pub fn history_previous(&mut self) {
+        let current = self.input.clone();
+        if let Some(entry) = self.history.previous(&current).map(str::to_string) {
+            self.set_input(&entry);
+        }
+    }
+
+    /// ↓: 다음 입력 (마지막 다음은 작성 중이던 내용)
+    pub fn history_next(&mut self) {
+        if let Some(entry) = self.history.next() {
+            self.set_input(&entry);
+        }
+    }
+
+    /// Ctrl+R: 검색을 시작하거나 더 오래된 결과로 이동합니다
+    pub fn reverse_search_step(&mut self) {
+        match self.reverse_search.take() {
+            None => {
+                self.reverse_search = Some(ReverseSearch {
+                    original: self.input.clone(),
+                    ..ReverseSearch::default()
+                });
+            }
+            Some(mut search) => {
+                if let Some((index, entry)) = self.history.search(&search.query, search.match_index) {
+                    search.match_index = Some(index);
+                    let entry = entry.to_string();
+                    self.set_input(&entry);
+                }
+                self.reverse_search = Some(search);
+            }
+        }
+        self.show_reverse_search();
+    }
+
+    /// 검색어가 바뀌면 가장 최근 결과부터 다시 찾습니다
+    fn update_reverse_search(&mut self) {
+        let Some(search) = self.reverse_search.as_mut() else {
+            return;
+        };
+        let found = self
+            .history
+            .search(&search.query, None)
+            .map(|(index, entry)| (index, entry.to_string()));
+        search.match_index = found.as_ref().map(|(index, _)| *index);
+        if let Some((_, entry)) = found {
+            self.set_input(&entry);
+        }
+        self.show_reverse_search();
+    }
+
+    fn show_reverse_search(&mut self) {
+        if let Some(search) = &self.reverse_search {
+            let status = if search.match_index.is_some() || search.query.is_empty() {
+                "역방향 검색"
+            } else {
+                "역방향 검색 (결과 없음)"
+            };
+            self.message = Some(TuiMessage::Info(format!(
+                "({}) '{}' · Ctrl+R 이전 결과 · Esc 취소",
+                status, search.query
+            )));
+        }
+    }
+
+    /// 검색을 끝냅니다 (취소하면 원래 입력으로 복원)
+    fn finish_reverse_search(&mut self, accept: bool) {
+        if let Some(search) = self.reverse_search.take() {
+            if !accept {
+                self.set_input(&search.original);
+            }
+        }
+        self.message = None;
+    }
+
+    /// 기록 브라우저로 전환합니다 (열 때마다 목록을 다시 읽음)
+    pub fn open_browser(&mut self) {
+        match HistoryBrowser::open(HistoryState::default()) {
+            Ok(browser) => {
+                self.browser = Some(browser);
+                self.mode = TuiMode::Browse;
+            }
+            Err(error) => {
+                self.logs.push(format!("기록 브라우저를 열 수 없습니다: {}", error));
+                self.should_scroll_to_bottom = true;
+            }
+        }
+    }
+
+    /// 오늘 입력 모드로 돌아갑니다
+    pub fn close_browser(&mut self) {
+        self.mode = TuiMode::Input;
+        self.browser = None;
+        self.should_scroll_to_bottom = true;
+    }
+
     pub fn scroll_up(&mut self, lines: usize) {
         self.scroll_offset = self.scroll_offset.saturating_sub(lines);
         self.should_scroll_to_bottom = false; // 수동 스크롤 시 자동 스크롤 해제
@@ -202,6 +611,8 @@ impl TuiApp {
 }
 
 pub fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>, String> {
+    // 엔진·피드백 파이프라인의 진단 출력이 화면에 섞이지 않도록 (실패하면 그대로 터미널에 출력)
+    let _ = stderr_log::redirect();
     enable_raw_mode().map_err(|e| format!("Raw mode 활성화 실패: {}", e))?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
@@ -223,6 +634,7 @@ pub fn restore_terminal(
     terminal
         .show_cursor()
         .map_err(|e| format!("커서 표시 실패: {}", e))?;
+    stderr_log::restore();
     Ok(())
 }
 
@@ -238,6 +650,9 @@ fn run_app<B: ratatui::backend::Backend>(
     app: &mut TuiApp,
 ) -> Result<Option<String>, String> {
     loop {
+        app.poll_feedback_stream();
+        app.poll_focus();
+
         // 터미널 크기에 맞춰 스크롤 조정
         let terminal_size = terminal.size().map_err(|e| format!("터미널 크기 확인 실패: {}", e))?;
         let log_area_height = terminal_size.height.saturating_sub(5) as usize; // 입력 영역과 여백 제외
@@ -247,16 +662,73 @@ fn run_app<B: ratatui::backend::Backend>(
             .draw(|f| ui(f, app))
             .map_err(|e| format!("그리기 실패: {}", e))?;
 
-        match event::read().map_err(|e| format!("이벤트 읽기 실패: {}", e))? {
+        // 피드백 생성 중에는 키 입력이 없어도 새 조각을 그림, 타이머는 1초마다 남은 시간을 갱신
+        let tick = if app.feedback_stream.is_some() {
+            Some(Duration::from_millis(100))
+        } else if app.focus.is_some() {
+            Some(Duration::from_secs(1))
+        } else {
+            None
+        };
+        if let Some(tick) = tick {
+            if !event::poll(tick).map_err(|e| format!("이벤트 확인 실패: {}", e))? {
+                continue;
+            }
+        }
+
+        let event = event::read().map_err(|e| format!("이벤트 읽기 실패: {}", e))?;
+        if app.mode == TuiMode::Browse {
+            if handle_browse_event(app, event) {
+                return Ok(None);
+            }
+            continue;
+        }
+
+        if let Event::Key(_) = event {
+            // 도움말은 아무 키나 누르면 닫힘
+            if app.show_help {
+                app.show_help = false;
+                continue;
+            }
+            app.message = None;
+        }
+
+        if app.reverse_search.is_some() {
+            if let Event::Key(key) = event {
+                handle_reverse_search_key(app, key);
+            }
+            continue;
+        }
+
+        match event {
             Event::Key(key) => match (key.code, key.modifiers) {
                 // Ctrl+C 또는 Ctrl+D로 종료
                 (KeyCode::Char('c'), KeyModifiers::CONTROL)
                 | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
                     return Ok(None);
                 }
-                // Esc로 종료
+                // Esc: 수정 취소 → 피드백 생성 취소 → 옆 창 닫기 → 종료
                 (KeyCode::Esc, _) => {
-                    return Ok(None);
+                    if app.editing.take().is_some() {
+                        app.input.clear();
+                        app.cursor_position = 0;
+                    } else if app.cancel_feedback_stream() {
+                        // 생성된 부분은 옆 창에 남겨 둠
+                    } else if app.side_pane.take().is_none() {
+                        return Ok(None);
+                    }
+                }
+                // Tab: 명령 이름 완성
+                (KeyCode::Tab, _) => {
+                    app.complete_input();
+                }
+                // Ctrl+R: 입력 기록 역방향 검색
+                (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
+                    app.reverse_search_step();
+                }
+                // Ctrl+B: 기록 브라우저
+                (KeyCode::Char('b'), KeyModifiers::CONTROL) => {
+                    app.open_browser();
                 }
                 // Ctrl+A: 줄 맨 앞으로
                 (KeyCode::Char('a'), KeyModifiers::CONTROL) => {
@@ -305,7 +777,12 @@ fn run_app<B: ratatui::backend::Backend>(
                 }
                 // Enter로 제출
                 (KeyCode::Enter, _) => {
+                    if !app.input.starts_with(':') {
+                        app.input = snippet_service::expand_snippets(&app.input, &app.snippets);
+                    }
                     if let Some(message) = app.submit_message() {
+                        app.history.push(&message);
+                        app.learn_mentions(&message);
                         // :q 명령어 처리
                         if message == ":q" || message == ":quit" {
                             return Ok(None);
@@ -314,6 +791,13 @@ fn run_app<B: ratatui::backend::Backend>(
                     }
                 }
                 // 문자 입력
+                // 스페이스: 바로 앞 단어가 스니펫이면 펼침
+                (KeyCode::Char(' '), KeyModifiers::NONE) => {
+                    let expanded = app.expand_snippet_at_cursor();
+                    if !expanded || !app.input[..app.cursor_position].ends_with(char::is_whitespace) {
+                        app.enter_char(' ');
+                    }
+                }
                 (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
                     app.enter_char(c);
                 }
@@ -328,11 +812,31 @@ fn run_app<B: ratatui::backend::Backend>(
                 (KeyCode::Right, KeyModifiers::NONE | KeyModifiers::SHIFT) => {
                     app.move_cursor_right();
                 }
-                // 로그 스크롤 (Up/Down)
+                // 옆 창이 열려 있으면 옆 창 스크롤
+                (KeyCode::Up, KeyModifiers::NONE) if app.side_pane.is_some() => {
+                    if let Some(pane) = app.side_pane.as_mut() {
+                        pane.follow = false;
+                        pane.scroll = pane.scroll.saturating_sub(1);
+                    }
+                }
+                (KeyCode::Down, KeyModifiers::NONE) if app.side_pane.is_some() => {
+                    if let Some(pane) = app.side_pane.as_mut() {
+                        pane.follow = false;
+                        pane.scroll = pane.scroll.saturating_add(1);
+                    }
+                }
+                // 입력 기록 (Up/Down)
                 (KeyCode::Up, KeyModifiers::NONE) => {
-                    app.scroll_up(1);
+                    app.history_previous();
                 }
                 (KeyCode::Down, KeyModifiers::NONE) => {
+                    app.history_next();
+                }
+                // 로그 스크롤 (Ctrl+Up/Down)
+                (KeyCode::Up, KeyModifiers::CONTROL) => {
+                    app.scroll_up(1);
+                }
+                (KeyCode::Down, KeyModifiers::CONTROL) => {
                     app.scroll_down(1);
                 }
                 // 페이지 단위 스크롤
@@ -373,7 +877,91 @@ fn run_app<B: ratatui::backend::Backend>(
     }
 }
 
+/// 역방향 검색 중 키 입력
+fn handle_reverse_search_key(app: &mut TuiApp, key: KeyEvent) {
+    match (key.code, key.modifiers) {
+        (KeyCode::Char('r'), KeyModifiers::CONTROL) => app.reverse_search_step(),
+        (KeyCode::Esc, _)
+        | (KeyCode::Char('c'), KeyModifiers::CONTROL)
+        | (KeyCode::Char('g'), KeyModifiers::CONTROL) => app.finish_reverse_search(false),
+        (KeyCode::Backspace, _) => {
+            if let Some(search) = app.reverse_search.as_mut() {
+                search.query.pop();
+            }
+            app.update_reverse_search();
+        }
+        (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+            if let Some(search) = app.reverse_search.as_mut() {
+                search.query.push(c);
+            }
+            app.update_reverse_search();
+        }
+        // Enter, 방향키 등: 찾은 입력을 그대로 두고 편집
+        _ => app.finish_reverse_search(true),
+    }
+}
+
+/// 브라우저 모드의 키 입력을 처리합니다 (종료해야 하면 true)
+fn handle_browse_event(app: &mut TuiApp, event: Event) -> bool {
+    let Some(browser) = app.browser.as_mut() else {
+        app.close_browser();
+        return false;
+    };
+
+    match event {
+        Event::Key(key) => {
+            if key.modifiers == KeyModifiers::CONTROL
+                && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('d'))
+            {
+                return true;
+            }
+
+            // 날짜 이동 입력 중
+            if let Some(input) = browser.jump_input.as_mut() {
+                match key.code {
+                    KeyCode::Enter => browser.finish_jump(),
+                    KeyCode::Esc => browser.jump_input = None,
+                    KeyCode::Backspace => {
+                        input.pop();
+                    }
+                    KeyCode::Char(c) => input.push(c),
+                    _ => {}
+                }
+                return false;
+            }
+
+            browser.status = None;
+            match key.code {
+                KeyCode::Char('q') => return true,
+                KeyCode::Esc | KeyCode::Char('i') => app.close_browser(),
+                KeyCode::Char('[') | KeyCode::Left => browser.previous_day(),
+                KeyCode::Char(']') | KeyCode::Right => browser.next_day(),
+                KeyCode::Char('k') | KeyCode::Up => browser.scroll(-1),
+                KeyCode::Char('j') | KeyCode::Down => browser.scroll(1),
+                KeyCode::PageUp => browser.scroll(-10),
+                KeyCode::PageDown => browser.scroll(10),
+                KeyCode::Tab => browser.toggle_focus(),
+                KeyCode::Char('g') => browser.start_jump(),
+                KeyCode::Char('t') => browser.jump_to_today(),
+                _ => {}
+            }
+        }
+        Event::Mouse(mouse) => match mouse.kind {
+            MouseEventKind::ScrollUp => browser.scroll(-3),
+            MouseEventKind::ScrollDown => browser.scroll(3),
+            _ => {}
+        },
+        _ => {}
+    }
+    false
+}
+
 fn ui(f: &mut ratatui::Frame, app: &mut TuiApp) {
+    if let (TuiMode::Browse, Some(browser)) = (app.mode, app.browser.as_ref()) {
+        browser::render(f, f.area(), browser);
+        return;
+    }
+
     // 입력 텍스트 길이에 따라 필요한 줄 수 계산 (unicode width 고려)
     // 프롬프트(4칸) + 입력 영역을 고려
     let available_width = f.area().width.saturating_sub(8) as usize; // 프롬프트(4) + borders(4)
@@ -393,15 +981,26 @@ fn ui(f: &mut ratatui::Frame, app: &mut TuiApp) {
         ])
         .split(f.area());
 
+    // 옆 창이 열려 있으면 로그 영역을 반으로 나눔
+    let (log_area, pane_area) = if app.side_pane.is_some() {
+        let halves = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+        (halves[0], Some(halves[1]))
+    } else {
+        (chunks[0], None)
+    };
+
     // 상단: 로그 영역
     // 스크롤 오프셋 적용
     // Block with Borders::NONE but with title + title_bottom
     // ratatui renders: title (1 line) + content + title_bottom (1 line)
-    let log_area_height = chunks[0].height.saturating_sub(2) as usize;
+    let log_area_height = log_area.height.saturating_sub(2) as usize;
     let total_logs = app.logs.len();
 
     // 텍스트가 wrap될 수 있으므로 실제 렌더링되는 줄 수 계산
-    let content_width = chunks[0].width.saturating_sub(4) as usize; // 좌우 여백 제외
+    let content_width = log_area.width.saturating_sub(4) as usize; // 좌우 여백 제외
 
     // scroll_offset부터 화면에 채울 수 있는 만큼의 로그 가져오기
     let visible_start = app.scroll_offset.min(total_logs.saturating_sub(1));
@@ -504,19 +1103,28 @@ fn ui(f: &mut ratatui::Frame, app: &mut TuiApp) {
         .block(
             Block::default()
                 .borders(Borders::NONE)
-                .title(Span::styled(
-                    format!("  Hoego  Daily Log — {}", app.date_label),
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
-                ))
+                .title(Line::from(vec![
+                    Span::styled(
+                        format!("  Hoego  Daily Log — {}", app.date_label),
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    session_title_span(app.session.as_ref()),
+                    focus_title_span(app.focus.as_ref()),
+                ]))
                 .title_bottom(Span::styled(
                     format!("  {}{}", app.file_path, scroll_indicator),
                     Style::default().fg(Color::DarkGray),
                 )),
         );
 
-    f.render_widget(logs_paragraph, chunks[0]);
+    f.render_widget(logs_paragraph, log_area);
+
+    let streaming = app.feedback_stream.is_some();
+    if let (Some(pane), Some(area)) = (app.side_pane.as_mut(), pane_area) {
+        render_side_pane(f, area, pane, streaming);
+    }
 
     // 하단: 입력 영역을 프롬프트와 입력으로 분할
     let input_chunks = Layout::default()
@@ -528,7 +1136,8 @@ fn ui(f: &mut ratatui::Frame, app: &mut TuiApp) {
         .split(chunks[1]);
 
     // 왼쪽: 프롬프트 영역
-    let prompt_widget = Paragraph::new("> ")
+    let prompt = if app.editing.is_some() { "✎ " } else { "> " };
+    let prompt_widget = Paragraph::new(prompt)
         .style(Style::default().fg(Color::Cyan))
         .block(
             Block::default()
@@ -549,14 +1158,22 @@ fn ui(f: &mut ratatui::Frame, app: &mut TuiApp) {
         format!(" {}", app.input)
     };
 
+    // 명령 결과와 오류는 입력창 위 테두리에 표시
+    let mut input_block = Block::default()
+        .borders(Borders::TOP | Borders::BOTTOM | Borders::LEFT)
+        .border_style(Style::default().fg(Color::DarkGray));
+    if let Some(message) = &app.message {
+        let (text, color) = match message {
+            TuiMessage::Info(text) => (text, Color::Cyan),
+            TuiMessage::Error(text) => (text, Color::Red),
+        };
+        input_block = input_block.title(Span::styled(format!(" {} ", text), Style::default().fg(color)));
+    }
+
     let input_widget = Paragraph::new(padded_text)
         .style(Style::default().fg(Color::White))
         .wrap(ratatui::widgets::Wrap { trim: false })
-        .block(
-            Block::default()
-                .borders(Borders::TOP | Borders::BOTTOM | Borders::LEFT)
-                .border_style(Style::default().fg(Color::DarkGray)),
-        );
+        .block(input_block);
 
     f.render_widget(input_widget, input_chunks[1]);
 
@@ -594,9 +1211,130 @@ fn ui(f: &mut ratatui::Frame, app: &mut TuiApp) {
             input_chunks[1].y + 1,
         ));
     }
+
+    if app.show_help {
+        render_help_overlay(f);
+    }
 }
 
-fn format_log_line(line: &str) -> Line<'_> {
+/// 제목 줄 오른쪽의 세션 상태 ("▶ 제목 · 09:00:00부터", "⏸ 제목 (일시정지)")
+fn session_title_span(session: Option<&WorkSession>) -> Span<'static> {
+    match session {
+        Some(session) if session.paused => Span::styled(
+            format!("  ⏸ {} (일시정지)", session.title),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Some(session) => Span::styled(
+            format!("  ▶ {} · {}부터", session.title, session.started_at),
+            Style::default().fg(Color::Yellow),
+        ),
+        None => Span::raw(""),
+    }
+}
+
+/// 제목 줄 오른쪽의 타이머 ("🍅 작업명 12:34", "☕ 휴식 03:00")
+fn focus_title_span(timer: Option<&FocusTimer>) -> Span<'static> {
+    let Some(timer) = timer else {
+        return Span::raw("");
+    };
+    let now = current_local_time().map(|now| now.unix_timestamp()).unwrap_or(timer.ends_at);
+    let countdown = focus_service::format_countdown(focus_service::remaining_seconds(timer, now));
+    match timer.phase {
+        FocusPhase::Focus => Span::styled(
+            format!("  🍅 {} {}", timer.intent, countdown),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+        FocusPhase::Break => Span::styled(
+            format!("  ☕ {} {}", timer.intent, countdown),
+            Style::default().fg(Color::Green),
+        ),
+    }
+}
+
+fn render_side_pane(f: &mut ratatui::Frame, area: ratatui::layout::Rect, pane: &mut SidePane, streaming: bool) {
+    if pane.follow {
+        // 줄바꿈된 높이를 어림해 마지막 줄이 보이도록 스크롤
+        let width = area.width.saturating_sub(1).max(1) as usize;
+        let height: usize = pane
+            .lines
+            .iter()
+            .map(|line| line.width().div_ceil(width).max(1))
+            .sum();
+        let visible = area.height.saturating_sub(2) as usize;
+        pane.scroll = height.saturating_sub(visible).min(u16::MAX as usize) as u16;
+    }
+
+    let hint = if streaming {
+        " 생성 중 · ↑↓ 스크롤 · Esc 취소 "
+    } else {
+        " ↑↓ 스크롤 · Esc 닫기 "
+    };
+    let lines: Vec<Line> = pane.lines.iter().map(|line| format_log_line(line)).collect();
+    let widget = Paragraph::new(Text::from(lines))
+        .wrap(ratatui::widgets::Wrap { trim: false })
+        .scroll((pane.scroll, 0))
+        .block(
+            Block::default()
+                .borders(Borders::LEFT)
+                .border_style(Style::default().fg(Color::DarkGray))
+                .title(Span::styled(
+                    format!(" {} ", pane.title),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ))
+                .title_bottom(Span::styled(hint, Style::default().fg(Color::DarkGray))),
+        );
+    f.render_widget(widget, area);
+}
+
+/// 명령과 단축키 도움말 (아무 키나 누르면 닫힘)
+fn render_help_overlay(f: &mut ratatui::Frame) {
+    let key_style = Style::default().fg(Color::Cyan);
+    let mut lines: Vec<Line> = palette::COMMANDS
+        .iter()
+        .map(|(_, usage, description)| {
+            Line::from(vec![
+                Span::styled(format!("  {:<18}", usage), key_style),
+                Span::raw(description.to_string()),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    for (keys, description) in [
+        ("Tab", "명령 · 스니펫 · #태그 · @사람 완성"),
+        ("↑↓ / Ctrl+R", "이전 입력 / 입력 기록 검색"),
+        ("Ctrl+↑↓ PgUp PgDn", "로그 스크롤"),
+        ("Ctrl+B", "기록 브라우저"),
+        ("Esc", "수정 취소 / 생성 취소 / 창 닫기 / 종료"),
+    ] {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<18}", keys), key_style),
+            Span::raw(description),
+        ]));
+    }
+
+    let area = f.area();
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let width = 60.min(area.width);
+    let popup = ratatui::layout::Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, popup);
+    f.render_widget(
+        Paragraph::new(Text::from(lines)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(Span::styled(" 도움말 ", Style::default().add_modifier(Modifier::BOLD))),
+        ),
+        popup,
+    );
+}
+
+pub fn format_log_line(line: &str) -> Line<'_> {
     if line.starts_with("# ") {
         // 헤더
         Line::from(Span::styled(
@@ -613,19 +1351,18 @@ fn format_log_line(line: &str) -> Line<'_> {
         ))
     } else if line.starts_with("- ") {
         // 로그 항목: "- 내용 (HH:MM:SS)" 형식 파싱
-        if let Some(time_start) = line.rfind('(') {
-            if let Some(time_end) = line.rfind(')') {
-                let content = line[2..time_start].trim();
-                let time = &line[time_start..=time_end];
-                return Line::from(vec![
-                    Span::styled("  - ", Style::default().fg(Color::DarkGray)),
-                    Span::raw(content.to_string()),
-                    Span::styled(
-                        format!(" {}", time),
-                        Style::default().fg(Color::DarkGray),
-                    ),
-                ]);
-            }
+        let bounds = line.rfind('(').zip(line.rfind(')'));
+        if let Some((time_start, time_end)) = bounds.filter(|(start, end)| start < end) {
+            let content = line[2..time_start].trim();
+            let time = &line[time_start..=time_end];
+            return Line::from(vec![
+                Span::styled("  - ", Style::default().fg(Color::DarkGray)),
+                Span::raw(content.to_string()),
+                Span::styled(
+                    format!(" {}", time),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]);
         }
         Line::from(format!("  {}", line))
     } else if line.trim().is_empty() {
@@ -0,0 +1,97 @@
+// commands/calendar.rs
+// Calendar (.ics) export and import commands
+
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, State};
+
+use crate::models::calendar::CalendarSource;
+use crate::models::dump::HistoryState;
+use crate::models::settings::{AppSettingsState, CalendarSettings};
+use crate::services::{calendar_service, history_service, weekly_service};
+
+fn calendar_settings(settings_state: &State<'_, AppSettingsState>) -> Result<CalendarSettings, String> {
+    Ok(settings_state
+        .settings
+        .lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?
+        .calendar
+        .clone())
+}
+
+/// Export time blocks between two dates (YYYY-MM-DD, inclusive) as an `.ics` file
+///
+/// Returns the path of the written file.
+#[tauri::command]
+pub fn export_calendar_ics(
+    history_state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
+    start_date: String,
+    end_date: String,
+    merge_categories: Option<bool>,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    let start = weekly_service::parse_iso_date(&start_date)?;
+    let end = weekly_service::parse_iso_date(&end_date)?;
+    if end < start {
+        return Err("종료일이 시작일보다 빠릅니다".to_string());
+    }
+
+    let time_tracking = settings_state
+        .settings
+        .lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?
+        .time_tracking
+        .clone();
+
+    let path = calendar_service::export_ics_file(
+        history_state.inner(),
+        &start,
+        &end,
+        &time_tracking,
+        merge_categories.unwrap_or(false),
+        output_path.map(PathBuf::from),
+    )?;
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Import an `.ics` file so its events show up next to the dump
+#[tauri::command]
+pub fn import_calendar_file(path: String) -> Result<CalendarSource, String> {
+    calendar_service::import_calendar(Path::new(&path))
+}
+
+/// List imported calendars and the files in the watched folder
+#[tauri::command]
+pub fn list_calendars(settings_state: State<'_, AppSettingsState>) -> Result<Vec<CalendarSource>, String> {
+    let settings = calendar_settings(&settings_state)?;
+    calendar_service::list_calendars(&settings)
+}
+
+/// Remove an imported calendar by file name
+#[tauri::command]
+pub fn remove_calendar(name: String) -> Result<(), String> {
+    calendar_service::remove_calendar(&name)
+}
+
+/// Write a day's (YYYY-MM-DD) timed events into its dump at their start times
+///
+/// Returns the number of entries added.
+#[tauri::command]
+pub fn materialize_calendar_events(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
+    date: String,
+) -> Result<usize, String> {
+    let day = weekly_service::parse_iso_date(&date)?;
+    let settings = calendar_settings(&settings_state)?;
+
+    let inserted = calendar_service::materialize_events(history_state.inner(), &day, &settings)?;
+    if inserted > 0 {
+        if let Err(e) = history_service::emit_history_update(&app, history_state.inner()) {
+            tracing::warn!("히스토리 업데이트 이벤트 발송 실패: {}", e);
+        }
+    }
+    Ok(inserted)
+}
@@ -0,0 +1,116 @@
+// commands/category.rs
+// Category rule management for time statistics
+
+use std::sync::Arc;
+use tauri::State;
+
+use crate::models::category::{CategoryMatch, CategoryRule, EntryClassification};
+use crate::models::dump::HistoryState;
+use crate::models::settings::AppSettingsState;
+use crate::services::{category_service, llm, weekly_service};
+
+/// List category rules (built-in defaults when no rules file exists)
+#[tauri::command]
+pub fn list_category_rules() -> Result<Vec<CategoryRule>, String> {
+    category_service::load_rules()
+}
+
+/// Create or update a category rule (empty id creates a new rule)
+#[tauri::command]
+pub fn save_category_rule(rule: CategoryRule) -> Result<Vec<CategoryRule>, String> {
+    category_service::upsert_rule(rule)
+}
+
+/// Replace all category rules (e.g. after reordering)
+#[tauri::command]
+pub fn save_category_rules(rules: Vec<CategoryRule>) -> Result<(), String> {
+    category_service::save_rules(&rules)
+}
+
+/// Delete a category rule
+#[tauri::command]
+pub fn delete_category_rule(rule_id: String) -> Result<Vec<CategoryRule>, String> {
+    category_service::delete_rule(&rule_id)
+}
+
+/// Restore the built-in category rules
+#[tauri::command]
+pub fn reset_category_rules() -> Result<Vec<CategoryRule>, String> {
+    category_service::reset_rules()
+}
+
+/// Categorize a sample text and explain which rule matched
+#[tauri::command]
+pub fn test_categorize(text: String) -> Result<CategoryMatch, String> {
+    category_service::test_categorize(&text)
+}
+
+/// List cached classifications for entries no rule matched
+#[tauri::command]
+pub fn list_entry_classifications() -> Result<Vec<EntryClassification>, String> {
+    category_service::list_classifications()
+}
+
+/// Pin an entry text to a category (overrides rules and model suggestions)
+#[tauri::command]
+pub fn override_entry_classification(
+    text: String,
+    category: String,
+) -> Result<EntryClassification, String> {
+    category_service::override_classification(&text, &category)
+}
+
+/// Forget a cached classification so the entry is classified again
+#[tauri::command]
+pub fn remove_entry_classification(text: String) -> Result<(), String> {
+    category_service::remove_classification(&text)
+}
+
+/// Classify uncategorized entries between two dates (YYYY-MM-DD, inclusive) with the local model
+///
+/// Returns the number of newly cached classifications.
+#[tauri::command]
+pub async fn categorize_entries_with_llm(
+    history_state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
+    llm_state: State<'_, Arc<llm::LLMManager>>,
+    start_date: String,
+    end_date: String,
+) -> Result<usize, String> {
+    let enabled = settings_state
+        .settings
+        .lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?
+        .llm_categorization;
+    if !enabled {
+        return Err("설정에서 LLM 카테고리 분류를 켜주세요".to_string());
+    }
+
+    let start = weekly_service::parse_iso_date(&start_date)?;
+    let end = weekly_service::parse_iso_date(&end_date)?;
+    if !(0..=366).contains(&(end - start).whole_days()) {
+        return Err("분류 기간은 1일에서 1년 사이여야 합니다".to_string());
+    }
+    let dates = weekly_service::get_range_dates(&start, &end);
+
+    category_service::refresh()?;
+    let entries = weekly_service::uncategorized_entries(history_state.inner(), &dates)?;
+    if entries.is_empty() {
+        return Ok(0);
+    }
+    let categories = category_service::category_names()?;
+
+    let model = llm_state
+        .local_model_name()
+        .await
+        .ok_or("로컬 모델이 로드되지 않았습니다. 먼저 모델을 불러와주세요.")?;
+
+    // 배치마다 엔진 잠금을 풀어 그 사이 피드백·요약 요청이 끼어들 수 있게 함
+    let results = llm::categorize::classify_entries(&llm_state, &entries, &categories)
+        .await
+        .map_err(|e| format!("LLM 분류 실패: {}", e))?;
+
+    let stored = category_service::store_llm_classifications(&results, Some(&model))?;
+    tracing::info!("LLM 카테고리 분류 완료: {}/{}개", stored, entries.len());
+    Ok(stored)
+}
@@ -6,40 +6,31 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::{AppHandle, State};
 
-use crate::models::dump::{AppendHistoryEntryPayload, HistoryState, TodayMarkdown};
-use crate::services::history_service;
-use crate::utils::{current_local_time, format_date_label, short_day_code};
+use crate::models::dump::{AppendHistoryEntryPayload, HistoryState, TaskItem, TodayMarkdown};
+use crate::models::settings::AppSettingsState;
+use crate::services::{history_service, task_service};
+use crate::utils::current_local_time;
 
 /// Get today's markdown file
 #[tauri::command]
-pub fn get_today_markdown(state: State<'_, HistoryState>) -> Result<TodayMarkdown, String> {
+pub fn get_today_markdown(
+    state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
+) -> Result<TodayMarkdown, String> {
     tracing::debug!("get_today_markdown 호출됨");
-    let now = current_local_time()?;
-    tracing::debug!("현재 시간: {:?}", now);
-    let (file_path, date_key) = history_service::ensure_daily_file(state.inner(), &now)?;
-    tracing::debug!("파일 경로: {:?}, date_key: {}", file_path, date_key);
-
-    let mut content = fs::read_to_string(&file_path).unwrap_or_default();
-    tracing::debug!("파일 내용 길이: {}", content.len());
-
-    if content.trim().is_empty() {
-        content = format!("# {}\n\n", format_date_label(&now));
-        fs::write(&file_path, &content)
-            .map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", file_path))?;
-        tracing::debug!("빈 파일에 헤더 작성 완료");
-    }
-
+    let calendar = settings_state
+        .settings
+        .lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?
+        .calendar
+        .clone();
+
+    let today = history_service::load_today(state.inner(), &calendar)?;
     tracing::debug!(
         "get_today_markdown 완료, 내용 길이: {}",
-        content.len()
+        today.content.len()
     );
-    Ok(TodayMarkdown {
-        date_key,
-        short_label: short_day_code(&now),
-        header_title: format_date_label(&now),
-        file_path: file_path.to_string_lossy().into_owned(),
-        content,
-    })
+    Ok(today)
 }
 
 /// Append a history entry to today's file
@@ -137,3 +128,39 @@ pub fn save_history_markdown(
     history_service::emit_history_update(&app, state.inner())?;
     Ok(())
 }
+
+/// List checkbox tasks of a specific day (YYYYMMDD)
+#[tauri::command]
+pub fn list_tasks(date_key: String, state: State<'_, HistoryState>) -> Result<Vec<TaskItem>, String> {
+    task_service::list_tasks(state.inner(), &date_key)
+}
+
+/// Toggle a checkbox task in place
+///
+/// `expected_text` should be the task text as listed, so a stale UI cannot flip the wrong line.
+#[tauri::command]
+pub fn toggle_task(
+    date_key: String,
+    line_number: usize,
+    expected_text: Option<String>,
+    app: AppHandle,
+    state: State<'_, HistoryState>,
+) -> Result<TaskItem, String> {
+    let task = task_service::toggle_task(
+        state.inner(),
+        &date_key,
+        line_number,
+        expected_text.as_deref(),
+    )?;
+    history_service::emit_history_update(&app, state.inner())?;
+    Ok(task)
+}
+
+/// List unfinished tasks across days (optionally limited to the most recent `days` files)
+#[tauri::command]
+pub fn list_open_tasks(
+    days: Option<usize>,
+    state: State<'_, HistoryState>,
+) -> Result<Vec<TaskItem>, String> {
+    task_service::list_open_tasks(state.inner(), days)
+}
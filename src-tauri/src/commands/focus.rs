@@ -0,0 +1,81 @@
+// commands/focus.rs
+// Focus timer commands: start focus/break blocks, note interruptions, stop early
+
+use tauri::{AppHandle, State};
+
+use crate::models::dump::HistoryState;
+use crate::models::focus::{FocusRecord, FocusTimer};
+use crate::services::{focus_service, history_service};
+
+fn emit_update(app: &AppHandle, history_state: &HistoryState) {
+    if let Err(e) = history_service::emit_history_update(app, history_state) {
+        tracing::warn!("히스토리 업데이트 이벤트 발송 실패: {}", e);
+    }
+}
+
+/// Start a focus block (default 25 minutes)
+#[tauri::command]
+pub fn start_focus_timer(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+    intent: String,
+    minutes: Option<i64>,
+) -> Result<FocusTimer, String> {
+    let timer = focus_service::start_focus(
+        history_state.inner(),
+        &intent,
+        minutes.unwrap_or(focus_service::DEFAULT_FOCUS_MINUTES),
+    )?;
+    // 진행 중이던 휴식이 중단으로 기록됐을 수 있음
+    emit_update(&app, history_state.inner());
+    Ok(timer)
+}
+
+/// Start a break (default 5 minutes)
+#[tauri::command]
+pub fn start_focus_break(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+    minutes: Option<i64>,
+) -> Result<FocusTimer, String> {
+    let timer = focus_service::start_break(
+        history_state.inner(),
+        minutes.unwrap_or(focus_service::DEFAULT_BREAK_MINUTES),
+    )?;
+    emit_update(&app, history_state.inner());
+    Ok(timer)
+}
+
+#[tauri::command]
+pub fn interrupt_focus_timer(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+    note: Option<String>,
+) -> Result<FocusTimer, String> {
+    let timer = focus_service::record_interruption(history_state.inner(), note.as_deref())?;
+    emit_update(&app, history_state.inner());
+    Ok(timer)
+}
+
+/// Stop the running timer and record the minutes spent so far
+#[tauri::command]
+pub fn stop_focus_timer(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+) -> Result<FocusRecord, String> {
+    let record = focus_service::stop(history_state.inner())?;
+    emit_update(&app, history_state.inner());
+    Ok(record)
+}
+
+/// Running timer, if any (a timer that already ran out is recorded first)
+#[tauri::command]
+pub fn get_focus_timer(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+) -> Result<Option<FocusTimer>, String> {
+    if focus_service::complete_if_due(history_state.inner())?.is_some() {
+        emit_update(&app, history_state.inner());
+    }
+    focus_service::current_timer(history_state.inner())
+}
@@ -0,0 +1,42 @@
+// commands/git.rs
+// Git commit import commands
+
+use tauri::{AppHandle, State};
+
+use crate::models::dump::HistoryState;
+use crate::models::git::GitSyncResult;
+use crate::models::settings::AppSettingsState;
+use crate::services::{git_service, history_service, weekly_service};
+use crate::utils::current_local_time;
+
+/// Copy a day's (YYYY-MM-DD, default today) commits from the configured repositories into its dump
+#[tauri::command]
+pub fn sync_git_commits(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
+    date: Option<String>,
+) -> Result<GitSyncResult, String> {
+    let day = match date {
+        Some(date) => weekly_service::parse_iso_date(&date)?,
+        None => weekly_service::local_midnight(current_local_time()?.date()),
+    };
+
+    let settings = settings_state
+        .settings
+        .lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?
+        .git
+        .clone();
+    if settings.repositories.is_empty() {
+        return Err("설정에 git 저장소가 없습니다".to_string());
+    }
+
+    let result = git_service::sync_day(history_state.inner(), &day, &settings)?;
+    if !result.added.is_empty() {
+        if let Err(e) = history_service::emit_history_update(&app, history_state.inner()) {
+            tracing::warn!("히스토리 업데이트 이벤트 발송 실패: {}", e);
+        }
+    }
+    Ok(result)
+}
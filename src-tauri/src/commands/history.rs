@@ -3,12 +3,14 @@
 // Lists, filters, and navigates through dump entries
 
 use tauri::{AppHandle, Manager, State};
-use time::macros::format_description;
-use time::{Date, Duration, Time, UtcOffset};
+use time::Duration;
 
 use crate::models::dump::{HistoryOverview, HistoryState};
-use crate::models::weekly::{GetWeekDataPayload, WeekData};
+use crate::models::settings::{AppSettingsState, TimeTrackingSettings};
+use crate::models::weekly::{GetPeriodDataPayload, GetWeekDataPayload, PeriodData, WeekData};
+use crate::services::calendar_service;
 use crate::services::history_service;
+use crate::services::stats_cache_service;
 use crate::services::weekly_service;
 use crate::utils::format_date_key;
 
@@ -16,17 +18,11 @@ use crate::utils::format_date_key;
 #[tauri::command]
 pub async fn get_week_data(
     history_state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
     payload: GetWeekDataPayload,
 ) -> Result<WeekData, String> {
     // Parse the start date from YYYY-MM-DD format
-    let date_format = format_description!("[year]-[month]-[day]");
-    let date = Date::parse(&payload.start_date, &date_format)
-        .map_err(|e| format!("Invalid start date format '{}': {}", payload.start_date, e))?;
-
-    // Create OffsetDateTime at midnight local time
-    let time = Time::from_hms(0, 0, 0).unwrap();
-    let start_date = date.with_time(time)
-        .assume_offset(UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC));
+    let start_date = weekly_service::parse_iso_date(&payload.start_date)?;
 
     let week_start_day = weekly_service::parse_week_start_day(&payload.week_start_day)?;
 
@@ -34,12 +30,31 @@ pub async fn get_week_data(
     let week_start = weekly_service::get_week_start(&start_date, week_start_day);
     let week_dates = weekly_service::get_week_dates(&week_start);
 
+    let time_tracking = time_tracking_settings(&settings_state)?;
+    let calendar = settings_state
+        .settings
+        .lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?
+        .calendar
+        .clone();
+
     // Build week data
-    let (daily_entries, aggregated_stats) = weekly_service::build_week_data(
+    let (mut daily_entries, aggregated_stats) = weekly_service::build_week_data(
         history_state.inner(),
         &week_dates,
+        &time_tracking,
     )?;
 
+    // Calendar layer: imported events are shown next to the dump, not counted as entries
+    match calendar_service::events_for_dates(&week_dates, &calendar) {
+        Ok(mut events) => {
+            for entry in &mut daily_entries {
+                entry.calendar_events = events.remove(&entry.date).unwrap_or_default();
+            }
+        }
+        Err(e) => tracing::warn!("캘린더 일정 불러오기 실패: {}", e),
+    }
+
     let start_date_str = format_date_key(&week_start)?;
     let end_date = week_start + Duration::days(6);
     let end_date_str = format_date_key(&end_date)?;
@@ -52,6 +67,59 @@ pub async fn get_week_data(
     })
 }
 
+/// Get statistics for a week, month, year or custom range, compared with the previous period
+#[tauri::command]
+pub async fn get_period_data(
+    history_state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
+    payload: GetPeriodDataPayload,
+) -> Result<PeriodData, String> {
+    let preset = weekly_service::parse_period_preset(&payload.preset)?;
+    let date = weekly_service::parse_iso_date(&payload.date)?;
+    let end_date = payload
+        .end_date
+        .as_deref()
+        .map(weekly_service::parse_iso_date)
+        .transpose()?;
+    let week_start_day = weekly_service::parse_week_start_day(
+        payload.week_start_day.as_deref().unwrap_or("monday"),
+    )?;
+
+    let (start, end) =
+        weekly_service::resolve_period(preset, &date, end_date.as_ref(), week_start_day)?;
+    let time_tracking = time_tracking_settings(&settings_state)?;
+
+    weekly_service::build_period_data(
+        history_state.inner(),
+        preset,
+        &start,
+        &end,
+        week_start_day,
+        &time_tracking,
+    )
+}
+
+/// Drop the stats cache and recompute statistics for every dump file
+#[tauri::command]
+pub async fn rebuild_stats_cache(
+    history_state: State<'_, HistoryState>,
+    settings_state: State<'_, AppSettingsState>,
+) -> Result<usize, String> {
+    let time_tracking = time_tracking_settings(&settings_state)?;
+    stats_cache_service::rebuild(history_state.inner(), &time_tracking)
+}
+
+fn time_tracking_settings(
+    settings_state: &State<'_, AppSettingsState>,
+) -> Result<TimeTrackingSettings, String> {
+    Ok(settings_state
+        .settings
+        .lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?
+        .time_tracking
+        .clone())
+}
+
 /// List all history files
 #[tauri::command]
 pub fn list_history(state: State<'_, HistoryState>) -> Result<HistoryOverview, String> {
@@ -0,0 +1,24 @@
+// commands/hooks.rs
+// Event hook commands: hooks.json configuration and the log of recent runs
+
+use crate::models::hooks::{HookConfig, HookRun};
+use crate::services::hook_service;
+
+const DEFAULT_RUN_LIMIT: usize = 50;
+
+#[tauri::command]
+pub fn list_hooks() -> Result<Vec<HookConfig>, String> {
+    hook_service::load_hooks()
+}
+
+/// Replace all hooks (also used to toggle a hook's `enabled` flag)
+#[tauri::command]
+pub fn save_hooks(hooks: Vec<HookConfig>) -> Result<(), String> {
+    hook_service::save_hooks(&hooks)
+}
+
+/// Recent hook runs with exit codes, newest first
+#[tauri::command]
+pub fn list_hook_runs(limit: Option<usize>) -> Result<Vec<HookRun>, String> {
+    hook_service::load_runs(limit.unwrap_or(DEFAULT_RUN_LIMIT))
+}
@@ -2,11 +2,17 @@
 // Tauri IPC command handlers organized by the 3-stage workflow
 // Dump → Feedback → Retrospect
 
+pub mod calendar;
+pub mod category;
 pub mod dump;
 pub mod feedback;
+pub mod focus;
+pub mod git;
 pub mod history;
+pub mod hooks;
 pub mod llm;
 pub mod retrospect;
+pub mod session;
 pub mod settings;
 pub mod window;
 
@@ -24,6 +30,10 @@ pub use dump::{
     // Specific dump file operations
     get_history_markdown,
     save_history_markdown,
+    // Checkbox tasks
+    list_open_tasks,
+    list_tasks,
+    toggle_task,
 };
 
 // ========================================
@@ -45,13 +55,76 @@ pub use retrospect::{
     save_retrospect_markdown,
 };
 
+// ========================================
+// Sessions (작업 세션)
+// ========================================
+pub use session::{
+    end_work_session,
+    get_current_work_session,
+    list_work_sessions,
+    pause_work_session,
+    resume_work_session,
+    start_work_session,
+};
+
+// ========================================
+// Focus timer (집중 타이머)
+// ========================================
+pub use focus::{
+    get_focus_timer,
+    interrupt_focus_timer,
+    start_focus_break,
+    start_focus_timer,
+    stop_focus_timer,
+};
+
 // ========================================
 // History (히스토리 탐색 - 사이드바용)
 // ========================================
 pub use history::{
+    get_period_data,
     get_week_data,
     list_history,
     open_history_folder,
+    rebuild_stats_cache,
+};
+
+// ========================================
+// Calendar (캘린더 내보내기/가져오기)
+// ========================================
+pub use calendar::{
+    export_calendar_ics,
+    import_calendar_file,
+    list_calendars,
+    materialize_calendar_events,
+    remove_calendar,
+};
+
+// ========================================
+// Git (커밋 가져오기)
+// ========================================
+pub use git::sync_git_commits;
+
+// ========================================
+// Hooks (이벤트 훅)
+// ========================================
+pub use hooks::{list_hook_runs, list_hooks, save_hooks};
+
+// ========================================
+// Categories (카테고리 규칙)
+// ========================================
+pub use category::{
+    delete_category_rule,
+    list_category_rules,
+    reset_category_rules,
+    save_category_rule,
+    save_category_rules,
+    test_categorize,
+    // LLM-assisted classification
+    categorize_entries_with_llm,
+    list_entry_classifications,
+    override_entry_classification,
+    remove_entry_classification,
 };
 
 // ========================================
@@ -67,4 +140,7 @@ pub use settings::{
     update_app_settings,
     update_documents_path,
     update_quick_note_shortcut,
+    // Snippets
+    expand_snippet_text,
+    list_snippets,
 };
@@ -0,0 +1,87 @@
+// commands/session.rs
+// Work session commands: start/pause/resume/end markers and per-session stats
+
+use tauri::{AppHandle, State};
+
+use crate::models::dump::HistoryState;
+use crate::models::session::WorkSession;
+use crate::services::session_service::{self, SessionMarker};
+use crate::services::{history_service, weekly_service};
+use crate::utils::current_local_time;
+
+/// Write a marker to today's dump and return the session it applies to
+fn record(
+    app: &AppHandle,
+    history_state: &HistoryState,
+    marker: SessionMarker,
+    title: Option<&str>,
+) -> Result<Option<WorkSession>, String> {
+    let title = session_service::record_marker(history_state, marker, title)?;
+    if let Err(e) = history_service::emit_history_update(app, history_state) {
+        tracing::warn!("히스토리 업데이트 이벤트 발송 실패: {}", e);
+    }
+
+    session_service::latest_session(history_state, &title)
+}
+
+/// Start a session (an open session is ended first)
+#[tauri::command]
+pub fn start_work_session(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+    title: String,
+) -> Result<Option<WorkSession>, String> {
+    record(
+        &app,
+        history_state.inner(),
+        SessionMarker::Start,
+        Some(&title),
+    )
+}
+
+#[tauri::command]
+pub fn pause_work_session(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+) -> Result<Option<WorkSession>, String> {
+    record(&app, history_state.inner(), SessionMarker::Pause, None)
+}
+
+#[tauri::command]
+pub fn resume_work_session(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+) -> Result<Option<WorkSession>, String> {
+    record(&app, history_state.inner(), SessionMarker::Resume, None)
+}
+
+#[tauri::command]
+pub fn end_work_session(
+    app: AppHandle,
+    history_state: State<'_, HistoryState>,
+) -> Result<Option<WorkSession>, String> {
+    record(&app, history_state.inner(), SessionMarker::End, None)
+}
+
+/// Session still open in today's dump, if any
+#[tauri::command]
+pub fn get_current_work_session(
+    history_state: State<'_, HistoryState>,
+) -> Result<Option<WorkSession>, String> {
+    let mut sessions =
+        session_service::load_sessions(history_state.inner(), &current_local_time()?)?;
+    Ok(sessions.pop().filter(|session| session.ended_at.is_none()))
+}
+
+/// Sessions of a day (YYYY-MM-DD, default today) with duration and entry count
+#[tauri::command]
+pub fn list_work_sessions(
+    history_state: State<'_, HistoryState>,
+    date: Option<String>,
+) -> Result<Vec<WorkSession>, String> {
+    let day = match date {
+        Some(date) => weekly_service::parse_iso_date(&date)?,
+        None => current_local_time()?,
+    };
+    session_service::load_sessions(history_state.inner(), &day)
+}
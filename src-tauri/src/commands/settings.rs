@@ -2,10 +2,14 @@
 // App settings command handlers
 
 use std::path::PathBuf;
+use std::sync::Arc;
 use tauri::{AppHandle, State};
 
-use crate::models::settings::{AppSettings, AppSettingsState, ModelSelectionState, SelectedModel};
-use crate::services::storage_service;
+use crate::models::settings::{
+    AppSettings, AppSettingsState, LlmEndpointSettings, ModelSelectionState, SelectedModel, Snippet,
+};
+use crate::services::llm::{LLMManager, LocalEndpoint};
+use crate::services::{snippet_service, storage_service};
 
 // Model Selection Commands
 
@@ -14,7 +18,40 @@ use crate::services::storage_service;
 pub async fn set_selected_model(
     model: SelectedModel,
     state: State<'_, ModelSelectionState>,
+    llm_state: State<'_, Arc<LLMManager>>,
+    settings_state: State<'_, AppSettingsState>,
 ) -> Result<(), String> {
+    // 외부 로컬 서버는 선택된 동안 번들 엔진 대신 사용됨 (클라우드 선택은 로컬 백엔드를 바꾸지 않음)
+    let endpoint = match model.model_type.as_str() {
+        "endpoint" => {
+            let base_url = model
+                .base_url
+                .as_deref()
+                .ok_or("엔드포인트 주소가 지정되지 않았습니다")?;
+            Some(Some(
+                LocalEndpoint::new(base_url, &model.model_id).map_err(|e| e.to_string())?,
+            ))
+        }
+        "local" => Some(None),
+        _ => None,
+    };
+
+    if let Some(endpoint) = endpoint {
+        // CLI와 다음 실행에서도 같은 서버를 쓰도록 저장
+        {
+            let mut settings = settings_state
+                .settings
+                .lock()
+                .map_err(|e| format!("설정 잠금 실패: {}", e))?;
+            settings.llm_endpoint = endpoint.as_ref().map(|endpoint| LlmEndpointSettings {
+                base_url: endpoint.base_url().to_string(),
+                model: endpoint.model().to_string(),
+            });
+            storage_service::save_settings(&settings)?;
+        }
+        llm_state.set_endpoint(endpoint).await;
+    }
+
     let mut selected = state.selected.write().await;
     *selected = Some(model);
     Ok(())
@@ -123,3 +160,21 @@ pub fn reset_app_settings(state: State<'_, AppSettingsState>) -> Result<AppSetti
 
     Ok(default_settings)
 }
+
+// Snippet Commands
+
+/// List text snippets (shared with the CLI input)
+#[tauri::command]
+pub fn list_snippets(state: State<'_, AppSettingsState>) -> Result<Vec<Snippet>, String> {
+    let settings = state.settings.lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?;
+    Ok(settings.snippets.clone())
+}
+
+/// Expand snippet triggers in overlay input before it is saved
+#[tauri::command]
+pub fn expand_snippet_text(text: String, state: State<'_, AppSettingsState>) -> Result<String, String> {
+    let settings = state.settings.lock()
+        .map_err(|e| format!("설정 잠금 실패: {}", e))?;
+    Ok(snippet_service::expand_snippets(&text, &settings.snippets))
+}
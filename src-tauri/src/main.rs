@@ -11,11 +11,16 @@ use platform::tray::{build_tray, handle_tray_event};
 use platform::window_manager;
 use services::llm;
 use services::storage_service;
+use services::category_service;
+use services::daemon_service;
+use services::git_service;
+use services::focus_service;
 use services::history_service;
 use utils::link_preview;
 use models::settings::{AppSettingsState, ModelSelectionState};
 use models::dump::HistoryState;
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use tauri::{Manager, WindowEvent};
 
@@ -195,10 +200,97 @@ async fn llm_load_model(
 async fn llm_is_ready(
     state: tauri::State<'_, Arc<llm::LLMManager>>,
 ) -> Result<bool, String> {
+    if let Some(endpoint) = state.endpoint().await {
+        return Ok(endpoint.health().await.is_ok());
+    }
     let engine = state.engine.lock().await;
     engine.wait_for_ready().await.map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+fn llm_get_engine_status(
+    state: tauri::State<'_, Arc<llm::LLMManager>>,
+) -> Result<llm::supervisor::ServerState, String> {
+    Ok(state.monitor.state())
+}
+
+#[tauri::command]
+fn llm_get_engine_logs(
+    state: tauri::State<'_, Arc<llm::LLMManager>>,
+    limit: Option<usize>,
+) -> Result<Vec<llm::supervisor::EngineLogLine>, String> {
+    Ok(state.monitor.logs(limit))
+}
+
+#[tauri::command]
+async fn llm_get_engine_config(
+    state: tauri::State<'_, Arc<llm::LLMManager>>,
+) -> Result<llm::LLMConfig, String> {
+    Ok(state.engine.lock().await.get_config())
+}
+
+#[tauri::command]
+async fn llm_list_model_profiles(
+    state: tauri::State<'_, Arc<llm::LLMManager>>,
+) -> Result<Vec<llm::profiles::ModelProfile>, String> {
+    let local_models: Vec<(String, PathBuf)> = state
+        .model_manager
+        .get_local_models()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|model| (model.info.id, model.path))
+        .collect();
+    llm::profiles::list_profiles(&local_models).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn llm_save_model_profile(
+    state: tauri::State<'_, Arc<llm::LLMManager>>,
+    profile: llm::profiles::ModelProfile,
+) -> Result<llm::profiles::ModelProfile, String> {
+    let saved = llm::profiles::save_profile(profile).map_err(|e| e.to_string())?;
+    reload_if_loaded(&state, &saved.model_id).await?;
+    Ok(saved)
+}
+
+#[tauri::command]
+async fn llm_reset_model_profile(
+    state: tauri::State<'_, Arc<llm::LLMManager>>,
+    model_id: String,
+) -> Result<llm::profiles::ModelProfile, String> {
+    let model_path = state.model_manager.get_model_path(&model_id);
+    let profile =
+        llm::profiles::reset_profile(&model_id, &model_path).map_err(|e| e.to_string())?;
+    reload_if_loaded(&state, &model_id).await?;
+    Ok(profile)
+}
+
+/// Apply a changed profile right away when its model is the one running
+async fn reload_if_loaded(state: &llm::LLMManager, model_id: &str) -> Result<(), String> {
+    let mut engine = state.engine.lock().await;
+    if engine.is_running() && engine.get_config().model == model_id {
+        engine
+            .reload()
+            .map_err(|e| format!("Failed to reload model: {}", e))?;
+        eprintln!("[LLM] Profile applied and reloaded: {}", model_id);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+async fn llm_list_endpoint_models(base_url: String) -> Result<Vec<String>, String> {
+    llm::endpoint::list_models(&base_url)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn llm_check_endpoint(base_url: String, model: String) -> Result<(), String> {
+    let endpoint = llm::LocalEndpoint::new(&base_url, &model).map_err(|e| e.to_string())?;
+    endpoint.health().await.map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn get_prompt_configs() -> Result<Vec<llm::prompt_config::PromptConfig>, String> {
     let store = llm::prompt_config::PromptConfigStore::load()
@@ -286,6 +378,9 @@ fn main() {
             commands::dump::save_today_markdown,
             commands::dump::get_history_markdown,
             commands::dump::save_history_markdown,
+            commands::dump::list_tasks,
+            commands::dump::toggle_task,
+            commands::dump::list_open_tasks,
             // ========================================
             // STAGE 2: Feedback (AI 피드백)
             // ========================================
@@ -298,12 +393,50 @@ fn main() {
             // ========================================
             commands::retrospect::get_retrospect_markdown,
             commands::retrospect::save_retrospect_markdown,
+            // Work sessions
+            commands::session::start_work_session,
+            commands::session::pause_work_session,
+            commands::session::resume_work_session,
+            commands::session::end_work_session,
+            commands::session::get_current_work_session,
+            commands::session::list_work_sessions,
+            // Focus timer
+            commands::focus::start_focus_timer,
+            commands::focus::start_focus_break,
+            commands::focus::interrupt_focus_timer,
+            commands::focus::stop_focus_timer,
+            commands::focus::get_focus_timer,
             // ========================================
             // History (히스토리 탐색 - 사이드바용)
             // ========================================
             commands::history::list_history,
             commands::history::open_history_folder,
             commands::history::get_week_data,
+            commands::history::get_period_data,
+            commands::history::rebuild_stats_cache,
+            // Calendar
+            commands::calendar::export_calendar_ics,
+            commands::calendar::import_calendar_file,
+            commands::calendar::list_calendars,
+            commands::calendar::remove_calendar,
+            commands::calendar::materialize_calendar_events,
+            // Git
+            commands::git::sync_git_commits,
+            // Event hooks
+            commands::hooks::list_hooks,
+            commands::hooks::save_hooks,
+            commands::hooks::list_hook_runs,
+            // Category rules
+            commands::category::list_category_rules,
+            commands::category::save_category_rule,
+            commands::category::save_category_rules,
+            commands::category::delete_category_rule,
+            commands::category::reset_category_rules,
+            commands::category::test_categorize,
+            commands::category::categorize_entries_with_llm,
+            commands::category::list_entry_classifications,
+            commands::category::override_entry_classification,
+            commands::category::remove_entry_classification,
             window_manager::hide_main_window,
             window_manager::toggle_overlay_window,
             window_manager::set_window_position,
@@ -325,6 +458,16 @@ fn main() {
             llm_set_default_model,
             llm_load_model,
             llm_is_ready,
+            llm_get_engine_status,
+            llm_get_engine_logs,
+            llm_get_engine_config,
+            // Model runtime profiles
+            llm_list_model_profiles,
+            llm_save_model_profile,
+            llm_reset_model_profile,
+            // External OpenAI-compatible servers (Ollama, LM Studio)
+            llm_list_endpoint_models,
+            llm_check_endpoint,
             // Prompt configuration commands
             get_prompt_configs,
             save_prompt_config,
@@ -350,6 +493,9 @@ fn main() {
             commands::settings::update_quick_note_shortcut,
             commands::settings::update_documents_path,
             commands::settings::reset_app_settings,
+            // Snippet commands
+            commands::settings::list_snippets,
+            commands::settings::expand_snippet_text,
             // Shortcut test command
             platform::shortcuts::test_shortcut_available
         ])
@@ -367,12 +513,25 @@ fn main() {
                 state.directory
             );
 
+            if let Err(error) = category_service::refresh() {
+                tracing::warn!("카테고리 규칙 불러오기 실패, 기본 규칙 사용: {}", error);
+            }
+
+            git_service::start_sync_job(app.handle());
+            focus_service::start_watch_job(app.handle());
+            #[cfg(unix)]
+            daemon_service::start_in_app(app.handle());
+
             ensure_accessibility_permission();
             register_shortcuts(&app.handle())?;
             history_service::emit_history_update(&app.handle(), &state)?;
 
             // 백그라운드에서 LLM 서버 예열: 기본 모델이 설정되어 있으면 자동 로드
             let llm_state = app.state::<Arc<llm::LLMManager>>().inner().clone();
+            let status_handle = app.handle();
+            llm_state.monitor.set_listener(Arc::new(move |status: &llm::supervisor::ServerState| {
+                let _ = status_handle.emit_all(llm::supervisor::STATUS_EVENT, status);
+            }));
             tauri::async_runtime::spawn_blocking(move || {
                 let rt = match tokio::runtime::Runtime::new() {
                     Ok(rt) => rt,
@@ -429,6 +588,11 @@ fn main() {
             }
             _ => {}
         })
-        .run(tauri::generate_context!())
-        .expect("error while running Tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building Tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                daemon_service::cleanup();
+            }
+        });
 }
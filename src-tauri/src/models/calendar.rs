@@ -0,0 +1,33 @@
+// models/calendar.rs
+// Calendar events imported from .ics files, shown as a layer next to the dump
+
+use serde::{Deserialize, Serialize};
+
+/// Calendar event on a single day
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub title: String,
+    pub location: Option<String>,
+    /// Date key (YYYYMMDD)
+    pub date: String,
+    /// Local start time "HH:MM", `None` for all-day events
+    pub start_time: Option<String>,
+    /// Local end time "HH:MM", `None` for all-day or zero-length events
+    pub end_time: Option<String>,
+    pub all_day: bool,
+    /// File name of the calendar the event came from
+    pub source: String,
+}
+
+/// Imported or watched `.ics` file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarSource {
+    pub name: String,
+    pub path: String,
+    pub event_count: usize,
+    /// Read from the watched folder rather than imported into Hoego
+    pub watched: bool,
+}
@@ -0,0 +1,81 @@
+// models/category.rs
+// Category rules used to classify dump entries for time statistics
+
+use serde::{Deserialize, Serialize};
+
+/// How time spent in a category counts towards productivity stats
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ProductivityClass {
+    Productive,
+    #[default]
+    Neutral,
+    Waste,
+}
+
+/// User-editable rule mapping entry text to a category
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRule {
+    pub id: String,
+    /// Category name shown in statistics (e.g. "개발")
+    pub name: String,
+    /// Keywords matched at the start of a word (case-insensitive)
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Regular expressions matched anywhere in the entry (case-insensitive)
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// Higher priority rules are checked first
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub productivity: ProductivityClass,
+    /// Display colour (e.g. "#4f46e5")
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Result of categorizing a single entry, including why it matched
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryMatch {
+    pub category: String,
+    pub productivity: ProductivityClass,
+    /// Matching rule id, `None` for the fallback category and cached classifications
+    pub rule_id: Option<String>,
+    /// Keyword or pattern that matched
+    pub matched_by: Option<String>,
+    /// "keyword", "pattern", "user" (override) or "llm" (cached model suggestion)
+    pub match_kind: Option<String>,
+}
+
+/// Who decided a cached entry classification
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ClassificationSource {
+    /// Suggested by the local model
+    Llm,
+    /// Set by the user; never overwritten by the model
+    User,
+}
+
+/// Cached category for an entry text that no rule matched
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryClassification {
+    pub text: String,
+    pub category: String,
+    pub source: ClassificationSource,
+    /// Model that produced the classification (LLM only)
+    #[serde(default)]
+    pub model: Option<String>,
+    /// RFC 3339 timestamp of the last change
+    pub updated_at: String,
+}
@@ -0,0 +1,137 @@
+// models/daemon.rs
+// JSON-RPC 2.0 messages for the local daemon socket (one JSON object per line)
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::session::WorkSession;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Standard JSON-RPC error codes plus the daemon's own
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+/// The service returned an error (message is the service's)
+pub const SERVICE_ERROR: i64 = -32000;
+/// Missing or wrong token
+pub const UNAUTHORIZED: i64 = -32001;
+
+/// `params.origin` of calls made from inside an event hook; their writes fire no hooks
+pub const HOOK_ORIGIN: &str = "hook";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRequest {
+    #[serde(default)]
+    pub jsonrpc: String,
+    /// Absent for notifications, which get no response
+    #[serde(default)]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcResponse {
+    pub jsonrpc: String,
+    pub id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl RpcResponse {
+    pub fn new(id: Value, result: Result<Value, RpcError>) -> Self {
+        let (result, error) = match result {
+            Ok(result) => (Some(result), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result,
+            error,
+        }
+    }
+}
+
+/// Server-initiated message sent to subscribed connections
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcNotification {
+    pub jsonrpc: &'static str,
+    pub method: String,
+    pub params: Value,
+}
+
+/// `auth` (the token may also be passed as `params.token` on any call)
+#[derive(Debug, Deserialize)]
+pub struct AuthParams {
+    pub token: String,
+}
+
+/// `append`
+#[derive(Debug, Deserialize)]
+pub struct AppendParams {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppendResult {
+    pub file_path: String,
+    /// Dump line as written, e.g. "- PR 리뷰 (14:03:10)"
+    pub line: String,
+}
+
+/// `read_day`
+#[derive(Debug, Default, Deserialize)]
+pub struct ReadDayParams {
+    /// YYYY-MM-DD, default today
+    pub date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayResult {
+    pub date: String,
+    pub content: String,
+}
+
+/// `search`
+#[derive(Debug, Deserialize)]
+pub struct SearchParams {
+    pub query: String,
+    pub limit: Option<usize>,
+}
+
+/// `session.start` (title required), `session.pause`, `session.resume`, `session.end`
+#[derive(Debug, Default, Deserialize)]
+pub struct SessionParams {
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionResult {
+    /// Title the marker was written for
+    pub title: String,
+    pub session: Option<WorkSession>,
+}
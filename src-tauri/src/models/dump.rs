@@ -4,6 +4,8 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use crate::models::calendar::CalendarEvent;
+
 /// History state - manages the history directory path
 #[derive(Debug)]
 pub struct HistoryState {
@@ -49,6 +51,14 @@ pub struct HistoryOverview {
     pub files: Vec<HistoryFileInfo>,
 }
 
+/// A line of a daily file matching a search query
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EntrySearchHit {
+    pub date: String,
+    pub line: String,
+}
+
 /// Today's markdown file data
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -58,4 +68,23 @@ pub struct TodayMarkdown {
     pub header_title: String,
     pub file_path: String,
     pub content: String,
+    /// Events from imported calendars for today
+    pub calendar_events: Vec<CalendarEvent>,
+}
+
+/// A checkbox task (`- [ ]` / `- [x]`) parsed from a dump file
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskItem {
+    /// Date key of the dump file (YYYYMMDD)
+    pub date: String,
+    /// Zero-based line index inside the dump file
+    pub line_number: usize,
+    /// Leading whitespace width (nesting level)
+    pub indent: usize,
+    /// Task text without the checkbox and trailing timestamp
+    pub text: String,
+    /// Trailing timestamp (HH:MM:SS) if the task was appended as an entry
+    pub timestamp: Option<String>,
+    pub done: bool,
 }
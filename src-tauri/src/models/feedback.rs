@@ -5,7 +5,8 @@ use serde::Serialize;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
-/// AI feedback streaming cancellation state
+/// AI feedback streaming cancellation state (clones share the same flag)
+#[derive(Clone)]
 pub struct StreamCancellationState {
     pub is_cancelled: Arc<AtomicBool>,
 }
@@ -0,0 +1,51 @@
+// models/focus.rs
+// Focus timer (Pomodoro) state shared by the app and the CLI
+
+use serde::{Deserialize, Serialize};
+
+/// What the running timer is measuring
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FocusPhase {
+    Focus,
+    Break,
+}
+
+/// Interruption noted while focusing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusInterruption {
+    /// Time label "HH:MM:SS"
+    pub at: String,
+    pub note: Option<String>,
+}
+
+/// Running focus or break timer, persisted so it survives a restart of either binary
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusTimer {
+    pub phase: FocusPhase,
+    /// What the block is for ("휴식" for breaks)
+    pub intent: String,
+    pub planned_seconds: i64,
+    /// Unix timestamps
+    pub started_at: i64,
+    pub ends_at: i64,
+    #[serde(default)]
+    pub interruptions: Vec<FocusInterruption>,
+}
+
+/// Entry written to the dump when a timer completes or is stopped
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusRecord {
+    pub phase: FocusPhase,
+    pub intent: String,
+    /// Measured minutes written to the entry
+    pub minutes: i64,
+    /// False when the timer was stopped early
+    pub completed: bool,
+    pub interruptions: usize,
+    /// Dump line as written
+    pub line: String,
+}
@@ -0,0 +1,32 @@
+// models/git.rs
+// Commits read from local git repositories and copied into the dump
+
+use serde::{Deserialize, Serialize};
+
+/// Commit found in a configured repository
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCommit {
+    pub hash: String,
+    /// Repository directory name
+    pub repo: String,
+    pub subject: String,
+    /// Date key of the commit time (YYYYMMDD)
+    pub date: String,
+    /// Local commit time "HH:MM:SS"
+    pub time: String,
+}
+
+/// Outcome of copying one day's commits into its dump
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GitSyncResult {
+    /// Date key (YYYYMMDD)
+    pub date: String,
+    /// Commits written as new entries
+    pub added: Vec<GitCommit>,
+    /// Commits skipped because they were imported before
+    pub skipped: usize,
+    /// Repositories that could not be read, with the reason
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,145 @@
+// models/hooks.rs
+// User scripts run on journal events (hooks.json) and the log of their runs
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::feedback::AiSummaryFile;
+use crate::models::session::WorkSession;
+
+/// Journal events a hook can subscribe to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    EntryAppended,
+    DayCreated,
+    FeedbackGenerated,
+    RetroSaved,
+    SessionEnded,
+}
+
+impl HookEvent {
+    pub const ALL: [HookEvent; 5] = [
+        HookEvent::EntryAppended,
+        HookEvent::DayCreated,
+        HookEvent::FeedbackGenerated,
+        HookEvent::RetroSaved,
+        HookEvent::SessionEnded,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HookEvent::EntryAppended => "entry_appended",
+            HookEvent::DayCreated => "day_created",
+            HookEvent::FeedbackGenerated => "feedback_generated",
+            HookEvent::RetroSaved => "retro_saved",
+            HookEvent::SessionEnded => "session_ended",
+        }
+    }
+}
+
+/// An executable run when `event` happens, with the event payload as JSON on stdin
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HookConfig {
+    /// Shown in the run log (defaults to the command)
+    #[serde(default)]
+    pub name: String,
+    pub event: HookEvent,
+    /// Executable path; a leading "~/" is expanded
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Killed after this many seconds (default 10)
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Written to the hook's stdin
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookPayload<T: Serialize> {
+    pub event: HookEvent,
+    /// RFC 3339
+    pub timestamp: String,
+    pub data: T,
+}
+
+/// `entry_appended`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryHookData {
+    /// YYYYMMDD
+    pub date: String,
+    pub file_path: String,
+    /// Dump line as written, e.g. "- PR 리뷰 (14:03:10)"
+    pub line: String,
+    pub text: String,
+}
+
+/// `day_created`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayHookData {
+    pub date: String,
+    pub file_path: String,
+}
+
+/// `retro_saved`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetroHookData {
+    pub date: String,
+    pub file_path: String,
+    pub content: String,
+}
+
+/// `feedback_generated`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedbackHookData {
+    pub date: String,
+    pub summary: AiSummaryFile,
+}
+
+/// `session_ended`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionHookData {
+    pub date: String,
+    pub title: String,
+    pub session: Option<WorkSession>,
+}
+
+/// One finished hook run, newest first in the run log
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HookRun {
+    pub name: String,
+    pub event: HookEvent,
+    pub command: String,
+    /// RFC 3339
+    pub started_at: String,
+    pub duration_ms: u64,
+    /// `None` when the process could not start, timed out or was killed by a signal
+    pub exit_code: Option<i32>,
+    #[serde(default)]
+    pub timed_out: bool,
+    /// Why the process could not be started
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Tail of what the hook wrote to stderr
+    #[serde(default)]
+    pub stderr: String,
+}
+
+impl HookRun {
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
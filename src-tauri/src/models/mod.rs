@@ -1,10 +1,17 @@
 // models/mod.rs
 // Data models and types used across the application
 
+pub mod calendar;
+pub mod category;
+pub mod daemon;
 pub mod dump;
 pub mod errors;
 pub mod feedback;
+pub mod focus;
+pub mod git;
+pub mod hooks;
 pub mod paths;
+pub mod session;
 pub mod settings;
 pub mod weekly;
 
@@ -0,0 +1,22 @@
+// models/session.rs
+// Work sessions marked in the dump with start/pause/resume/end headers
+
+use serde::{Deserialize, Serialize};
+
+/// A titled span of work reconstructed from `## 세션…` markers
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkSession {
+    pub title: String,
+    /// Start time "HH:MM:SS"
+    pub started_at: String,
+    /// End time "HH:MM:SS", None while the session is still open
+    pub ended_at: Option<String>,
+    /// Time worked, excluding pauses
+    pub active_seconds: i64,
+    pub paused_seconds: i64,
+    /// Entries written while the session was running
+    pub entry_count: usize,
+    /// The session is currently paused
+    pub paused: bool,
+}
@@ -14,6 +14,135 @@ pub struct AppSettings {
 
     /// Document storage path (absolute path)
     pub documents_path: String,
+
+    /// Carry unfinished tasks from the previous journaled day into a new daily file
+    #[serde(default)]
+    pub carry_over_tasks: bool,
+
+    /// Heuristics for inferring time between dump entries
+    #[serde(default)]
+    pub time_tracking: TimeTrackingSettings,
+
+    /// Let the local model classify entries that no category rule matched
+    #[serde(default)]
+    pub llm_categorization: bool,
+
+    /// Imported calendars shown alongside the dump
+    #[serde(default)]
+    pub calendar: CalendarSettings,
+
+    /// Local repositories whose commits are copied into the dump
+    #[serde(default)]
+    pub git: GitSettings,
+
+    /// Text shortcuts expanded while typing (e.g. ";mtg" → "회의: ")
+    #[serde(default = "default_snippets")]
+    pub snippets: Vec<Snippet>,
+
+    /// Port for the bundled llama-server; a free port is picked when unset
+    #[serde(default)]
+    pub llm_server_port: Option<u16>,
+
+    /// OpenAI-compatible server used instead of the bundled llama-server (set by selecting it)
+    #[serde(default)]
+    pub llm_endpoint: Option<LlmEndpointSettings>,
+}
+
+/// External local model server, e.g. Ollama or LM Studio
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LlmEndpointSettings {
+    /// e.g. "http://localhost:11434"
+    pub base_url: String,
+    /// Model name on the server
+    pub model: String,
+}
+
+/// Heuristics used when durations are inferred from gaps between entries
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TimeTrackingSettings {
+    /// Longest gap credited to a single entry; the overflow counts as untracked.
+    /// `None` credits the whole gap
+    pub max_gap_minutes: Option<u32>,
+
+    /// Duration credited to the last entry of the day
+    pub default_tail_minutes: u32,
+
+    /// Start of the sleep window excluded from inference (e.g. "23:30")
+    pub sleep_start: Option<String>,
+
+    /// End of the sleep window excluded from inference (e.g. "07:00")
+    pub sleep_end: Option<String>,
+
+    /// Stop inferring at `## 세션:` headers instead of running into the next session
+    pub session_boundaries: bool,
+}
+
+impl Default for TimeTrackingSettings {
+    /// Same totals as before these settings existed; gap caps and session boundaries are opt-in
+    fn default() -> Self {
+        Self {
+            max_gap_minutes: None,
+            default_tail_minutes: 60,
+            sleep_start: None,
+            sleep_end: None,
+            session_boundaries: false,
+        }
+    }
+}
+
+/// Where calendar events come from and who sees them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CalendarSettings {
+    /// Folder whose `.ics` files are read in addition to imported calendars
+    pub watch_folder: Option<String>,
+
+    /// Add the day's events to the AI feedback prompt
+    pub include_in_feedback: bool,
+}
+
+impl Default for CalendarSettings {
+    fn default() -> Self {
+        Self {
+            watch_folder: None,
+            include_in_feedback: true,
+        }
+    }
+}
+
+/// Git commit import
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GitSettings {
+    /// Absolute paths of local repositories
+    pub repositories: Vec<String>,
+
+    /// Only import commits whose author name or email matches (git `--author` pattern)
+    pub author: Option<String>,
+
+    /// Minutes between background imports of today's commits; 0 turns the job off
+    pub sync_interval_minutes: u32,
+}
+
+/// A text shortcut shared by the CLI and the GUI overlay
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Snippet {
+    /// Word that triggers the expansion, usually starting with ';'
+    pub trigger: String,
+    pub expansion: String,
+}
+
+fn default_snippets() -> Vec<Snippet> {
+    [(";mtg", "회의: "), (";rv", "리뷰: "), (";lunch", "점심 식사")]
+        .into_iter()
+        .map(|(trigger, expansion)| Snippet {
+            trigger: trigger.to_string(),
+            expansion: expansion.to_string(),
+        })
+        .collect()
 }
 
 impl Default for AppSettings {
@@ -29,6 +158,14 @@ impl Default for AppSettings {
         Self {
             quick_note_shortcut: "CommandOrControl+J".to_string(),
             documents_path: default_path,
+            carry_over_tasks: false,
+            time_tracking: TimeTrackingSettings::default(),
+            llm_categorization: false,
+            calendar: CalendarSettings::default(),
+            git: GitSettings::default(),
+            snippets: default_snippets(),
+            llm_server_port: None,
+            llm_endpoint: None,
         }
     }
 }
@@ -56,10 +193,12 @@ impl AppSettingsState {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SelectedModel {
-    pub model_type: String, // "local" or "cloud"
-    pub model_id: String,
+    pub model_type: String, // "local", "endpoint" or "cloud"
+    pub model_id: String, // For endpoint models: the model name on the server
     pub provider: Option<String>, // For cloud models: "openai", "claude", "gemini"
     pub display_name: Option<String>,
+    #[serde(default)]
+    pub base_url: Option<String>, // For endpoint models: e.g. "http://localhost:11434"
 }
 
 /// Model selection state management
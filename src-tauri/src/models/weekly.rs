@@ -4,6 +4,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::models::calendar::CalendarEvent;
+use crate::models::session::WorkSession;
+
 /// Week data with daily entries and aggregated statistics
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -23,6 +26,24 @@ pub struct DailyEntry {
     pub ai_feedback: Option<String>,
     pub retrospect_content: Option<String>,
     pub categorized_time: HashMap<String, i64>, // category -> seconds
+    pub time_breakdown: HashMap<String, TimeBreakdown>, // category -> measured/inferred split
+    pub untracked_seconds: i64, // time between entries not credited to any category
+    pub entry_count: usize,
+    #[serde(default)]
+    pub calendar_events: Vec<CalendarEvent>, // events from imported calendars
+    #[serde(default)]
+    pub sessions: Vec<WorkSession>, // work sessions marked in the dump
+}
+
+/// Measured vs inferred seconds for a category
+///
+/// Measured time comes from explicit syntax (`(~45m)`, `(14:00-15:30)`, `▶`/`■` markers, 🍅 focus blocks);
+/// inferred time is guessed from the gap to the next entry.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeBreakdown {
+    pub measured_seconds: i64,
+    pub inferred_seconds: i64,
 }
 
 /// Aggregated statistics for the week
@@ -30,6 +51,8 @@ pub struct DailyEntry {
 #[serde(rename_all = "camelCase")]
 pub struct AggregatedStats {
     pub total_categories: HashMap<String, i64>, // category -> total seconds
+    pub time_breakdown: HashMap<String, TimeBreakdown>, // category -> measured/inferred split
+    pub untracked_seconds: i64, // total untracked seconds (gap overflow, after stop markers)
     pub productivity_vs_waste: ProductivityStats,
     pub daily_trend: Vec<DailyTrend>,
 }
@@ -59,3 +82,65 @@ pub struct GetWeekDataPayload {
     pub start_date: String,      // ISO 8601 format: YYYY-MM-DD
     pub week_start_day: String,  // "sunday" or "monday"
 }
+
+/// Payload for getting period data (week, month, year or custom range)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPeriodDataPayload {
+    pub preset: String,                 // "week", "month", "year" or "custom"
+    pub date: String,                   // YYYY-MM-DD: any day in the period, or the start of a custom range
+    pub end_date: Option<String>,       // YYYY-MM-DD: end of a custom range (inclusive)
+    pub week_start_day: Option<String>, // "sunday" or "monday" (default: monday)
+}
+
+/// Statistics for an arbitrary period, compared with the previous equivalent period
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodData {
+    pub preset: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub daily: Vec<PeriodRollup>,
+    pub weekly: Vec<PeriodRollup>,
+    pub aggregated_stats: AggregatedStats,
+    pub comparison: PeriodComparison,
+}
+
+/// Totals for a day or week within a period
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodRollup {
+    pub start_date: String,
+    pub end_date: String,
+    pub categories: HashMap<String, i64>, // category -> seconds
+    pub total_seconds: i64,
+    pub untracked_seconds: i64,
+    pub productive_seconds: i64,
+    pub waste_seconds: i64,
+    pub entry_count: usize,
+    pub feedback_days: usize, // days with at least one AI feedback
+}
+
+/// Comparison against the previous equivalent period (previous week, month, year or range)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodComparison {
+    pub previous_start_date: String,
+    pub previous_end_date: String,
+    pub previous_total_categories: HashMap<String, i64>,
+    pub previous_productivity: ProductivityStats,
+    pub category_deltas: Vec<CategoryDelta>, // sorted by current seconds, descending
+    pub productive_percentage_delta: f64,    // percentage points
+    pub waste_percentage_delta: f64,         // percentage points
+}
+
+/// Change of one category between two periods
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryDelta {
+    pub category: String,
+    pub current_seconds: i64,
+    pub previous_seconds: i64,
+    pub delta_seconds: i64,
+    pub delta_percentage: Option<f64>, // None when the category is new in this period
+}
@@ -0,0 +1,589 @@
+// services/calendar_service.rs
+// Calendar export of categorized time blocks and import of .ics calendars
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+use sha2::{Digest, Sha256};
+use time::{Date, Duration, OffsetDateTime, UtcOffset};
+
+use crate::models::calendar::{CalendarEvent, CalendarSource};
+use crate::models::dump::HistoryState;
+use crate::models::settings::{CalendarSettings, TimeTrackingSettings};
+use crate::services::{category_service, history_service};
+use crate::services::weekly_service::{self, TimeBlock};
+use crate::utils::ics::{self, IcsEvent, ParsedEvent};
+use crate::utils::*;
+
+/// Calendar name shown by calendar apps
+const CALENDAR_NAME: &str = "Hoego";
+/// Days an all-day event is spread over at most
+const MAX_ALL_DAY_SPAN_DAYS: i64 = 366;
+
+fn block_uid(date_key: &str, block: &TimeBlock) -> String {
+    let hash = hex::encode(Sha256::digest(block.text.as_bytes()));
+    format!("{}-{}-{}@hoego", date_key, block.start_seconds, &hash[..8])
+}
+
+fn clock_label(seconds: u32) -> String {
+    format!("{:02}:{:02}", (seconds / 3600) % 24, (seconds % 3600) / 60)
+}
+
+/// Turn a day's time blocks into calendar events
+///
+/// `day_start` is local midnight of the day. With `merge`, consecutive blocks of the same
+/// category are combined into one event whose description lists the entries.
+pub fn blocks_to_events(
+    day_start: &OffsetDateTime,
+    date_key: &str,
+    blocks: &[TimeBlock],
+    merge: bool,
+) -> Vec<IcsEvent> {
+    let mut groups: Vec<Vec<&TimeBlock>> = Vec::new();
+
+    for block in blocks.iter().filter(|b| b.duration_seconds > 0) {
+        if merge {
+            if let Some(group) = groups.last_mut() {
+                let last = group[group.len() - 1];
+                let last_end = last.start_seconds as i64 + last.duration_seconds;
+                if last.category == block.category && block.start_seconds as i64 <= last_end {
+                    group.push(block);
+                    continue;
+                }
+            }
+        }
+        groups.push(vec![block]);
+    }
+
+    groups
+        .into_iter()
+        .map(|group| {
+            let first = group[0];
+            let end_seconds = group
+                .iter()
+                .map(|b| b.start_seconds as i64 + b.duration_seconds)
+                .max()
+                .unwrap_or(first.start_seconds as i64);
+
+            let (summary, description) = if group.len() == 1 {
+                (first.text.clone(), None)
+            } else {
+                let lines: Vec<String> = group
+                    .iter()
+                    .map(|b| format!("{} {}", clock_label(b.start_seconds), b.text))
+                    .collect();
+                (
+                    format!("{}: {} 외 {}건", first.category, first.text, group.len() - 1),
+                    Some(lines.join("\n")),
+                )
+            };
+
+            IcsEvent {
+                uid: block_uid(date_key, first),
+                start: *day_start + Duration::seconds(first.start_seconds as i64),
+                end: *day_start + Duration::seconds(end_seconds),
+                summary,
+                description,
+                categories: vec![first.category.clone()],
+            }
+        })
+        .collect()
+}
+
+/// Build an iCalendar document for all days from start to end (inclusive)
+pub fn export_range(
+    history_state: &HistoryState,
+    start: &OffsetDateTime,
+    end: &OffsetDateTime,
+    settings: &TimeTrackingSettings,
+    merge: bool,
+) -> Result<(String, usize), String> {
+    if let Err(error) = category_service::refresh() {
+        tracing::warn!("카테고리 규칙 불러오기 실패, 기존 규칙 사용: {}", error);
+    }
+
+    let mut events = Vec::new();
+    for date in weekly_service::get_range_dates(start, end) {
+        let content = weekly_service::load_daily_dump(history_state, &date)?;
+        if content.trim().is_empty() {
+            continue;
+        }
+        let date_key = format_date_key(&date)?;
+        let blocks = weekly_service::parse_time_blocks(&content, settings);
+        events.extend(blocks_to_events(&date, &date_key, &blocks, merge));
+    }
+
+    let count = events.len();
+    let stamp = OffsetDateTime::now_utc();
+    Ok((ics::write_calendar(CALENDAR_NAME, &events, &stamp), count))
+}
+
+/// Default export location (Documents/Hoego/exports/hoego-START-END.ics)
+pub fn default_export_path(start: &OffsetDateTime, end: &OffsetDateTime) -> Result<PathBuf, String> {
+    let mut path = document_dir()?;
+    path.push("Hoego");
+    path.push("exports");
+    path.push(format!(
+        "hoego-{}-{}.ics",
+        format_date_key(start)?,
+        format_date_key(end)?
+    ));
+    Ok(path)
+}
+
+/// Export a date range to an `.ics` file and return its path
+pub fn export_ics_file(
+    history_state: &HistoryState,
+    start: &OffsetDateTime,
+    end: &OffsetDateTime,
+    settings: &TimeTrackingSettings,
+    merge: bool,
+    output_path: Option<PathBuf>,
+) -> Result<PathBuf, String> {
+    let output_path = match output_path {
+        Some(path) => path,
+        None => default_export_path(start, end)?,
+    };
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("내보내기 폴더 생성 실패: {error}, 경로: {:?}", parent))?;
+    }
+
+    let (calendar, count) = export_range(history_state, start, end, settings, merge)?;
+    fs::write(&output_path, calendar)
+        .map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", output_path))?;
+
+    tracing::info!("캘린더 내보내기 완료: {}개 일정, {:?}", count, output_path);
+    Ok(output_path)
+}
+
+/// Imported calendars are copied into Documents/Hoego/calendars
+pub fn calendars_dir() -> Result<PathBuf, String> {
+    let mut path = document_dir()?;
+    path.push("Hoego");
+    path.push("calendars");
+    Ok(path)
+}
+
+/// `.ics` files directly inside a folder, sorted by name
+fn ics_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("ics"))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Every calendar file to read, with whether it comes from the watched folder
+fn calendar_files(settings: &CalendarSettings) -> Result<Vec<(PathBuf, bool)>, String> {
+    let mut files: Vec<(PathBuf, bool)> = ics_files(&calendars_dir()?)
+        .into_iter()
+        .map(|path| (path, false))
+        .collect();
+
+    if let Some(folder) = settings.watch_folder.as_deref().filter(|f| !f.trim().is_empty()) {
+        files.extend(ics_files(Path::new(folder)).into_iter().map(|path| (path, true)));
+    }
+    Ok(files)
+}
+
+type ParseCache = HashMap<PathBuf, (SystemTime, u64, Arc<Vec<ParsedEvent>>)>;
+
+static PARSE_CACHE: OnceLock<Mutex<ParseCache>> = OnceLock::new();
+
+fn parse_cache() -> &'static Mutex<ParseCache> {
+    PARSE_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn forget_parsed(path: &Path) {
+    if let Ok(mut cache) = parse_cache().lock() {
+        cache.remove(path);
+    }
+}
+
+/// Parse a calendar file, reusing the previous result while its mtime and size are unchanged
+fn parsed_events(path: &Path) -> Result<Arc<Vec<ParsedEvent>>, String> {
+    let metadata = fs::metadata(path)
+        .map_err(|error| format!("파일 정보 읽기 실패: {error}, 경로: {:?}", path))?;
+    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    let size = metadata.len();
+
+    let mut cache = parse_cache()
+        .lock()
+        .map_err(|e| format!("캘린더 캐시 잠금 실패: {}", e))?;
+    if let Some((cached_modified, cached_size, events)) = cache.get(path) {
+        if *cached_modified == modified && *cached_size == size {
+            return Ok(events.clone());
+        }
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("파일 읽기 실패: {error}, 경로: {:?}", path))?;
+    let events = Arc::new(ics::parse_calendar(&content));
+    tracing::debug!("캘린더 파싱: {:?}, {}개 일정", path, events.len());
+    cache.insert(path.to_path_buf(), (modified, size, events.clone()));
+    Ok(events)
+}
+
+fn date_key_of(date: Date) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month() as u8, date.day())
+}
+
+fn clock_of(time: time::Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+/// Place an imported event on local days
+///
+/// All-day events appear on every day they cover; timed events appear on the day they start.
+pub fn to_calendar_events(event: &ParsedEvent, offset: UtcOffset, source: &str) -> Vec<CalendarEvent> {
+    let start = event.start.to_local(offset);
+    let make = |date: Date, start_time: Option<String>, end_time: Option<String>, all_day: bool| {
+        CalendarEvent {
+            uid: event.uid.clone(),
+            title: event.summary.clone(),
+            location: event.location.clone(),
+            date: date_key_of(date),
+            start_time,
+            end_time,
+            all_day,
+            source: source.to_string(),
+        }
+    };
+
+    if event.start.is_date() {
+        let first = start.date();
+        // 끝이 아주 먼 일정도 MAX_ALL_DAY_SPAN_DAYS일까지만 펼침
+        let last = first
+            .checked_add(Duration::days(MAX_ALL_DAY_SPAN_DAYS))
+            .unwrap_or(Date::MAX);
+        let end = event
+            .end
+            .map(|end| end.to_local(offset).date())
+            .unwrap_or(first)
+            .min(last);
+
+        let mut events = vec![make(first, None, None, true)];
+        let mut day = first;
+        while let Some(next) = day.next_day().filter(|next| *next < end) {
+            events.push(make(next, None, None, true));
+            day = next;
+        }
+        return events;
+    }
+
+    let end_time = event
+        .end
+        .map(|end| end.to_local(offset))
+        .filter(|end| *end > start)
+        .map(|end| clock_of(end.time()));
+    vec![make(start.date(), Some(clock_of(start.time())), end_time, false)]
+}
+
+/// List imported and watched calendars
+pub fn list_calendars(settings: &CalendarSettings) -> Result<Vec<CalendarSource>, String> {
+    let mut sources = Vec::new();
+    for (path, watched) in calendar_files(settings)? {
+        let event_count = match parsed_events(&path) {
+            Ok(events) => events.len(),
+            Err(error) => {
+                tracing::warn!("캘린더 읽기 실패: {}", error);
+                0
+            }
+        };
+        sources.push(CalendarSource {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path: path.to_string_lossy().to_string(),
+            event_count,
+            watched,
+        });
+    }
+    Ok(sources)
+}
+
+/// Copy an `.ics` file into the calendars folder; re-importing a file replaces it
+pub fn import_calendar(source_path: &Path) -> Result<CalendarSource, String> {
+    let content = fs::read_to_string(source_path)
+        .map_err(|error| format!("파일 읽기 실패: {error}, 경로: {:?}", source_path))?;
+    if !content.contains("BEGIN:VCALENDAR") {
+        return Err(format!("iCalendar 파일이 아닙니다: {:?}", source_path));
+    }
+    let file_name = source_path
+        .file_name()
+        .ok_or_else(|| format!("파일 이름이 없습니다: {:?}", source_path))?;
+
+    let dir = calendars_dir()?;
+    fs::create_dir_all(&dir)
+        .map_err(|error| format!("디렉토리 생성 실패: {error}, 경로: {:?}", dir))?;
+    let target = dir.join(file_name);
+    fs::write(&target, &content)
+        .map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", target))?;
+    forget_parsed(&target);
+
+    let event_count = parsed_events(&target)?.len();
+    tracing::info!("캘린더 가져오기 완료: {:?}, {}개 일정", target, event_count);
+    Ok(CalendarSource {
+        name: file_name.to_string_lossy().to_string(),
+        path: target.to_string_lossy().to_string(),
+        event_count,
+        watched: false,
+    })
+}
+
+/// Delete an imported calendar by file name (watched folders are left alone)
+pub fn remove_calendar(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.contains(['/', '\\']) || name == ".." {
+        return Err(format!("잘못된 캘린더 이름: {}", name));
+    }
+    let path = calendars_dir()?.join(name);
+    if !path.is_file() {
+        return Err(format!("캘린더를 찾을 수 없습니다: {}", name));
+    }
+    fs::remove_file(&path)
+        .map_err(|error| format!("파일 삭제 실패: {error}, 경로: {:?}", path))?;
+    forget_parsed(&path);
+    Ok(())
+}
+
+/// Calendar events for each of the given local days, keyed by date key
+///
+/// Events are sorted with all-day events first. The same event found in several files
+/// (e.g. imported and also in the watched folder) is listed once.
+pub fn events_for_dates(
+    dates: &[OffsetDateTime],
+    settings: &CalendarSettings,
+) -> Result<HashMap<String, Vec<CalendarEvent>>, String> {
+    let Some(offset) = dates.first().map(|d| d.offset()) else {
+        return Ok(HashMap::new());
+    };
+    let mut by_date: HashMap<String, Vec<CalendarEvent>> = HashMap::new();
+    for date in dates {
+        by_date.insert(date_key_of(date.date()), Vec::new());
+    }
+
+    let mut seen = HashSet::new();
+    for (path, _) in calendar_files(settings)? {
+        let events = match parsed_events(&path) {
+            Ok(events) => events,
+            Err(error) => {
+                tracing::warn!("캘린더 읽기 실패: {}", error);
+                continue;
+            }
+        };
+        let source = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        for event in events.iter() {
+            for day_event in to_calendar_events(event, offset, &source) {
+                let Some(day) = by_date.get_mut(&day_event.date) else {
+                    continue;
+                };
+                if seen.insert((day_event.uid.clone(), day_event.date.clone())) {
+                    day.push(day_event);
+                }
+            }
+        }
+    }
+
+    for events in by_date.values_mut() {
+        events.sort_by(|a, b| {
+            (!a.all_day, &a.start_time, &a.title).cmp(&(!b.all_day, &b.start_time, &b.title))
+        });
+    }
+    Ok(by_date)
+}
+
+/// Title on one line: unescaped `\n` in SUMMARY would split the dump entry in two
+fn event_title(event: &CalendarEvent) -> String {
+    let title = event.title.split_whitespace().collect::<Vec<_>>().join(" ");
+    if title.is_empty() {
+        "(제목 없음)".to_string()
+    } else {
+        title
+    }
+}
+
+/// Markdown section listing a day's events for the feedback prompt
+pub fn prompt_section(events: &[CalendarEvent]) -> Option<String> {
+    if events.is_empty() {
+        return None;
+    }
+
+    let mut section = String::from("## 캘린더 일정\n");
+    for event in events {
+        let when = match (&event.start_time, &event.end_time) {
+            (Some(start), Some(end)) => format!("{}-{}", start, end),
+            (Some(start), None) => start.clone(),
+            _ => "종일".to_string(),
+        };
+        section.push_str(&format!("- {} {}", when, event_title(event)));
+        if let Some(location) = &event.location {
+            section.push_str(&format!(" ({})", location));
+        }
+        section.push('\n');
+    }
+    Some(section)
+}
+
+/// Calendar section to append to a day's dump before asking for feedback, if enabled
+pub fn feedback_context(date: &OffsetDateTime, settings: &CalendarSettings) -> Option<String> {
+    if !settings.include_in_feedback {
+        return None;
+    }
+    match events_for_dates(std::slice::from_ref(date), settings) {
+        Ok(mut events) => {
+            let key = date_key_of(date.date());
+            prompt_section(&events.remove(&key).unwrap_or_default())
+        }
+        Err(error) => {
+            tracing::warn!("캘린더 일정 불러오기 실패: {}", error);
+            None
+        }
+    }
+}
+
+/// (time label, entry text) written to the dump for a timed event
+fn entry_for_event(event: &CalendarEvent) -> Option<(String, String)> {
+    if event.all_day {
+        return None;
+    }
+    let start = event.start_time.as_ref()?;
+    let text = match &event.end_time {
+        Some(end) => format!("📅 {} ({}-{})", event_title(event), start, end),
+        None => format!("📅 {}", event_title(event)),
+    };
+    Some((format!("{}:00", start), text))
+}
+
+/// Write a day's timed events into its dump as `- 📅 제목 (HH:MM-HH:MM) (HH:MM:00)`
+///
+/// Entries are inserted at their start time, so time statistics pick up the meeting
+/// range. Events already written are skipped; returns how many were added.
+pub fn materialize_events(
+    history_state: &HistoryState,
+    date: &OffsetDateTime,
+    settings: &CalendarSettings,
+) -> Result<usize, String> {
+    let key = date_key_of(date.date());
+    let events = events_for_dates(std::slice::from_ref(date), settings)?
+        .remove(&key)
+        .unwrap_or_default();
+
+    let mut inserted = 0;
+    for (time_label, text) in events.iter().filter_map(entry_for_event) {
+        if history_service::insert_entry_at_time(history_state, date, &time_label, &text)? {
+            inserted += 1;
+        }
+    }
+
+    tracing::info!("캘린더 일정 {}건을 {} 일지에 추가", inserted, key);
+    Ok(inserted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ics::IcsTime;
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn merges_consecutive_blocks_of_same_category() {
+        let content = "- 코딩 (09:00:00)\n- 리팩토링 (10:00:00)\n- 점심 (11:00:00)\n";
+        let blocks = weekly_service::parse_time_blocks(content, &TimeTrackingSettings::default());
+        let day = datetime!(2024-01-02 00:00 +09:00);
+
+        let events = blocks_to_events(&day, "20240102", &blocks, false);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].summary, "코딩");
+        assert_eq!(events[0].end, datetime!(2024-01-02 10:00 +09:00));
+
+        let events = blocks_to_events(&day, "20240102", &blocks, true);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].summary, "개발: 코딩 외 1건");
+        assert_eq!(events[0].end, datetime!(2024-01-02 11:00 +09:00));
+        assert_eq!(events[0].description.as_deref(), Some("09:00 코딩\n10:00 리팩토링"));
+        assert_eq!(events[1].categories, vec!["식사".to_string()]);
+    }
+
+    #[test]
+    fn places_events_on_local_days() {
+        let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+        let meeting = ParsedEvent {
+            uid: "m".to_string(),
+            summary: "주간 회의".to_string(),
+            location: Some("3층".to_string()),
+            description: None,
+            // 23:30 UTC is 08:30 the next morning in KST
+            start: IcsTime::Utc(datetime!(2024-01-01 23:30 UTC)),
+            end: Some(IcsTime::Utc(datetime!(2024-01-02 00:30 UTC))),
+        };
+        let events = to_calendar_events(&meeting, offset, "work.ics");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].date, "20240102");
+        assert_eq!(events[0].start_time.as_deref(), Some("08:30"));
+        assert_eq!(events[0].end_time.as_deref(), Some("09:30"));
+
+        let trip = ParsedEvent {
+            uid: "t".to_string(),
+            summary: "출장".to_string(),
+            location: None,
+            description: None,
+            start: IcsTime::Date(date!(2024-01-02)),
+            end: Some(IcsTime::Date(date!(2024-01-04))),
+        };
+        let days: Vec<String> = to_calendar_events(&trip, offset, "work.ics")
+            .into_iter()
+            .map(|e| e.date)
+            .collect();
+        assert_eq!(days, vec!["20240102", "20240103"]);
+
+        let forever = ParsedEvent {
+            end: Some(IcsTime::Date(date!(9999-12-31))),
+            ..trip.clone()
+        };
+        assert_eq!(
+            to_calendar_events(&forever, offset, "work.ics").len(),
+            MAX_ALL_DAY_SPAN_DAYS as usize
+        );
+
+        let mut all = to_calendar_events(&trip, offset, "work.ics");
+        all.truncate(1);
+        all.extend(events);
+        assert_eq!(
+            prompt_section(&all).unwrap(),
+            "## 캘린더 일정\n- 종일 출장\n- 08:30-09:30 주간 회의 (3층)\n"
+        );
+    }
+
+    #[test]
+    fn multi_line_titles_stay_on_one_dump_line() {
+        let ics = "BEGIN:VEVENT\r\nUID:x\r\nDTSTART:20240102T010000Z\r\n\
+DTEND:20240102T020000Z\r\nSUMMARY:주간 회의\\n  2부\r\nEND:VEVENT\r\n";
+        let parsed = ics::parse_calendar(ics);
+        assert_eq!(parsed[0].summary, "주간 회의\n  2부");
+
+        let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+        let events = to_calendar_events(&parsed[0], offset, "work.ics");
+        let (time_label, text) = entry_for_event(&events[0]).unwrap();
+        assert_eq!(time_label, "10:00:00");
+        assert_eq!(text, "📅 주간 회의 2부 (10:00-11:00)");
+        assert_eq!(prompt_section(&events).unwrap(), "## 캘린더 일정\n- 10:00-11:00 주간 회의 2부\n");
+    }
+}
@@ -0,0 +1,720 @@
+// services/category_service.rs
+// User-editable category rules: storage, matching and live reload
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::SystemTime;
+
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::models::category::{
+    CategoryMatch, CategoryRule, ClassificationSource, EntryClassification, ProductivityClass,
+};
+
+/// Category used when no rule matches
+pub const FALLBACK_CATEGORY: &str = "기타";
+
+/// A rule with its keywords normalized and patterns compiled
+struct CompiledRule {
+    rule: CategoryRule,
+    keywords: Vec<String>,
+    patterns: Vec<(String, Regex)>,
+}
+
+/// Matches entry text against a set of category rules
+pub struct CategoryMatcher {
+    rules: Vec<CompiledRule>,
+    classes: HashMap<String, ProductivityClass>,
+}
+
+impl CategoryMatcher {
+    /// Compile rules, highest priority first (file order breaks ties)
+    ///
+    /// Disabled rules are skipped and invalid patterns are ignored with a warning.
+    pub fn new(rules: &[CategoryRule]) -> Self {
+        let mut compiled: Vec<CompiledRule> = rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .map(|rule| CompiledRule {
+                rule: rule.clone(),
+                keywords: rule
+                    .keywords
+                    .iter()
+                    .map(|k| k.trim().to_lowercase())
+                    .filter(|k| !k.is_empty())
+                    .collect(),
+                patterns: rule
+                    .patterns
+                    .iter()
+                    .filter_map(|pattern| match compile_pattern(pattern) {
+                        Ok(regex) => Some((pattern.clone(), regex)),
+                        Err(error) => {
+                            tracing::warn!("카테고리 규칙 패턴 무시 ({}): {}", rule.name, error);
+                            None
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        compiled.sort_by_key(|c| std::cmp::Reverse(c.rule.priority));
+
+        let mut classes = HashMap::new();
+        for compiled_rule in &compiled {
+            classes
+                .entry(compiled_rule.rule.name.clone())
+                .or_insert(compiled_rule.rule.productivity);
+        }
+
+        Self {
+            rules: compiled,
+            classes,
+        }
+    }
+
+    /// Categorize entry text and explain which rule matched
+    pub fn categorize(&self, text: &str) -> CategoryMatch {
+        let text_lower = text.to_lowercase();
+
+        for compiled in &self.rules {
+            if let Some(keyword) = compiled
+                .keywords
+                .iter()
+                .find(|keyword| keyword_matches(&text_lower, keyword))
+            {
+                return matched(&compiled.rule, keyword, "keyword");
+            }
+            if let Some((pattern, _)) = compiled
+                .patterns
+                .iter()
+                .find(|(_, regex)| regex.is_match(text))
+            {
+                return matched(&compiled.rule, pattern, "pattern");
+            }
+        }
+
+        CategoryMatch {
+            category: FALLBACK_CATEGORY.to_string(),
+            productivity: self.productivity_of(FALLBACK_CATEGORY),
+            rule_id: None,
+            matched_by: None,
+            match_kind: None,
+        }
+    }
+
+    /// Productivity class of a category name (neutral when unknown)
+    pub fn productivity_of(&self, category: &str) -> ProductivityClass {
+        self.classes.get(category).copied().unwrap_or_default()
+    }
+}
+
+fn matched(rule: &CategoryRule, matched_by: &str, kind: &str) -> CategoryMatch {
+    CategoryMatch {
+        category: rule.name.clone(),
+        productivity: rule.productivity,
+        rule_id: Some(rule.id.clone()),
+        matched_by: Some(matched_by.to_string()),
+        match_kind: Some(kind.to_string()),
+    }
+}
+
+fn compile_pattern(pattern: &str) -> Result<Regex, String> {
+    Regex::new(&format!("(?i){pattern}")).map_err(|error| format!("잘못된 정규식 '{pattern}': {error}"))
+}
+
+/// Keyword must start a word: "물" matches "물 마심" but not "선물"
+///
+/// Keywords ending in a Latin letter or digit must also end the word (an optional
+/// plural "s" is allowed), so "pr" does not match "prefix". Korean keywords only need
+/// to start the word so particles and endings ("회의를", "먹음") still match.
+fn keyword_matches(text_lower: &str, keyword: &str) -> bool {
+    let whole_word = keyword
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_ascii_alphanumeric());
+
+    text_lower.match_indices(keyword).any(|(index, _)| {
+        let starts_word = text_lower[..index]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        if !starts_word || !whole_word {
+            return starts_word;
+        }
+
+        let rest = &text_lower[index + keyword.len()..];
+        let rest = rest.strip_prefix('s').unwrap_or(rest);
+        rest.chars().next().is_none_or(|c| !c.is_alphanumeric())
+    })
+}
+
+fn rule(
+    id: &str,
+    name: &str,
+    priority: i32,
+    productivity: ProductivityClass,
+    color: &str,
+    keywords: &[&str],
+    patterns: &[&str],
+) -> CategoryRule {
+    CategoryRule {
+        id: id.to_string(),
+        name: name.to_string(),
+        keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        patterns: patterns.iter().map(|p| p.to_string()).collect(),
+        priority,
+        productivity,
+        color: Some(color.to_string()),
+        enabled: true,
+    }
+}
+
+/// Built-in Korean and English rules
+pub fn default_rules() -> Vec<CategoryRule> {
+    use ProductivityClass::*;
+
+    vec![
+        rule("dev", "개발", 120, Productive, "#4f46e5", &[
+            "개발", "코딩", "프로그래밍", "디버깅", "버그", "코드", "구현", "리팩토링", "테스트",
+            "api", "ui", "레이아웃", "컴포넌트", "기능", "빌드", "배포", "에러", "수정", "패널",
+            "섹션", "추가", "변경", "조정", "최적화", "작업", "프로젝트", "앱", "서비스", "웹",
+            "모바일", "css", "html", "javascript", "typescript", "react", "tauri", "rust", "프론트",
+            "백엔드", "db", "데이터베이스", "쿼리", "디자인시스템", "와이어프레임", "프로토타입", "mvp",
+            "code", "coding", "programming", "debug", "debugging", "bug", "implement",
+            "refactor", "refactoring", "test", "testing", "build", "deploy", "fix", "fixed", "pr",
+            "commit",
+        ], &[]),
+        rule("meeting", "회의", 110, Productive, "#0ea5e9", &[
+            "회의", "미팅", "논의", "협의", "상의", "브리핑", "발표",
+            "meeting", "standup", "sync", "1:1", "call", "presentation",
+        ], &[]),
+        rule("planning", "기획", 100, Productive, "#8b5cf6", &[
+            "기획", "계획", "설계", "구상", "아이디어", "전략", "로드맵", "스펙", "요구사항",
+            "plan", "planning", "design", "roadmap", "spec", "strategy", "idea",
+        ], &[]),
+        rule("study", "학습", 90, Productive, "#10b981", &[
+            "공부", "학습", "강의", "책", "독서", "읽기", "강좌", "튜토리얼", "문서", "docs",
+            "매뉴얼", "가이드", "배우", "조사", "리서치", "아티클", "글 읽", "자료",
+            "study", "learn", "reading", "course", "lecture", "tutorial", "research", "article",
+        ], &[]),
+        rule("exercise", "운동", 80, Productive, "#22c55e", &[
+            "운동", "헬스", "달리기", "러닝", "조깅", "산책", "요가", "스트레칭", "근력", "웨이트",
+            "트레이닝", "수영", "자전거", "등산", "필라테스", "케이던스", "뛰", "걷", "걸",
+            "workout", "gym", "run", "running", "jog", "walk", "yoga", "stretch", "swim", "bike", "hike",
+        ], &[]),
+        rule("meal", "식사", 70, Neutral, "#f59e0b", &[
+            "식사", "아침", "점심", "저녁", "간식", "먹", "밥", "음식", "요리", "커피", "카페",
+            "마시", "물 마", "차 마", "음료", "배고", "계란", "빵", "샐러드", "고구마", "바나나",
+            "breakfast", "lunch", "dinner", "snack", "coffee", "meal", "eat", "cook",
+        ], &[]),
+        rule("rest", "휴식", 60, Waste, "#94a3b8", &[
+            "휴식", "쉬", "낮잠", "잠", "수면", "침대", "눕", "누워", "취침", "일어남", "기상",
+            "자고", "졸",
+            "rest", "nap", "sleep", "break",
+        ], &[]),
+        rule("social", "약속", 50, Neutral, "#ec4899", &[
+            "약속", "친구", "만남", "모임", "만나", "연락", "톡", "전화", "메시지", "엄마", "가족",
+            "friend", "family", "hangout", "party",
+        ], &[]),
+        rule("entertainment", "오락", 40, Waste, "#ef4444", &[
+            "게임", "유튜브", "sns", "영상", "드라마", "영화", "넷플릭스", "트위치", "스트리밍",
+            "악기", "취미",
+            "game", "gaming", "youtube", "netflix", "movie", "tv", "twitch", "instagram", "twitter",
+        ], &[r"기타\s*연습"]),
+        rule("personal-care", "개인관리", 30, Neutral, "#14b8a6", &[
+            "샤워", "세면", "화장", "옷", "준비", "씻", "세수", "양치", "면도", "머리", "입고",
+            "shower", "shave", "brush teeth", "get ready", "getting ready",
+        ], &[]),
+        rule("commute", "이동", 20, Neutral, "#64748b", &[
+            "이동", "출근", "퇴근", "버스", "지하철", "운전", "택시", "도착", "복귀", "귀가",
+            "집 나섬", "외출",
+            "commute", "bus", "subway", "drive", "driving", "taxi", "train",
+        ], &[]),
+        rule("thinking", "사고", 10, Neutral, "#a855f7", &[
+            "생각", "고민", "메모", "기록", "정리", "떠오름", "회고", "복기",
+            "think", "thought", "journal", "note", "reflect", "retro",
+        ], &[]),
+    ]
+}
+
+/// Get the category rules file path
+pub fn get_rules_path() -> Result<PathBuf, String> {
+    let config_dir = tauri::api::path::config_dir()
+        .ok_or_else(|| "설정 디렉토리를 찾을 수 없습니다".to_string())?;
+
+    let app_config_dir = config_dir.join("hoego");
+    fs::create_dir_all(&app_config_dir)
+        .map_err(|e| format!("설정 디렉토리 생성 실패: {}", e))?;
+
+    Ok(app_config_dir.join("category_rules.json"))
+}
+
+fn modified_time(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Load rules from file, falling back to the built-in defaults
+pub fn load_rules() -> Result<Vec<CategoryRule>, String> {
+    let rules_path = get_rules_path()?;
+
+    if !rules_path.exists() {
+        return Ok(default_rules());
+    }
+
+    let content = fs::read_to_string(&rules_path)
+        .map_err(|e| format!("카테고리 규칙 파일 읽기 실패: {}", e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("카테고리 규칙 파일 파싱 실패: {}", e))
+}
+
+/// Validate and save rules, then swap them into the active matcher
+pub fn save_rules(rules: &[CategoryRule]) -> Result<(), String> {
+    validate_rules(rules)?;
+
+    let rules_path = get_rules_path()?;
+    let content = serde_json::to_string_pretty(rules)
+        .map_err(|e| format!("카테고리 규칙 직렬화 실패: {}", e))?;
+
+    fs::write(&rules_path, content)
+        .map_err(|e| format!("카테고리 규칙 파일 저장 실패: {}", e))?;
+
+    install(rules, modified_time(&rules_path));
+    tracing::info!("카테고리 규칙 저장 완료: {:?}", rules_path);
+    Ok(())
+}
+
+fn validate_rules(rules: &[CategoryRule]) -> Result<(), String> {
+    let mut ids = std::collections::HashSet::new();
+    for rule in rules {
+        if rule.name.trim().is_empty() {
+            return Err("카테고리 이름이 비어 있습니다".to_string());
+        }
+        if !ids.insert(rule.id.as_str()) {
+            return Err(format!("중복된 규칙 ID: {}", rule.id));
+        }
+        for pattern in &rule.patterns {
+            compile_pattern(pattern)?;
+        }
+    }
+    Ok(())
+}
+
+/// Insert a new rule or replace the rule with the same id
+pub fn upsert_rule(mut rule: CategoryRule) -> Result<Vec<CategoryRule>, String> {
+    let mut rules = load_rules()?;
+
+    if rule.id.trim().is_empty() {
+        rule.id = uuid::Uuid::new_v4().to_string();
+    }
+
+    match rules.iter_mut().find(|existing| existing.id == rule.id) {
+        Some(existing) => *existing = rule,
+        None => rules.push(rule),
+    }
+
+    save_rules(&rules)?;
+    Ok(rules)
+}
+
+/// Delete a rule by id
+pub fn delete_rule(rule_id: &str) -> Result<Vec<CategoryRule>, String> {
+    let mut rules = load_rules()?;
+    let before = rules.len();
+    rules.retain(|rule| rule.id != rule_id);
+
+    if rules.len() == before {
+        return Err(format!("카테고리 규칙을 찾을 수 없습니다: {}", rule_id));
+    }
+
+    save_rules(&rules)?;
+    Ok(rules)
+}
+
+/// Restore the built-in rules
+pub fn reset_rules() -> Result<Vec<CategoryRule>, String> {
+    let rules = default_rules();
+    save_rules(&rules)?;
+    Ok(rules)
+}
+
+/// Get the cached entry classifications file path
+pub fn get_classifications_path() -> Result<PathBuf, String> {
+    Ok(get_rules_path()?.with_file_name("category_cache.json"))
+}
+
+/// Cache key for an entry text (whitespace and case insensitive)
+pub fn entry_hash(text: &str) -> String {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+/// Load cached classifications keyed by [`entry_hash`]
+pub fn load_classifications() -> Result<HashMap<String, EntryClassification>, String> {
+    let cache_path = get_classifications_path()?;
+
+    if !cache_path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = fs::read_to_string(&cache_path)
+        .map_err(|e| format!("분류 캐시 파일 읽기 실패: {}", e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("분류 캐시 파일 파싱 실패: {}", e))
+}
+
+fn save_classifications(classifications: HashMap<String, EntryClassification>) -> Result<(), String> {
+    let cache_path = get_classifications_path()?;
+
+    // Sorted keys keep the file diff-friendly
+    let sorted: BTreeMap<&String, &EntryClassification> = classifications.iter().collect();
+    let content = serde_json::to_string_pretty(&sorted)
+        .map_err(|e| format!("분류 캐시 직렬화 실패: {}", e))?;
+
+    fs::write(&cache_path, content)
+        .map_err(|e| format!("분류 캐시 파일 저장 실패: {}", e))?;
+
+    install_classifications(classifications, modified_time(&cache_path));
+    Ok(())
+}
+
+fn now_rfc3339() -> String {
+    OffsetDateTime::now_utc().format(&Rfc3339).unwrap_or_default()
+}
+
+/// List cached classifications, most recently changed first
+pub fn list_classifications() -> Result<Vec<EntryClassification>, String> {
+    let mut list: Vec<EntryClassification> = load_classifications()?.into_values().collect();
+    list.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(list)
+}
+
+/// Overrides must name a category that rules can produce
+fn check_category(category: &str, names: &[String]) -> Result<(), String> {
+    if names.iter().any(|name| name == category) {
+        Ok(())
+    } else {
+        Err(format!(
+            "알 수 없는 카테고리입니다: {} (사용 가능: {})",
+            category,
+            names.join(", ")
+        ))
+    }
+}
+
+/// Pin an entry text to a category; user overrides win over rules and the model
+pub fn override_classification(text: &str, category: &str) -> Result<EntryClassification, String> {
+    let category = category.trim();
+    if text.trim().is_empty() || category.is_empty() {
+        return Err("항목과 카테고리를 입력해주세요".to_string());
+    }
+    check_category(category, &category_names()?)?;
+
+    let classification = EntryClassification {
+        text: text.trim().to_string(),
+        category: category.to_string(),
+        source: ClassificationSource::User,
+        model: None,
+        updated_at: now_rfc3339(),
+    };
+
+    let mut classifications = load_classifications()?;
+    classifications.insert(entry_hash(text), classification.clone());
+    save_classifications(classifications)?;
+
+    tracing::debug!("항목 분류 수정: {} -> {}", classification.text, classification.category);
+    Ok(classification)
+}
+
+/// Remove a cached classification so the entry is classified again
+pub fn remove_classification(text: &str) -> Result<(), String> {
+    let mut classifications = load_classifications()?;
+    if classifications.remove(&entry_hash(text)).is_none() {
+        return Err(format!("저장된 분류가 없습니다: {}", text));
+    }
+    save_classifications(classifications)
+}
+
+/// Store model suggestions without touching user overrides
+///
+/// Returns the number of stored classifications.
+pub fn store_llm_classifications(results: &[(String, String)], model: Option<&str>) -> Result<usize, String> {
+    let mut classifications = load_classifications()?;
+    let updated_at = now_rfc3339();
+    let mut stored = 0;
+
+    for (text, category) in results {
+        let key = entry_hash(text);
+        if classifications
+            .get(&key)
+            .is_some_and(|existing| existing.source == ClassificationSource::User)
+        {
+            continue;
+        }
+        classifications.insert(
+            key,
+            EntryClassification {
+                text: text.trim().to_string(),
+                category: category.clone(),
+                source: ClassificationSource::Llm,
+                model: model.map(str::to_string),
+                updated_at: updated_at.clone(),
+            },
+        );
+        stored += 1;
+    }
+
+    save_classifications(classifications)?;
+    Ok(stored)
+}
+
+/// Category names the model may choose from (enabled rules plus the fallback)
+pub fn category_names() -> Result<Vec<String>, String> {
+    let mut names: Vec<String> = Vec::new();
+    for rule in load_rules()?.into_iter().filter(|rule| rule.enabled) {
+        if !names.contains(&rule.name) {
+            names.push(rule.name);
+        }
+    }
+    if !names.iter().any(|name| name == FALLBACK_CATEGORY) {
+        names.push(FALLBACK_CATEGORY.to_string());
+    }
+    Ok(names)
+}
+
+/// Active matcher, cached classifications and the file times they were loaded from
+struct ActiveRules {
+    matcher: Arc<CategoryMatcher>,
+    modified: Option<SystemTime>,
+    rules_hash: String,
+    classifications: Arc<HashMap<String, EntryClassification>>,
+    classifications_modified: Option<SystemTime>,
+    classifications_hash: String,
+}
+
+/// Content hash of anything serializable (stable for maps via sorted keys)
+fn content_hash<T: serde::Serialize + ?Sized>(value: &T) -> String {
+    let json = serde_json::to_vec(value).unwrap_or_default();
+    hex::encode(Sha256::digest(&json))
+}
+
+static ACTIVE_RULES: OnceLock<RwLock<ActiveRules>> = OnceLock::new();
+
+fn active_rules() -> &'static RwLock<ActiveRules> {
+    ACTIVE_RULES.get_or_init(|| {
+        let rules = default_rules();
+        RwLock::new(ActiveRules {
+            matcher: Arc::new(CategoryMatcher::new(&rules)),
+            modified: None,
+            rules_hash: content_hash(&rules),
+            classifications: Arc::new(HashMap::new()),
+            classifications_modified: None,
+            classifications_hash: String::new(),
+        })
+    })
+}
+
+fn install(rules: &[CategoryRule], modified: Option<SystemTime>) {
+    if let Ok(mut active) = active_rules().write() {
+        active.matcher = Arc::new(CategoryMatcher::new(rules));
+        active.modified = modified;
+        active.rules_hash = content_hash(rules);
+    }
+}
+
+fn install_classifications(
+    classifications: HashMap<String, EntryClassification>,
+    modified: Option<SystemTime>,
+) {
+    if let Ok(mut active) = active_rules().write() {
+        let sorted: BTreeMap<&String, &EntryClassification> = classifications.iter().collect();
+        active.classifications_hash = if sorted.is_empty() {
+            String::new()
+        } else {
+            content_hash(&sorted)
+        };
+        active.classifications = Arc::new(classifications);
+        active.classifications_modified = modified;
+    }
+}
+
+/// Hash of the active rules and classifications; changes whenever categorization could change
+pub fn fingerprint() -> String {
+    match active_rules().read() {
+        Ok(active) => format!("{}:{}", active.rules_hash, active.classifications_hash),
+        Err(_) => String::new(),
+    }
+}
+
+/// Matcher currently in use (built-in defaults until [`refresh`] loads the rules file)
+pub fn current_matcher() -> Arc<CategoryMatcher> {
+    match active_rules().read() {
+        Ok(active) => active.matcher.clone(),
+        Err(_) => Arc::new(CategoryMatcher::new(&default_rules())),
+    }
+}
+
+/// Reload the rules and classification files if they changed since they were last loaded
+pub fn refresh() -> Result<(), String> {
+    let rules_path = get_rules_path()?;
+    let modified = modified_time(&rules_path);
+    let cache_path = get_classifications_path()?;
+    let cache_modified = modified_time(&cache_path);
+
+    let (rules_unchanged, cache_unchanged) = active_rules()
+        .read()
+        .map(|active| {
+            (
+                active.modified == modified,
+                active.classifications_modified == cache_modified,
+            )
+        })
+        .unwrap_or((false, false));
+
+    if !rules_unchanged {
+        let rules = load_rules()?;
+        install(&rules, modified);
+        tracing::debug!("카테고리 규칙 다시 불러옴: {}개", rules.len());
+    }
+    if !cache_unchanged {
+        let classifications = load_classifications()?;
+        tracing::debug!("분류 캐시 다시 불러옴: {}개", classifications.len());
+        install_classifications(classifications, cache_modified);
+    }
+    Ok(())
+}
+
+fn from_cached(
+    matcher: &CategoryMatcher,
+    classification: &EntryClassification,
+    kind: &str,
+) -> CategoryMatch {
+    CategoryMatch {
+        category: classification.category.clone(),
+        productivity: matcher.productivity_of(&classification.category),
+        rule_id: None,
+        matched_by: classification.model.clone(),
+        match_kind: Some(kind.to_string()),
+    }
+}
+
+/// Categorize with user overrides first, then rules, then cached model suggestions
+pub fn categorize_with(
+    matcher: &CategoryMatcher,
+    classifications: &HashMap<String, EntryClassification>,
+    text: &str,
+) -> CategoryMatch {
+    let cached = if classifications.is_empty() {
+        None
+    } else {
+        classifications.get(&entry_hash(text))
+    };
+
+    if let Some(classification) = cached.filter(|c| c.source == ClassificationSource::User) {
+        return from_cached(matcher, classification, "user");
+    }
+
+    let result = matcher.categorize(text);
+    match cached {
+        Some(classification) if result.match_kind.is_none() => from_cached(matcher, classification, "llm"),
+        _ => result,
+    }
+}
+
+/// Categorize a single text with the active rules and cached classifications
+pub fn categorize(text: &str) -> CategoryMatch {
+    match active_rules().read() {
+        Ok(active) => categorize_with(&active.matcher, &active.classifications, text),
+        Err(_) => CategoryMatcher::new(&default_rules()).categorize(text),
+    }
+}
+
+/// Whether no rule, override or cached suggestion applies to the text
+pub fn is_uncategorized(text: &str) -> bool {
+    categorize(text).match_kind.is_none()
+}
+
+/// Categorize a single text with the latest rules and explain the match
+pub fn test_categorize(text: &str) -> Result<CategoryMatch, String> {
+    refresh()?;
+    Ok(categorize(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords_only_match_at_word_start() {
+        let matcher = CategoryMatcher::new(&default_rules());
+        assert_eq!(matcher.categorize("물 마심").category, "식사");
+        assert_eq!(matcher.categorize("선물 포장").category, FALLBACK_CATEGORY);
+        assert_eq!(matcher.categorize("Fixed login bugs").category, "개발");
+        assert_eq!(matcher.categorize("회의를 정리함").category, "회의");
+        assert_eq!(matcher.categorize("prefix 정리").category, "사고");
+    }
+
+    #[test]
+    fn explains_which_rule_matched() {
+        let matcher = CategoryMatcher::new(&default_rules());
+        let result = matcher.categorize("기타 연습 30분");
+        assert_eq!(result.category, "오락");
+        assert_eq!(result.match_kind.as_deref(), Some("pattern"));
+        assert_eq!(result.productivity, ProductivityClass::Waste);
+
+        let result = matcher.categorize("팀 회의");
+        assert_eq!(result.rule_id.as_deref(), Some("meeting"));
+        assert_eq!(result.matched_by.as_deref(), Some("회의"));
+    }
+
+    #[test]
+    fn priority_and_enabled_flag_are_respected() {
+        let mut rules = default_rules();
+        rules.push(rule("focus", "집중", 500, ProductivityClass::Productive, "#000000", &["코딩"], &[]));
+        let matcher = CategoryMatcher::new(&rules);
+        assert_eq!(matcher.categorize("코딩").category, "집중");
+
+        rules.last_mut().unwrap().enabled = false;
+        let matcher = CategoryMatcher::new(&rules);
+        assert_eq!(matcher.categorize("코딩").category, "개발");
+        assert_eq!(matcher.productivity_of("집중"), ProductivityClass::Neutral);
+    }
+
+    #[test]
+    fn overrides_and_model_suggestions_apply_in_order() {
+        let matcher = CategoryMatcher::new(&default_rules());
+        let cached = |text: &str, category: &str, source| EntryClassification {
+            text: text.to_string(),
+            category: category.to_string(),
+            source,
+            model: None,
+            updated_at: String::new(),
+        };
+
+        let mut classifications = HashMap::new();
+        classifications.insert(entry_hash("선물 포장"), cached("선물 포장", "약속", ClassificationSource::Llm));
+        classifications.insert(entry_hash("코딩"), cached("코딩", "기획", ClassificationSource::Llm));
+        classifications.insert(entry_hash("팀 회의"), cached("팀 회의", "개발", ClassificationSource::User));
+
+        let result = categorize_with(&matcher, &classifications, "  선물   포장 ");
+        assert_eq!(result.category, "약속");
+        assert_eq!(result.match_kind.as_deref(), Some("llm"));
+
+        // Rules beat model suggestions, user overrides beat rules
+        assert_eq!(categorize_with(&matcher, &classifications, "코딩").category, "개발");
+        let result = categorize_with(&matcher, &classifications, "팀 회의");
+        assert_eq!(result.category, "개발");
+        assert_eq!(result.match_kind.as_deref(), Some("user"));
+    }
+
+    #[test]
+    fn overrides_only_accept_known_categories() {
+        let names = vec!["개발".to_string(), FALLBACK_CATEGORY.to_string()];
+        assert!(check_category("개발", &names).is_ok());
+        assert!(check_category(FALLBACK_CATEGORY, &names).is_ok());
+        assert!(check_category("개발하기", &names).is_err());
+    }
+}
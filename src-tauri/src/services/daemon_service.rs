@@ -0,0 +1,667 @@
+// services/daemon_service.rs
+// Local daemon: JSON-RPC 2.0 over a Unix socket for scripts, editor plugins and the CLI
+//
+// One JSON object per line. A connection authenticates with `auth` (or `params.token` on any
+// call) using the token file written next to the socket, and may `subscribe` to receive
+// `history_updated` notifications. The socket server and client are Unix-only; elsewhere
+// `DaemonClient::connect` finds no daemon and the CLI writes directly.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::broadcast;
+#[cfg(unix)]
+use {
+    std::fs::OpenOptions,
+    std::io::{BufRead, BufReader, Write},
+    std::os::unix::fs::{OpenOptionsExt, PermissionsExt},
+    std::os::unix::net::UnixStream as StdUnixStream,
+    std::path::Path,
+    std::time::Duration,
+    tauri::{AppHandle, Manager},
+    tokio::io::{AsyncBufReadExt, AsyncWriteExt},
+    tokio::net::{UnixListener, UnixStream},
+};
+
+use crate::models::daemon::*;
+use crate::models::dump::HistoryState;
+use crate::services::session_service::{self, SessionMarker};
+use crate::services::{history_service, hook_service, weekly_service};
+use crate::utils::{current_local_time, format_date_key, format_time_with_seconds};
+
+/// Notification sent to subscribers whenever the journal changes
+pub const HISTORY_UPDATED_EVENT: &str = "history_updated";
+
+const DEFAULT_SEARCH_LIMIT: usize = 50;
+#[cfg(unix)]
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Called after a method changed the journal
+pub type WriteHook = Arc<dyn Fn(&HistoryState) + Send + Sync>;
+
+static EVENTS: OnceLock<broadcast::Sender<RpcNotification>> = OnceLock::new();
+/// This process owns the socket (so only it removes the files on exit)
+static SERVING: AtomicBool = AtomicBool::new(false);
+
+fn events() -> &'static broadcast::Sender<RpcNotification> {
+    EVENTS.get_or_init(|| broadcast::channel(64).0)
+}
+
+fn daemon_dir() -> Result<PathBuf, String> {
+    let data_dir =
+        dirs::data_dir().ok_or_else(|| "데이터 디렉토리를 찾을 수 없습니다".to_string())?;
+    let app_data_dir = data_dir.join("hoego");
+    fs::create_dir_all(&app_data_dir).map_err(|e| format!("데이터 디렉토리 생성 실패: {}", e))?;
+    Ok(app_data_dir)
+}
+
+/// Get the daemon socket path
+pub fn socket_path() -> Result<PathBuf, String> {
+    Ok(daemon_dir()?.join("daemon.sock"))
+}
+
+/// Get the token file path (readable only by the current user)
+pub fn token_path() -> Result<PathBuf, String> {
+    Ok(daemon_dir()?.join("daemon.token"))
+}
+
+/// Forward an event to subscribed socket clients
+pub fn publish<T: Serialize>(method: &str, payload: &T) {
+    let sender = events();
+    if sender.receiver_count() == 0 {
+        return;
+    }
+    match serde_json::to_value(payload) {
+        Ok(params) => {
+            let _ = sender.send(RpcNotification {
+                jsonrpc: JSONRPC_VERSION,
+                method: method.to_string(),
+                params,
+            });
+        }
+        Err(error) => tracing::warn!("데몬 알림 직렬화 실패: {}", error),
+    }
+}
+
+/// Publish `history_updated` to subscribers (headless daemon; the app goes through
+/// `history_service::emit_history_update`)
+pub fn publish_history_update(state: &HistoryState) {
+    if events().receiver_count() == 0 {
+        return;
+    }
+    match history_service::collect_history(state) {
+        Ok(overview) => publish(HISTORY_UPDATED_EVENT, &overview),
+        Err(error) => tracing::warn!("히스토리 목록 읽기 실패: {}", error),
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: &Value) -> Result<T, RpcError> {
+    let params = if params.is_null() {
+        json!({})
+    } else {
+        params.clone()
+    };
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("인자가 올바르지 않습니다: {}", e)))
+}
+
+fn to_result<T: Serialize>(value: Result<T, String>) -> Result<Value, RpcError> {
+    let value = value.map_err(|e| RpcError::new(SERVICE_ERROR, e))?;
+    serde_json::to_value(value).map_err(|e| RpcError::new(SERVICE_ERROR, e.to_string()))
+}
+
+const SESSION_METHODS: [(SessionMarker, &str); 4] = [
+    (SessionMarker::Start, "session.start"),
+    (SessionMarker::Pause, "session.pause"),
+    (SessionMarker::Resume, "session.resume"),
+    (SessionMarker::End, "session.end"),
+];
+
+fn session_marker(method: &str) -> Option<SessionMarker> {
+    SESSION_METHODS
+        .iter()
+        .find(|(_, name)| *name == method)
+        .map(|(marker, _)| *marker)
+}
+
+/// Method name for a session marker, e.g. "session.start"
+pub fn session_method(marker: SessionMarker) -> &'static str {
+    SESSION_METHODS
+        .iter()
+        .find(|(candidate, _)| *candidate == marker)
+        .map(|(_, name)| *name)
+        .unwrap_or("session.start")
+}
+
+fn append(state: &HistoryState, text: &str) -> Result<AppendResult, String> {
+    let now = current_local_time()?;
+    let file_path = history_service::append_entry(state, &now, text)?;
+    Ok(AppendResult {
+        file_path: file_path.to_string_lossy().to_string(),
+        line: history_service::entry_line(text, &format_time_with_seconds(&now)?),
+    })
+}
+
+fn read_day(state: &HistoryState, date: Option<&str>) -> Result<DayResult, String> {
+    let day = match date {
+        Some(date) => weekly_service::parse_iso_date(date)?,
+        None => current_local_time()?,
+    };
+    Ok(DayResult {
+        date: format_date_key(&day)?,
+        content: weekly_service::load_daily_dump(state, &day)?,
+    })
+}
+
+/// Write a session marker and look up the session it applies to
+pub fn record_session(
+    state: &HistoryState,
+    marker: SessionMarker,
+    title: Option<&str>,
+) -> Result<SessionResult, String> {
+    let title = session_service::record_marker(state, marker, title)?;
+    let session = session_service::latest_session(state, &title)?;
+    Ok(SessionResult { title, session })
+}
+
+/// Run a journal method; returns the result and whether the journal changed
+pub fn dispatch(
+    state: &HistoryState,
+    method: &str,
+    params: &Value,
+) -> Result<(Value, bool), RpcError> {
+    if let Some(marker) = session_marker(method) {
+        let params: SessionParams = parse_params(params)?;
+        let result = to_result(record_session(state, marker, params.title.as_deref()))?;
+        return Ok((result, true));
+    }
+
+    match method {
+        "ping" => Ok((json!({ "version": env!("CARGO_PKG_VERSION") }), false)),
+        "append" => {
+            let params: AppendParams = parse_params(params)?;
+            let text = params.text.trim();
+            if text.is_empty() {
+                return Err(RpcError::new(INVALID_PARAMS, "text가 비어 있습니다"));
+            }
+            Ok((to_result(append(state, text))?, true))
+        }
+        "read_day" => {
+            let params: ReadDayParams = parse_params(params)?;
+            Ok((to_result(read_day(state, params.date.as_deref()))?, false))
+        }
+        "search" => {
+            let params: SearchParams = parse_params(params)?;
+            let limit = params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+            let hits = history_service::search_entries(state, &params.query, limit);
+            Ok((to_result(hits)?, false))
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("알 수 없는 메서드: {}", method),
+        )),
+    }
+}
+
+#[cfg(unix)]
+struct ServerContext {
+    state: Arc<HistoryState>,
+    token: String,
+    on_write: WriteHook,
+}
+
+/// Per-connection state
+#[cfg(unix)]
+#[derive(Default)]
+struct Connection {
+    authenticated: bool,
+    events: Option<broadcast::Receiver<RpcNotification>>,
+}
+
+#[cfg(unix)]
+fn handle_request(
+    request: &RpcRequest,
+    context: &ServerContext,
+    connection: &mut Connection,
+) -> Result<Value, RpcError> {
+    if !request.jsonrpc.is_empty() && request.jsonrpc != JSONRPC_VERSION {
+        return Err(RpcError::new(
+            INVALID_REQUEST,
+            "jsonrpc는 \"2.0\"이어야 합니다",
+        ));
+    }
+    if let Some(token) = request.params.get("token").and_then(Value::as_str) {
+        if token.trim() != context.token {
+            return Err(RpcError::new(UNAUTHORIZED, "토큰이 올바르지 않습니다"));
+        }
+        connection.authenticated = true;
+    }
+
+    match request.method.as_str() {
+        "auth" => {
+            parse_params::<AuthParams>(&request.params)?;
+            Ok(json!(true))
+        }
+        _ if !connection.authenticated => Err(RpcError::new(
+            UNAUTHORIZED,
+            "먼저 auth로 인증하세요 (토큰 파일: daemon.token)",
+        )),
+        "subscribe" => {
+            connection.events = Some(events().subscribe());
+            Ok(json!(true))
+        }
+        "unsubscribe" => {
+            connection.events = None;
+            Ok(json!(true))
+        }
+        method => {
+            let handle = || {
+                let (result, changed) = dispatch(&context.state, method, &request.params)?;
+                if changed {
+                    (context.on_write)(&context.state);
+                }
+                Ok(result)
+            };
+            scoped_to_origin(&request.params, handle)
+        }
+    }
+}
+
+/// Run a request's work; writes that come from a hook don't fire hooks again
+#[cfg(unix)]
+fn scoped_to_origin<R>(params: &Value, work: impl FnOnce() -> R) -> R {
+    if params.get("origin").and_then(Value::as_str) == Some(HOOK_ORIGIN) {
+        hook_service::without_hooks(work)
+    } else {
+        work()
+    }
+}
+
+/// Handle one line; returns the response to send (none for notifications)
+#[cfg(unix)]
+fn handle_line(
+    line: &str,
+    context: &ServerContext,
+    connection: &mut Connection,
+) -> Option<RpcResponse> {
+    let request: RpcRequest = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(error) => {
+            return Some(RpcResponse::new(
+                Value::Null,
+                Err(RpcError::new(
+                    PARSE_ERROR,
+                    format!("JSON 파싱 실패: {}", error),
+                )),
+            ))
+        }
+    };
+    let result = handle_request(&request, context, connection);
+    request.id.map(|id| RpcResponse::new(id, result))
+}
+
+/// Next notification for a subscribed connection; never resolves when not subscribed
+#[cfg(unix)]
+async fn next_event(
+    receiver: &mut Option<broadcast::Receiver<RpcNotification>>,
+) -> Option<RpcNotification> {
+    let Some(receiver) = receiver.as_mut() else {
+        return std::future::pending().await;
+    };
+    loop {
+        match receiver.recv().await {
+            Ok(notification) => return Some(notification),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::debug!("데몬 알림 {}건 건너뜀", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => return None,
+        }
+    }
+}
+
+#[cfg(unix)]
+enum Incoming {
+    Line(String),
+    Event(RpcNotification),
+    Closed,
+}
+
+#[cfg(unix)]
+async fn handle_connection(stream: UnixStream, context: Arc<ServerContext>) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = tokio::io::BufReader::new(reader).lines();
+    let mut connection = Connection::default();
+
+    loop {
+        let incoming = tokio::select! {
+            line = lines.next_line() => match line {
+                Ok(Some(line)) => Incoming::Line(line),
+                _ => Incoming::Closed,
+            },
+            Some(notification) = next_event(&mut connection.events) => Incoming::Event(notification),
+        };
+
+        let message = match incoming {
+            Incoming::Line(line) if line.trim().is_empty() => continue,
+            Incoming::Line(line) => match handle_line(&line, &context, &mut connection) {
+                Some(response) => serde_json::to_string(&response),
+                None => continue,
+            },
+            Incoming::Event(notification) => serde_json::to_string(&notification),
+            Incoming::Closed => break,
+        };
+        let Ok(mut message) = message else {
+            continue;
+        };
+        message.push('\n');
+        if writer.write_all(message.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Remove a socket left behind by a daemon that exited without cleaning up
+#[cfg(unix)]
+fn prepare_socket(socket: &Path) -> Result<(), String> {
+    if !socket.exists() {
+        return Ok(());
+    }
+    if StdUnixStream::connect(socket).is_ok() {
+        return Err("다른 hoego 데몬이 이미 실행 중입니다".to_string());
+    }
+    fs::remove_file(socket).map_err(|e| format!("이전 소켓 삭제 실패: {}", e))
+}
+
+/// Write a fresh token readable only by the current user
+#[cfg(unix)]
+fn write_token() -> Result<String, String> {
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    let path = token_path()?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(&path)
+        .map_err(|e| format!("토큰 파일 생성 실패: {}", e))?;
+    file.write_all(token.as_bytes())
+        .map_err(|e| format!("토큰 파일 쓰기 실패: {}", e))?;
+    Ok(token)
+}
+
+/// Remove the socket and token files if this process is serving them
+pub fn cleanup() {
+    if !SERVING.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    for path in [socket_path(), token_path()].into_iter().flatten() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// Bound daemon socket, ready to accept connections
+#[cfg(unix)]
+pub struct DaemonServer {
+    listener: UnixListener,
+    context: Arc<ServerContext>,
+}
+
+#[cfg(unix)]
+impl DaemonServer {
+    /// Bind the socket and write a fresh token (fails if another daemon is running)
+    pub fn bind(state: Arc<HistoryState>, on_write: WriteHook) -> Result<Self, String> {
+        let socket = socket_path()?;
+        prepare_socket(&socket)?;
+        let listener =
+            UnixListener::bind(&socket).map_err(|e| format!("데몬 소켓 열기 실패: {}", e))?;
+        fs::set_permissions(&socket, fs::Permissions::from_mode(0o600))
+            .map_err(|e| format!("데몬 소켓 권한 설정 실패: {}", e))?;
+        SERVING.store(true, Ordering::SeqCst);
+
+        let context = Arc::new(ServerContext {
+            state,
+            token: write_token()?,
+            on_write,
+        });
+        tracing::info!("데몬 시작: {:?}", socket);
+        Ok(Self { listener, context })
+    }
+
+    /// Accept connections until the task is dropped
+    pub async fn run(self) {
+        loop {
+            match self.listener.accept().await {
+                Ok((stream, _)) => {
+                    tokio::spawn(handle_connection(stream, self.context.clone()));
+                }
+                Err(error) => tracing::warn!("데몬 연결 수락 실패: {}", error),
+            }
+        }
+    }
+}
+
+/// Serve the socket from the app; writes through it also reach the app's windows
+#[cfg(unix)]
+pub fn start_in_app(app: AppHandle) {
+    let state = Arc::new(HistoryState {
+        directory: app.state::<HistoryState>().directory.clone(),
+    });
+    let on_write: WriteHook = Arc::new(move |state| {
+        if let Err(error) = history_service::emit_history_update(&app, state) {
+            tracing::warn!("히스토리 업데이트 이벤트 발송 실패: {}", error);
+        }
+    });
+
+    tauri::async_runtime::spawn(async move {
+        match DaemonServer::bind(state, on_write) {
+            Ok(server) => server.run().await,
+            Err(error) => tracing::warn!("데몬을 시작하지 못했습니다: {}", error),
+        }
+    });
+}
+
+/// Blocking client used by the CLI so its writes go through the running daemon
+#[cfg(unix)]
+pub struct DaemonClient {
+    reader: BufReader<StdUnixStream>,
+    writer: StdUnixStream,
+    next_id: u64,
+}
+
+#[cfg(unix)]
+impl DaemonClient {
+    /// Connect and authenticate; None when no daemon is running
+    pub fn connect() -> Option<Self> {
+        let socket = socket_path().ok()?;
+        if !socket.exists() {
+            return None;
+        }
+        let token = fs::read_to_string(token_path().ok()?).ok()?;
+        let stream = StdUnixStream::connect(&socket).ok()?;
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT)).ok()?;
+
+        let mut client = Self {
+            reader: BufReader::new(stream.try_clone().ok()?),
+            writer: stream,
+            next_id: 1,
+        };
+        match client.call("auth", json!({ "token": token.trim() })) {
+            Ok(_) => Some(client),
+            Err(error) => {
+                tracing::warn!("데몬 인증 실패, 직접 기록합니다: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Call a method and wait for its response (notifications in between are skipped)
+    pub fn call(&mut self, method: &str, params: Value) -> Result<Value, String> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let request = RpcRequest {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Some(json!(id)),
+            method: method.to_string(),
+            params,
+        };
+        let line = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+        writeln!(self.writer, "{}", line).map_err(|e| format!("데몬 요청 실패: {}", e))?;
+
+        loop {
+            let mut line = String::new();
+            let read = self
+                .reader
+                .read_line(&mut line)
+                .map_err(|e| format!("데몬 응답 읽기 실패: {}", e))?;
+            if read == 0 {
+                return Err("데몬 연결이 끊어졌습니다".to_string());
+            }
+            let Ok(response) = serde_json::from_str::<RpcResponse>(&line) else {
+                continue;
+            };
+            if response.id != json!(id) {
+                continue;
+            }
+            return match (response.result, response.error) {
+                (_, Some(error)) => Err(error.message),
+                (Some(result), None) => Ok(result),
+                (None, None) => Ok(Value::Null),
+            };
+        }
+    }
+
+    /// Call a method and deserialize its result
+    pub fn call_as<T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Value,
+    ) -> Result<T, String> {
+        let result = self.call(method, params)?;
+        serde_json::from_value(result).map_err(|e| format!("데몬 응답 형식 오류: {}", e))
+    }
+}
+
+/// No daemon socket on this platform; the CLI always writes directly
+#[cfg(not(unix))]
+pub struct DaemonClient;
+
+#[cfg(not(unix))]
+impl DaemonClient {
+    pub fn connect() -> Option<Self> {
+        None
+    }
+
+    pub fn call_as<T: DeserializeOwned>(
+        &mut self,
+        _method: &str,
+        _params: Value,
+    ) -> Result<T, String> {
+        Err("이 플랫폼에서는 데몬을 지원하지 않습니다".to_string())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn context(token: &str) -> (tempfile::TempDir, ServerContext) {
+        let directory = tempfile::tempdir().expect("tempdir");
+        let context = ServerContext {
+            state: Arc::new(HistoryState {
+                directory: directory.path().to_path_buf(),
+            }),
+            token: token.to_string(),
+            on_write: Arc::new(|_| {}),
+        };
+        (directory, context)
+    }
+
+    fn request(line: &str, context: &ServerContext, connection: &mut Connection) -> RpcResponse {
+        handle_line(line, context, connection).expect("response")
+    }
+
+    #[test]
+    fn requires_token_before_other_methods() {
+        let (_directory, context) = context("secret");
+        let mut connection = Connection::default();
+
+        let denied = request(
+            r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#,
+            &context,
+            &mut connection,
+        );
+        assert_eq!(denied.error.map(|e| e.code), Some(UNAUTHORIZED));
+
+        let wrong = request(
+            r#"{"jsonrpc":"2.0","id":2,"method":"auth","params":{"token":"nope"}}"#,
+            &context,
+            &mut connection,
+        );
+        assert_eq!(wrong.error.map(|e| e.code), Some(UNAUTHORIZED));
+
+        let ok = request(
+            r#"{"jsonrpc":"2.0","id":3,"method":"auth","params":{"token":"secret"}}"#,
+            &context,
+            &mut connection,
+        );
+        assert_eq!(ok.result, Some(json!(true)));
+
+        let pong = request(
+            r#"{"jsonrpc":"2.0","id":4,"method":"ping"}"#,
+            &context,
+            &mut connection,
+        );
+        assert!(pong.result.is_some());
+        assert_eq!(pong.id, json!(4));
+    }
+
+    #[test]
+    fn writes_from_hooks_fire_no_hooks() {
+        let from_hook =
+            scoped_to_origin(&json!({ "origin": "hook" }), hook_service::hooks_suppressed);
+        assert!(from_hook);
+        assert!(!hook_service::hooks_suppressed());
+
+        let from_user = scoped_to_origin(
+            &json!({ "text": "직접 기록" }),
+            hook_service::hooks_suppressed,
+        );
+        assert!(!from_user);
+    }
+
+    #[test]
+    fn inline_token_and_error_codes() {
+        let (_directory, context) = context("secret");
+        let mut connection = Connection::default();
+
+        let unknown = request(
+            r#"{"jsonrpc":"2.0","id":"a","method":"frobnicate","params":{"token":"secret"}}"#,
+            &context,
+            &mut connection,
+        );
+        assert_eq!(unknown.error.map(|e| e.code), Some(METHOD_NOT_FOUND));
+
+        let bad_params = request(
+            r#"{"jsonrpc":"2.0","id":1,"method":"append","params":{"txt":"x"}}"#,
+            &context,
+            &mut connection,
+        );
+        assert_eq!(bad_params.error.map(|e| e.code), Some(INVALID_PARAMS));
+
+        let parse = request("{not json", &context, &mut connection);
+        assert_eq!(parse.error.map(|e| e.code), Some(PARSE_ERROR));
+
+        // Notifications (no id) get no response
+        assert!(handle_line(
+            r#"{"jsonrpc":"2.0","method":"ping"}"#,
+            &context,
+            &mut connection
+        )
+        .is_none());
+    }
+}
@@ -7,9 +7,43 @@ use time::format_description::well_known::Rfc3339;
 use time::macros::format_description;
 use time::OffsetDateTime;
 
-use crate::models::feedback::AiSummaryFile;
+use crate::models::dump::HistoryState;
+use crate::models::feedback::{AiSummaryFile, StreamCancellationState};
+use crate::models::hooks::{FeedbackHookData, HookEvent};
+use crate::models::settings::CalendarSettings;
+use crate::services::history_service::ensure_daily_file;
+use crate::services::{calendar_service, hook_service, llm, session_service};
+use crate::utils::pii_masker;
 use crate::utils::*;
 
+/// Cloud model used when none has been selected
+pub const DEFAULT_CLOUD_MODEL: &str = "gpt-4-turbo";
+
+/// Where feedback is generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedbackBackend {
+    /// The bundled llama-server engine, or the external endpoint when one is selected
+    Local,
+    /// The configured cloud provider with the given model id
+    Cloud { model_id: String },
+}
+
+impl FeedbackBackend {
+    pub fn is_cloud(&self) -> bool {
+        matches!(self, FeedbackBackend::Cloud { .. })
+    }
+}
+
+/// A day's dump ready to be sent to the model
+#[derive(Debug, Clone)]
+pub struct FeedbackInput {
+    pub date: OffsetDateTime,
+    /// Dump plus calendar context, PII-masked when it leaves the machine
+    pub content: String,
+    pub original_length: usize,
+    pub pii_masked: bool,
+}
+
 /// Ensure AI summaries directory exists
 pub fn ensure_summaries_dir(path: &PathBuf) -> Result<(), String> {
     fs::create_dir_all(path)
@@ -49,3 +83,240 @@ pub fn write_ai_summary_file(date: &OffsetDateTime, content: &str, pii_masked: b
         pii_masked,
     })
 }
+
+/// List saved AI feedback of a day (YYYYMMDD), newest first
+pub fn list_summaries(date_key: &str, limit: usize) -> Result<Vec<AiSummaryFile>, String> {
+    let dir = summaries_directory_path()?;
+    ensure_summaries_dir(&dir)?;
+
+    let mut summaries: Vec<(OffsetDateTime, AiSummaryFile)> = fs::read_dir(&dir)
+        .map_err(|error| error.to_string())?
+        .filter_map(|entry| match entry {
+            Ok(entry) => {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+                    return None;
+                }
+                let filename = entry.file_name().to_string_lossy().into_owned();
+
+                // Filter for the specified date's summaries (ai-feedback-YYYYMMDD*.md pattern)
+                if !filename.starts_with(&format!("ai-feedback-{date_key}")) {
+                    return None;
+                }
+
+                let content = fs::read_to_string(&path).unwrap_or_default();
+
+                // 개인정보 보호 메타데이터 파싱
+                let pii_masked = content.contains("개인정보 보호: 적용됨");
+
+                let metadata = entry.metadata().ok();
+                let (sort_key, created_at) = metadata
+                    .and_then(|meta| meta.modified().ok())
+                    .map(|modified| {
+                        let odt: OffsetDateTime = modified.into();
+                        let iso = odt.format(&Rfc3339).unwrap_or_else(|_| odt.to_string());
+                        (odt, Some(iso))
+                    })
+                    .unwrap_or((OffsetDateTime::UNIX_EPOCH, None));
+
+                Some((
+                    sort_key,
+                    AiSummaryFile {
+                        filename,
+                        path: path.to_string_lossy().into_owned(),
+                        created_at,
+                        content,
+                        pii_masked,
+                    },
+                ))
+            }
+            Err(_) => None,
+        })
+        .collect();
+
+    summaries.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    Ok(summaries
+        .into_iter()
+        .take(limit)
+        .map(|(_, summary)| summary)
+        .collect())
+}
+
+/// Append the day's calendar events so the model sees meetings that were not dumped
+pub fn with_calendar_context(content: String, date: &OffsetDateTime, settings: &CalendarSettings) -> String {
+    match calendar_service::feedback_context(date, settings) {
+        Some(section) => format!("{}\n\n{}", content.trim_end(), section),
+        None => content,
+    }
+}
+
+/// Load a day's dump for feedback, masking PII when it is sent to a cloud model
+pub fn prepare_feedback_input(
+    state: &HistoryState,
+    date: &OffsetDateTime,
+    calendar: &CalendarSettings,
+    mask: bool,
+) -> Result<FeedbackInput, String> {
+    let (path, _) = ensure_daily_file(state, date)?;
+    let content = fs::read_to_string(&path).unwrap_or_default();
+
+    if content.trim().is_empty() {
+        return Err("오늘 기록된 내용이 없어 요약을 생성할 수 없습니다.".into());
+    }
+    let content = match session_service::feedback_context(&content) {
+        Some(section) => format!("{}\n\n{}", content.trim_end(), section),
+        None => content,
+    };
+    let content = with_calendar_context(content, date, calendar);
+    let original_length = content.len();
+
+    // 🔒 개인정보 마스킹 처리 (클라우드 LLM 사용 시에만)
+    let (content, pii_masked) = if mask {
+        eprintln!("[PII Masking] Cloud LLM detected - applying PII masking");
+        let masked = pii_masker::mask_pii(&content, false);
+        let detected = content != masked;
+
+        eprintln!("[PII Masking] Original length: {} chars", original_length);
+        eprintln!("[PII Masking] Masked length: {} chars", masked.len());
+        if detected {
+            eprintln!("[PII Masking] ⚠️ PII detected and masked");
+        } else {
+            eprintln!("[PII Masking] ✅ No PII detected");
+        }
+
+        (masked, detected)
+    } else {
+        eprintln!("[PII Masking] Local model detected - skipping PII masking");
+        (content, false)
+    };
+
+    Ok(FeedbackInput {
+        date: *date,
+        content,
+        original_length,
+        pii_masked,
+    })
+}
+
+/// Stream unified feedback for `input`, calling `on_delta` with each chunk
+///
+/// Returns the full text, or `None` when the stream was cancelled.
+pub async fn stream_feedback(
+    input: &FeedbackInput,
+    backend: &FeedbackBackend,
+    llm_manager: &llm::LLMManager,
+    cloud: &llm::CloudLLMState,
+    cancellation: &StreamCancellationState,
+    mut on_delta: impl FnMut(&str) + Send,
+) -> Result<Option<String>, String> {
+    // 프롬프트 구성 (v7.0 통합 프롬프트 - 로컬/클라우드 동일)
+    eprintln!("[Prompt Selection] Using unified research-based prompt (v7.0)");
+    let prompt = llm::prompts::PromptTemplate::for_unified_feedback(&input.content);
+    let chat_messages = prompt.to_chat_format();
+
+    match backend {
+        FeedbackBackend::Cloud { model_id } => {
+            eprintln!("[AI Feedback] Using Cloud LLM: {}", model_id);
+
+            // 프롬프트를 Cloud LLM 형식으로 변환
+            let messages: Vec<llm::types::Message> = chat_messages
+                .iter()
+                .map(|msg| llm::types::Message {
+                    role: match msg.role.as_str() {
+                        "system" => llm::types::Role::System,
+                        "assistant" => llm::types::Role::Assistant,
+                        _ => llm::types::Role::User,
+                    },
+                    content: msg.content.clone(),
+                })
+                .collect();
+
+            let request = llm::types::CompletionRequest {
+                messages,
+                model: model_id.clone(),
+                temperature: Some(0.7),
+                max_tokens: Some(4000),
+                system_prompt: None,
+                metadata: None,
+            };
+
+            let provider_lock = cloud.current_provider.read().await;
+            let provider = provider_lock.as_ref().ok_or_else(|| {
+                "Cloud LLM이 설정되지 않았습니다. 설정에서 API 키를 등록해주세요.".to_string()
+            })?;
+            let mut rx = provider
+                .stream(request)
+                .await
+                .map_err(|e| format!("Cloud LLM 오류: {}", e))?;
+
+            let mut full_text = String::new();
+            while let Some(delta) = rx.recv().await {
+                // 취소는 에러가 아니라 조기 종료로 처리
+                if cancellation.is_cancelled() {
+                    eprintln!("[Cloud LLM Stream] Cancelled by user");
+                    return Ok(None);
+                }
+                full_text.push_str(&delta);
+                on_delta(&delta);
+            }
+            Ok(Some(full_text))
+        }
+        FeedbackBackend::Local => {
+            // 외부 로컬 서버가 선택되어 있으면 번들 엔진 대신 사용 (취소 시 연결을 끊음)
+            if let Some(endpoint) = llm_manager.endpoint().await {
+                eprintln!("[AI Feedback] Using local endpoint: {}", endpoint.model());
+                let result = endpoint
+                    .chat_complete_stream(
+                        chat_messages,
+                        None,
+                        None,
+                        || cancellation.is_cancelled(),
+                        |delta| on_delta(delta),
+                    )
+                    .await
+                    .map_err(|e| e.to_string());
+
+                if cancellation.is_cancelled() {
+                    return Ok(None);
+                }
+                return result.map(Some);
+            }
+
+            let mut engine = llm_manager.engine.lock().await;
+            let cancel_check = cancellation.clone();
+
+            let result = engine
+                .chat_complete_stream(chat_messages, None, None, |delta| {
+                    if !cancel_check.is_cancelled() {
+                        on_delta(delta);
+                    }
+                })
+                .await
+                .map_err(|e| e.to_string());
+
+            // 스트리밍 완료 후 취소 확인
+            if cancellation.is_cancelled() {
+                return Ok(None);
+            }
+            result.map(Some)
+        }
+    }
+}
+
+/// Save generated feedback for the day, keeping the LLM output as-is
+pub fn save_feedback(date: &OffsetDateTime, text: &str, pii_masked: bool) -> Result<AiSummaryFile, String> {
+    let markdown = if text.trim().is_empty() {
+        "(생성된 요약이 비어 있습니다)".to_string()
+    } else {
+        text.trim().to_string()
+    };
+    let summary = write_ai_summary_file(date, &markdown, pii_masked)?;
+    hook_service::fire(
+        HookEvent::FeedbackGenerated,
+        &FeedbackHookData {
+            date: format_date_key(date)?,
+            summary: summary.clone(),
+        },
+    );
+    Ok(summary)
+}
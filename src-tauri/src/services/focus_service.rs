@@ -0,0 +1,424 @@
+// services/focus_service.rs
+// Focus timer (Pomodoro): persisted timer state and the dump entries it writes
+
+use regex::Regex;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration as StdDuration;
+use tauri::{AppHandle, Manager};
+use time::OffsetDateTime;
+
+use crate::models::dump::HistoryState;
+use crate::models::focus::{FocusInterruption, FocusPhase, FocusRecord, FocusTimer};
+use crate::models::hooks::{EntryHookData, HookEvent};
+use crate::services::{history_service, hook_service};
+use crate::utils::{current_local_time, format_time_with_seconds};
+
+pub const DEFAULT_FOCUS_MINUTES: i64 = 25;
+pub const DEFAULT_BREAK_MINUTES: i64 = 5;
+const MAX_MINUTES: i64 = 12 * 60;
+
+const FOCUS_ICON: &str = "🍅";
+const BREAK_ICON: &str = "☕";
+const INTERRUPTION_ICON: &str = "⚡";
+const BREAK_INTENT: &str = "휴식";
+
+/// Event emitted by the app when a timer finishes in the background
+pub const FOCUS_COMPLETED_EVENT: &str = "focus_timer_completed";
+
+static FOCUS_ENTRY_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_focus_entry_regex() -> &'static Regex {
+    FOCUS_ENTRY_REGEX.get_or_init(|| {
+        // "🍅 작업명 25m 완료", "☕ 휴식 5m 완료", "🍅 작업명 12m 중단 · 방해 2회"
+        Regex::new(r"^(🍅|☕)\s*(.+?)\s+(\d+)m\s+(완료|중단)(?:\s*·.*)?$").unwrap()
+    })
+}
+
+/// Get the timer state file path
+pub fn get_state_path() -> Result<PathBuf, String> {
+    let data_dir =
+        dirs::data_dir().ok_or_else(|| "데이터 디렉토리를 찾을 수 없습니다".to_string())?;
+    let app_data_dir = data_dir.join("hoego");
+    fs::create_dir_all(&app_data_dir).map_err(|e| format!("데이터 디렉토리 생성 실패: {}", e))?;
+    Ok(app_data_dir.join("focus_timer.json"))
+}
+
+fn load_timer() -> Result<Option<FocusTimer>, String> {
+    let path = get_state_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("집중 타이머 읽기 실패: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("집중 타이머 파싱 실패: {}", e))
+}
+
+fn save_timer(timer: Option<&FocusTimer>) -> Result<(), String> {
+    let path = get_state_path()?;
+    match timer {
+        Some(timer) => {
+            let content = serde_json::to_string_pretty(timer)
+                .map_err(|e| format!("집중 타이머 직렬화 실패: {}", e))?;
+            fs::write(&path, content).map_err(|e| format!("집중 타이머 저장 실패: {}", e))
+        }
+        None if path.exists() => {
+            fs::remove_file(&path).map_err(|e| format!("집중 타이머 삭제 실패: {}", e))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Dump text for a finished block, e.g. "🍅 작업명 25m 완료" (without the time label)
+pub fn format_record_text(
+    phase: FocusPhase,
+    intent: &str,
+    minutes: i64,
+    completed: bool,
+    interruptions: usize,
+) -> String {
+    let icon = match phase {
+        FocusPhase::Focus => FOCUS_ICON,
+        FocusPhase::Break => BREAK_ICON,
+    };
+    let mut text = format!(
+        "{} {} {}m {}",
+        icon,
+        intent,
+        minutes,
+        if completed { "완료" } else { "중단" }
+    );
+    if interruptions > 0 {
+        text.push_str(&format!(" · 방해 {}회", interruptions));
+    }
+    text
+}
+
+/// Parse the text of a focus or break entry (time label removed) into (phase, intent, seconds)
+pub fn parse_record_text(text: &str) -> Option<(FocusPhase, String, u32)> {
+    let caps = get_focus_entry_regex().captures(text.trim())?;
+    let phase = if &caps[1] == FOCUS_ICON {
+        FocusPhase::Focus
+    } else {
+        FocusPhase::Break
+    };
+    let minutes = caps[3].parse::<u32>().ok()?;
+    Some((phase, caps[2].to_string(), minutes * 60))
+}
+
+fn validate_minutes(minutes: i64) -> Result<i64, String> {
+    if (1..=MAX_MINUTES).contains(&minutes) {
+        Ok(minutes)
+    } else {
+        Err(format!(
+            "시간은 1~{}분 사이여야 합니다: {}",
+            MAX_MINUTES, minutes
+        ))
+    }
+}
+
+fn local_time_at(unix: i64) -> Result<OffsetDateTime, String> {
+    let offset = current_local_time()?.offset();
+    OffsetDateTime::from_unix_timestamp(unix)
+        .map(|time| time.to_offset(offset))
+        .map_err(|e| format!("시간 변환 실패: {}", e))
+}
+
+/// Take the state file so that only one process records a finished timer
+///
+/// The app's watch job and the TUI both poll for due timers; whoever renames the file
+/// first records it. Returns the claimed path with the timer it holds, or `None` when
+/// another process got there first. A different timer (started in the meantime) is put back.
+fn claim_timer(path: &Path, started_at: i64) -> Result<Option<(PathBuf, FocusTimer)>, String> {
+    let claimed = path.with_extension(format!("{}.claimed", std::process::id()));
+    match fs::rename(path, &claimed) {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("집중 타이머 가져오기 실패: {}", e)),
+    }
+
+    let timer = fs::read_to_string(&claimed)
+        .ok()
+        .and_then(|content| serde_json::from_str::<FocusTimer>(&content).ok());
+    match timer {
+        Some(timer) if timer.started_at == started_at => Ok(Some((claimed, timer))),
+        _ => {
+            fs::rename(&claimed, path).map_err(|e| format!("집중 타이머 되돌리기 실패: {}", e))?;
+            Ok(None)
+        }
+    }
+}
+
+/// Insert the finished block into the dump of the day it ended and clear the state
+///
+/// Returns `None` when another process already recorded this timer.
+fn finish(
+    state: &HistoryState,
+    timer: &FocusTimer,
+    ended_at: i64,
+    completed: bool,
+) -> Result<Option<FocusRecord>, String> {
+    let Some((claimed, timer)) = claim_timer(&get_state_path()?, timer.started_at)? else {
+        return Ok(None);
+    };
+
+    let elapsed = (ended_at - timer.started_at).clamp(0, timer.planned_seconds);
+    let minutes = if completed {
+        timer.planned_seconds / 60
+    } else {
+        elapsed / 60
+    };
+    let text = format_record_text(
+        timer.phase,
+        &timer.intent,
+        minutes,
+        completed,
+        timer.interruptions.len(),
+    );
+
+    let ended = local_time_at(ended_at)?;
+    let time_label = format_time_with_seconds(&ended)?;
+    // 끝난 시각 자리에 넣음 (앱과 CLI가 모두 닫혀 있던 동안 끝난 타이머도 순서대로)
+    let recorded = history_service::ensure_daily_file(state, &ended).and_then(|(path, key)| {
+        let inserted = history_service::insert_entry_at_time(state, &ended, &time_label, &text)?;
+        Ok((path, key, inserted))
+    });
+    let (file_path, date_key, inserted) = match recorded {
+        Ok(recorded) => recorded,
+        Err(error) => {
+            // 다음 확인 때 다시 기록하도록 상태를 되돌림
+            let _ = fs::rename(&claimed, get_state_path()?);
+            return Err(error);
+        }
+    };
+    let _ = fs::remove_file(&claimed);
+    tracing::info!("집중 타이머 기록: {}", text);
+
+    let line = history_service::entry_line(&text, &time_label);
+    if inserted {
+        hook_service::fire(
+            HookEvent::EntryAppended,
+            &EntryHookData {
+                date: date_key,
+                file_path: file_path.to_string_lossy().into_owned(),
+                line: line.clone(),
+                text: text.clone(),
+            },
+        );
+    }
+
+    Ok(Some(FocusRecord {
+        phase: timer.phase,
+        intent: timer.intent.clone(),
+        minutes,
+        completed,
+        interruptions: timer.interruptions.len(),
+        line,
+    }))
+}
+
+/// Write the completion entry if the running timer has run out
+///
+/// The entry is stamped with the planned end time, so a timer that ran out while neither
+/// binary was open is still recorded where it belongs.
+pub fn complete_if_due(state: &HistoryState) -> Result<Option<FocusRecord>, String> {
+    let Some(timer) = load_timer()? else {
+        return Ok(None);
+    };
+    if current_local_time()?.unix_timestamp() < timer.ends_at {
+        return Ok(None);
+    }
+    finish(state, &timer, timer.ends_at, true)
+}
+
+/// Running timer, after recording one that has already run out
+pub fn current_timer(state: &HistoryState) -> Result<Option<FocusTimer>, String> {
+    complete_if_due(state)?;
+    load_timer()
+}
+
+fn start(
+    state: &HistoryState,
+    phase: FocusPhase,
+    intent: String,
+    minutes: i64,
+) -> Result<FocusTimer, String> {
+    let minutes = validate_minutes(minutes)?;
+    match current_timer(state)? {
+        Some(running) if running.phase == FocusPhase::Focus => {
+            return Err(format!(
+                "이미 진행 중인 집중 타이머가 있습니다: {}",
+                running.intent
+            ));
+        }
+        // 휴식 중에 새로 시작하면 휴식은 중단으로 기록
+        Some(running) => {
+            finish(
+                state,
+                &running,
+                current_local_time()?.unix_timestamp(),
+                false,
+            )?;
+        }
+        None => {}
+    }
+
+    let started_at = current_local_time()?.unix_timestamp();
+    let timer = FocusTimer {
+        phase,
+        intent,
+        planned_seconds: minutes * 60,
+        started_at,
+        ends_at: started_at + minutes * 60,
+        interruptions: Vec::new(),
+    };
+    save_timer(Some(&timer))?;
+    Ok(timer)
+}
+
+/// Start a focus block for `intent`
+pub fn start_focus(state: &HistoryState, intent: &str, minutes: i64) -> Result<FocusTimer, String> {
+    let intent = intent.trim();
+    if intent.is_empty() {
+        return Err("무엇에 집중할지 입력하세요".to_string());
+    }
+    start(state, FocusPhase::Focus, intent.to_string(), minutes)
+}
+
+/// Start a break (a running break is restarted)
+pub fn start_break(state: &HistoryState, minutes: i64) -> Result<FocusTimer, String> {
+    start(state, FocusPhase::Break, BREAK_INTENT.to_string(), minutes)
+}
+
+/// Note an interruption: counted on the block and written to the dump right away
+pub fn record_interruption(state: &HistoryState, note: Option<&str>) -> Result<FocusTimer, String> {
+    let mut timer = current_timer(state)?
+        .filter(|timer| timer.phase == FocusPhase::Focus)
+        .ok_or("진행 중인 집중 타이머가 없습니다")?;
+    let note = note.map(str::trim).filter(|note| !note.is_empty());
+
+    let now = current_local_time()?;
+    let text = match note {
+        Some(note) => format!("{} 방해: {}", INTERRUPTION_ICON, note),
+        None => format!("{} 방해", INTERRUPTION_ICON),
+    };
+    history_service::append_entry(state, &now, &text)?;
+
+    timer.interruptions.push(FocusInterruption {
+        at: format_time_with_seconds(&now)?,
+        note: note.map(str::to_string),
+    });
+    save_timer(Some(&timer))?;
+    Ok(timer)
+}
+
+/// Stop the running timer early, recording the minutes actually spent
+pub fn stop(state: &HistoryState) -> Result<FocusRecord, String> {
+    let Some(timer) = load_timer()? else {
+        return Err("진행 중인 타이머가 없습니다".to_string());
+    };
+    let now = current_local_time()?.unix_timestamp();
+    // 이미 끝난 타이머는 완료로 기록
+    let (ended_at, completed) = if now < timer.ends_at {
+        (now, false)
+    } else {
+        (timer.ends_at, true)
+    };
+    finish(state, &timer, ended_at, completed)?
+        .ok_or_else(|| "타이머가 이미 기록되었습니다".to_string())
+}
+
+/// Seconds left at `now` (unix timestamp)
+pub fn remaining_seconds(timer: &FocusTimer, now: i64) -> i64 {
+    (timer.ends_at - now).max(0)
+}
+
+/// "MM:SS" countdown label
+pub fn format_countdown(seconds: i64) -> String {
+    format!("{:02}:{:02}", seconds.max(0) / 60, seconds.max(0) % 60)
+}
+
+/// Record timers that run out while the app is open and tell the frontend
+pub fn start_watch_job(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(StdDuration::from_secs(5)).await;
+
+            let state = app.state::<HistoryState>();
+            match complete_if_due(state.inner()) {
+                Ok(Some(record)) => {
+                    if let Err(error) = history_service::emit_history_update(&app, state.inner()) {
+                        tracing::warn!("히스토리 업데이트 이벤트 발송 실패: {}", error);
+                    }
+                    let _ = app.emit_all(FOCUS_COMPLETED_EVENT, &record);
+                }
+                Ok(None) => {}
+                Err(error) => tracing::warn!("집중 타이머 확인 실패: {}", error),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_text_round_trips() {
+        let text = format_record_text(FocusPhase::Focus, "API 설계", 25, true, 0);
+        assert_eq!(text, "🍅 API 설계 25m 완료");
+        assert_eq!(
+            parse_record_text(&text),
+            Some((FocusPhase::Focus, "API 설계".to_string(), 25 * 60))
+        );
+
+        let text = format_record_text(FocusPhase::Focus, "리뷰", 12, false, 2);
+        assert_eq!(text, "🍅 리뷰 12m 중단 · 방해 2회");
+        assert_eq!(
+            parse_record_text(&text),
+            Some((FocusPhase::Focus, "리뷰".to_string(), 12 * 60))
+        );
+
+        let text = format_record_text(FocusPhase::Break, BREAK_INTENT, 5, true, 0);
+        assert_eq!(
+            parse_record_text(&text),
+            Some((FocusPhase::Break, "휴식".to_string(), 5 * 60))
+        );
+        assert_eq!(parse_record_text("🍅 토마토 샀음"), None);
+    }
+
+    #[test]
+    fn only_one_claim_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("focus_timer.json");
+        let timer = FocusTimer {
+            phase: FocusPhase::Focus,
+            intent: "리뷰".to_string(),
+            planned_seconds: 25 * 60,
+            started_at: 1_000,
+            ends_at: 1_000 + 25 * 60,
+            interruptions: Vec::new(),
+        };
+        fs::write(&path, serde_json::to_string(&timer).unwrap()).unwrap();
+
+        // 그 사이 새로 시작된 타이머는 가져가지 않고 되돌려 놓음
+        assert!(claim_timer(&path, 999).unwrap().is_none());
+        assert!(path.exists());
+
+        let (claimed, claimed_timer) = claim_timer(&path, 1_000).unwrap().unwrap();
+        assert_eq!(claimed_timer, timer);
+        assert!(claimed.exists() && !path.exists());
+        assert!(claim_timer(&path, 1_000).unwrap().is_none());
+    }
+
+    #[test]
+    fn countdown_and_minutes() {
+        assert_eq!(format_countdown(25 * 60), "25:00");
+        assert_eq!(format_countdown(-3), "00:00");
+        assert!(validate_minutes(0).is_err());
+        assert_eq!(validate_minutes(25), Ok(25));
+    }
+}
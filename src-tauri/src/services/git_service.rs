@@ -0,0 +1,265 @@
+// services/git_service.rs
+// Import of commits from local git repositories into the daily dump
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration as StdDuration, Instant};
+
+use tauri::{AppHandle, Manager};
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+use crate::models::dump::HistoryState;
+use crate::models::git::{GitCommit, GitSyncResult};
+use crate::models::settings::{AppSettingsState, GitSettings};
+use crate::services::{history_service, weekly_service};
+use crate::utils::*;
+
+/// Tag appended to imported entries; the built-in "개발" rule matches it
+pub const COMMIT_TAG: &str = "#commit";
+
+/// Separates fields in the `git log` format string
+const FIELD_SEPARATOR: char = '\u{1f}';
+
+/// Serializes manual and background imports so a commit is never written twice
+static SYNC_LOCK: Mutex<()> = Mutex::new(());
+
+/// Directory name used as the repository label
+pub fn repo_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+/// Parse `git log --format=%H%x1f%ct%x1f%s` output
+pub fn parse_log_output(output: &str, repo: &str, offset: UtcOffset) -> Vec<(OffsetDateTime, GitCommit)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, FIELD_SEPARATOR);
+            let hash = fields.next()?.trim();
+            let seconds: i64 = fields.next()?.trim().parse().ok()?;
+            let subject = fields.next().unwrap_or("").trim();
+            if hash.is_empty() {
+                return None;
+            }
+
+            let committed = OffsetDateTime::from_unix_timestamp(seconds).ok()?.to_offset(offset);
+            Some((
+                committed,
+                GitCommit {
+                    hash: hash.to_string(),
+                    repo: repo.to_string(),
+                    subject: subject.to_string(),
+                    date: format_date_key(&committed).ok()?,
+                    time: format_time_with_seconds(&committed).ok()?,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Read commits on local branches committed in [start, end)
+pub fn read_commits(
+    repo_path: &Path,
+    start: &OffsetDateTime,
+    end: &OffsetDateTime,
+    author: Option<&str>,
+) -> Result<Vec<GitCommit>, String> {
+    let since = start.format(&Rfc3339).map_err(|e| e.to_string())?;
+    let until = end.format(&Rfc3339).map_err(|e| e.to_string())?;
+
+    let mut command = Command::new("git");
+    command
+        .arg("-C")
+        .arg(repo_path)
+        .args(["log", "--branches", "--no-merges", "--format=%H%x1f%ct%x1f%s"])
+        .arg(format!("--since={since}"))
+        .arg(format!("--until={until}"));
+    if let Some(author) = author.map(str::trim).filter(|a| !a.is_empty()) {
+        command.arg(format!("--author={author}"));
+    }
+
+    let output = command
+        .output()
+        .map_err(|error| format!("git 실행 실패: {error}, 경로: {:?}", repo_path))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git log 실패: {}, 경로: {:?}",
+            String::from_utf8_lossy(&output.stderr).trim(),
+            repo_path
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut commits: Vec<(OffsetDateTime, GitCommit)> =
+        parse_log_output(&stdout, &repo_name(repo_path), start.offset())
+            .into_iter()
+            // `--since`/`--until` are inclusive on both ends
+            .filter(|(committed, _)| committed >= start && committed < end)
+            .collect();
+    commits.sort_by_key(|(committed, _)| *committed);
+    Ok(commits.into_iter().map(|(_, commit)| commit).collect())
+}
+
+/// Entry text written to the dump: "[repo] subject #commit"
+pub fn entry_text(commit: &GitCommit) -> String {
+    format!("[{}] {} {}", commit.repo, commit.subject, COMMIT_TAG)
+}
+
+/// Get the index of imported commit hashes
+fn get_index_path() -> Result<PathBuf, String> {
+    let data_dir = dirs::data_dir().ok_or_else(|| "데이터 디렉토리를 찾을 수 없습니다".to_string())?;
+    let app_data_dir = data_dir.join("hoego");
+    fs::create_dir_all(&app_data_dir).map_err(|e| format!("데이터 디렉토리 생성 실패: {}", e))?;
+    Ok(app_data_dir.join("git_commits.json"))
+}
+
+fn load_index(path: &Path) -> Result<BTreeSet<String>, String> {
+    if !path.exists() {
+        return Ok(BTreeSet::new());
+    }
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("파일 읽기 실패: {error}, 경로: {:?}", path))?;
+    serde_json::from_str(&content).map_err(|e| format!("커밋 목록 파싱 실패: {}", e))
+}
+
+fn save_index(path: &Path, hashes: &BTreeSet<String>) -> Result<(), String> {
+    let json = serde_json::to_string(hashes).map_err(|e| format!("커밋 목록 직렬화 실패: {}", e))?;
+    fs::write(path, json).map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", path))
+}
+
+/// Copy one day's commits into its dump at their commit times
+///
+/// `date` is local midnight of the day. Hashes of imported commits are remembered, so
+/// an entry the user deleted is not brought back by the next sync.
+pub fn sync_day(
+    history_state: &HistoryState,
+    date: &OffsetDateTime,
+    settings: &GitSettings,
+) -> Result<GitSyncResult, String> {
+    let _guard = SYNC_LOCK.lock().map_err(|e| format!("git 동기화 잠금 실패: {}", e))?;
+
+    let end = *date + Duration::days(1);
+    let index_path = get_index_path()?;
+    let mut imported = load_index(&index_path)?;
+
+    let mut result = GitSyncResult {
+        date: format_date_key(date)?,
+        added: Vec::new(),
+        skipped: 0,
+        errors: Vec::new(),
+    };
+
+    for repo in settings.repositories.iter().filter(|r| !r.trim().is_empty()) {
+        let commits = match read_commits(Path::new(repo), date, &end, settings.author.as_deref()) {
+            Ok(commits) => commits,
+            Err(error) => {
+                tracing::warn!("커밋 읽기 실패: {}", error);
+                result.errors.push(error);
+                continue;
+            }
+        };
+
+        for commit in commits {
+            if imported.contains(&commit.hash) {
+                result.skipped += 1;
+                continue;
+            }
+            if history_service::insert_entry_at_time(history_state, date, &commit.time, &entry_text(&commit))? {
+                result.added.push(commit.clone());
+            } else {
+                result.skipped += 1;
+            }
+            imported.insert(commit.hash);
+        }
+    }
+
+    save_index(&index_path, &imported)?;
+    tracing::info!(
+        "git 커밋 가져오기 완료: {}, 추가 {}건, 건너뜀 {}건",
+        result.date,
+        result.added.len(),
+        result.skipped
+    );
+    Ok(result)
+}
+
+/// Periodically import today's commits while the app runs
+///
+/// Settings are re-read every minute, so changing the interval or repositories takes
+/// effect without a restart.
+pub fn start_sync_job(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last_sync: Option<Instant> = None;
+        loop {
+            tokio::time::sleep(StdDuration::from_secs(60)).await;
+
+            let Some(settings) = app
+                .try_state::<AppSettingsState>()
+                .and_then(|state| state.settings.lock().ok().map(|s| s.git.clone()))
+            else {
+                continue;
+            };
+            if settings.sync_interval_minutes == 0 || settings.repositories.is_empty() {
+                continue;
+            }
+            let interval = StdDuration::from_secs(settings.sync_interval_minutes as u64 * 60);
+            if last_sync.is_some_and(|at| at.elapsed() < interval) {
+                continue;
+            }
+            last_sync = Some(Instant::now());
+
+            let app = app.clone();
+            let job = tauri::async_runtime::spawn_blocking(move || -> Result<usize, String> {
+                let state = app.state::<HistoryState>();
+                let today = weekly_service::local_midnight(current_local_time()?.date());
+                let result = sync_day(state.inner(), &today, &settings)?;
+                if !result.added.is_empty() {
+                    history_service::emit_history_update(&app, state.inner())?;
+                }
+                Ok(result.added.len())
+            });
+            match job.await {
+                Ok(Ok(added)) => tracing::debug!("주기적 git 커밋 가져오기: {}건 추가", added),
+                Ok(Err(error)) => tracing::warn!("주기적 git 커밋 가져오기 실패: {}", error),
+                Err(error) => tracing::warn!("주기적 git 커밋 작업 실패: {}", error),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::category_service::{default_rules, CategoryMatcher};
+
+    #[test]
+    fn parses_log_lines_in_local_time() {
+        let output = "abc123\u{1f}1704157200\u{1f}Fix parser: handle tabs\nbroken line\n";
+        let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+
+        let commits = parse_log_output(output, "hoego", offset);
+        assert_eq!(commits.len(), 1);
+        let commit = &commits[0].1;
+        assert_eq!(commit.date, "20240102");
+        assert_eq!(commit.time, "10:00:00");
+        assert_eq!(entry_text(commit), "[hoego] Fix parser: handle tabs #commit");
+    }
+
+    #[test]
+    fn commit_entries_count_as_development() {
+        let matcher = CategoryMatcher::new(&default_rules());
+        let commit = GitCommit {
+            hash: "abc".to_string(),
+            repo: "hoego".to_string(),
+            subject: "회의록 템플릿 정리".to_string(),
+            date: "20240102".to_string(),
+            time: "10:00:00".to_string(),
+        };
+        assert_eq!(matcher.categorize(&entry_text(&commit)).category, "개발");
+    }
+}
@@ -1,11 +1,22 @@
 // services/history_service.rs
 // Daily dump and history management service
 
-use crate::models::dump::{AppendHistoryEntryPayload, HistoryFileInfo, HistoryOverview, HistoryState};
+use crate::models::dump::{
+    AppendHistoryEntryPayload, EntrySearchHit, HistoryFileInfo, HistoryOverview, HistoryState,
+    TodayMarkdown,
+};
+use crate::models::hooks::{DayHookData, EntryHookData, HookEvent, RetroHookData};
+use crate::models::settings::CalendarSettings;
+use crate::services::{
+    calendar_service, daemon_service, hook_service, storage_service, task_service,
+};
 use crate::utils::*;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use regex::Regex;
 use tauri::{AppHandle, Manager};
 use time::format_description::well_known::Rfc3339;
 use time::{OffsetDateTime, UtcOffset};
@@ -34,10 +45,20 @@ pub fn ensure_daily_file(
     tracing::debug!("파일 경로 확인: {:?}", file_path);
 
     if !file_path.exists() {
-        let header = format!("# {}\n\n", format_date_label(timestamp));
+        let mut header = format!("# {}\n\n", format_date_label(timestamp));
+        if let Some(section) = carry_over_section(state, &date_key) {
+            header.push_str(&section);
+        }
         fs::write(&file_path, header)
             .map_err(|error| format!("파일 생성 실패: {error}, 경로: {:?}", file_path))?;
         tracing::debug!("새 파일 생성: {:?}", file_path);
+        hook_service::fire(
+            HookEvent::DayCreated,
+            &DayHookData {
+                date: date_key.clone(),
+                file_path: file_path.to_string_lossy().into_owned(),
+            },
+        );
     } else {
         tracing::debug!("기존 파일 사용: {:?}", file_path);
     }
@@ -45,6 +66,29 @@ pub fn ensure_daily_file(
     Ok((file_path, date_key))
 }
 
+/// Builds the carry-over task section when creating today's file, if enabled in settings
+fn carry_over_section(state: &HistoryState, date_key: &str) -> Option<String> {
+    let today_key = current_local_time().ok().and_then(|now| format_date_key(&now).ok())?;
+    if date_key != today_key {
+        return None;
+    }
+
+    let enabled = storage_service::load_settings()
+        .map(|settings| settings.carry_over_tasks)
+        .unwrap_or(false);
+    if !enabled {
+        return None;
+    }
+
+    match task_service::build_carry_over_section(&state.directory, date_key) {
+        Ok(section) => section,
+        Err(e) => {
+            tracing::warn!("할 일 이월 실패: {}", e);
+            None
+        }
+    }
+}
+
 /// Appends a markdown entry to the daily file
 pub fn append_markdown_entry(
     state: &HistoryState,
@@ -68,15 +112,34 @@ pub fn append_markdown_entry(
 
     let kst_timestamp = timestamp.to_offset(kst_offset);
     tracing::debug!("변환된 시간(KST): {:?}", kst_timestamp);
+    tracing::debug!("새 분 여부: {}", payload.is_new_minute);
 
-    let time_label_with_seconds = format_time_with_seconds(&kst_timestamp)?;
+    let file_path = append_entry(state, &kst_timestamp, &payload.task)?;
+    tracing::debug!("append_markdown_entry 성공");
+
+    Ok(file_path)
+}
+
+/// A top-level dump entry: `- text (HH:MM:SS)`
+pub fn entry_line(text: &str, time_label: &str) -> String {
+    format!("- {} ({})", text, time_label)
+}
+
+/// Appends `- text (HH:MM:SS)` to the daily file of the given time
+///
+/// Shared by the GUI and the CLI so both write entries the same way.
+pub fn append_entry(
+    state: &HistoryState,
+    timestamp: &OffsetDateTime,
+    text: &str,
+) -> Result<PathBuf, String> {
+    let time_label_with_seconds = format_time_with_seconds(timestamp)?;
     tracing::debug!("시간 레이블(초 포함): {}", time_label_with_seconds);
 
     // Ensure daily file exists
-    let (file_path, _) = ensure_daily_file(state, &kst_timestamp)?;
+    let (file_path, date_key) = ensure_daily_file(state, timestamp)?;
     tracing::debug!("파일 경로: {:?}", file_path);
-    tracing::debug!("작업 내용: {}", payload.task);
-    tracing::debug!("새 분 여부: {}", payload.is_new_minute);
+    tracing::debug!("작업 내용: {}", text);
 
     // Open file in append mode
     let mut file = OpenOptions::new()
@@ -86,19 +149,257 @@ pub fn append_markdown_entry(
         .map_err(|error| format!("파일 열기 실패: {error}, 경로: {:?}", file_path))?;
 
     // Append task entry
-    writeln!(file, "- {} ({})", payload.task, time_label_with_seconds)
+    let line = entry_line(text, &time_label_with_seconds);
+    writeln!(file, "{}", line)
         .map_err(|error| format!("작업 쓰기 실패: {error}"))?;
 
     // Flush file buffer
     file.flush()
         .map_err(|error| format!("파일 flush 실패: {error}"))?;
 
-    tracing::debug!("항목 추가 완료: {}", payload.task);
-    tracing::debug!("append_markdown_entry 성공");
+    tracing::debug!("항목 추가 완료: {}", text);
+    hook_service::fire(
+        HookEvent::EntryAppended,
+        &EntryHookData {
+            date: date_key,
+            file_path: file_path.to_string_lossy().into_owned(),
+            line,
+            text: text.to_string(),
+        },
+    );
+    Ok(file_path)
+}
+
+/// Loads today's dump (writing the date header into an empty file) with today's calendar events
+pub fn load_today(state: &HistoryState, calendar: &CalendarSettings) -> Result<TodayMarkdown, String> {
+    let now = current_local_time()?;
+    tracing::debug!("현재 시간: {:?}", now);
+    let (file_path, date_key) = ensure_daily_file(state, &now)?;
+    tracing::debug!("파일 경로: {:?}, date_key: {}", file_path, date_key);
+
+    let mut content = fs::read_to_string(&file_path).unwrap_or_default();
+    tracing::debug!("파일 내용 길이: {}", content.len());
+
+    if content.trim().is_empty() {
+        content = format!("# {}\n\n", format_date_label(&now));
+        fs::write(&file_path, &content)
+            .map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", file_path))?;
+        tracing::debug!("빈 파일에 헤더 작성 완료");
+    }
+
+    let calendar_events = match calendar_service::events_for_dates(std::slice::from_ref(&now), calendar) {
+        Ok(mut events) => events.remove(&date_key).unwrap_or_default(),
+        Err(e) => {
+            tracing::warn!("캘린더 일정 불러오기 실패: {}", e);
+            Vec::new()
+        }
+    };
+
+    Ok(TodayMarkdown {
+        date_key,
+        short_label: short_day_code(&now),
+        header_title: format_date_label(&now),
+        file_path: file_path.to_string_lossy().into_owned(),
+        content,
+        calendar_events,
+    })
+}
+
+/// Path of the retrospect file of a day (YYYYMMDD.retro.md)
+pub fn retrospect_path(state: &HistoryState, date_key: &str) -> PathBuf {
+    state.directory.join(format!("{}.retro.md", date_key))
+}
+
+/// Reads a day's retrospect, empty when none was written
+pub fn load_retrospect(state: &HistoryState, date_key: &str) -> Result<String, String> {
+    ensure_history_dir(&state.directory)?;
+    let file_path = retrospect_path(state, date_key);
+
+    if !file_path.exists() {
+        tracing::debug!("회고 파일이 없음: {:?}", file_path);
+        return Ok(String::new());
+    }
+
+    fs::read_to_string(&file_path).map_err(|error| {
+        format!("회고 파일 읽기 실패: {}, 경로: {:?}", error, file_path)
+    })
+}
+
+/// Writes a day's retrospect
+pub fn save_retrospect(state: &HistoryState, date_key: &str, content: &str) -> Result<PathBuf, String> {
+    ensure_history_dir(&state.directory)?;
+    let file_path = retrospect_path(state, date_key);
 
+    fs::write(&file_path, content).map_err(|error| {
+        format!("회고 파일 저장 실패: {}, 경로: {:?}", error, file_path)
+    })?;
+
+    tracing::debug!("회고 저장 완료: {:?}", file_path);
+    hook_service::fire(
+        HookEvent::RetroSaved,
+        &RetroHookData {
+            date: date_key.to_string(),
+            file_path: file_path.to_string_lossy().into_owned(),
+            content: content.to_string(),
+        },
+    );
     Ok(file_path)
 }
 
+/// Trailing "HH:MM:SS" of a top-level "- ... (HH:MM:SS)" entry
+pub fn entry_time_label(line: &str) -> Option<&str> {
+    let body = line.strip_prefix("- ")?.trim_end();
+    let inner = body.strip_suffix(')')?;
+    let start = inner.len().checked_sub(8)?;
+    let label = inner.get(start..)?;
+    let valid = label.bytes().enumerate().all(|(i, b)| {
+        if i == 2 || i == 5 {
+            b == b':'
+        } else {
+            b.is_ascii_digit()
+        }
+    });
+    (valid && inner[..start].ends_with('(')).then_some(label)
+}
+
+/// Insert `line` before the first top-level entry with a later timestamp
+///
+/// Returns `None` when the same line is already present.
+fn insert_line_chronologically(content: &str, line: &str, time_label: &str) -> Option<String> {
+    if content.lines().any(|existing| existing.trim_end() == line) {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    let position = lines
+        .iter()
+        .position(|existing| entry_time_label(existing).is_some_and(|label| label > time_label))
+        .unwrap_or(lines.len());
+    lines.insert(position, line);
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    Some(updated)
+}
+
+/// Inserts an entry at a given time of day, keeping entries in chronological order
+///
+/// Used for entries that happened earlier than "now" (e.g. calendar events). Returns
+/// `false` when an identical entry already exists.
+pub fn insert_entry_at_time(
+    state: &HistoryState,
+    date: &OffsetDateTime,
+    time_label: &str,
+    text: &str,
+) -> Result<bool, String> {
+    let (file_path, _) = ensure_daily_file(state, date)?;
+    let content = fs::read_to_string(&file_path)
+        .map_err(|error| format!("파일 읽기 실패: {error}, 경로: {:?}", file_path))?;
+
+    let line = entry_line(text, time_label);
+    let Some(updated) = insert_line_chronologically(&content, &line, time_label) else {
+        tracing::debug!("이미 존재하는 항목 건너뜀: {}", line);
+        return Ok(false);
+    };
+
+    fs::write(&file_path, updated)
+        .map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", file_path))?;
+    tracing::debug!("항목 삽입 완료: {}", line);
+    Ok(true)
+}
+
+/// Replaces the last line equal to `old_line`, or removes it when `replacement` is `None`
+///
+/// Returns `false` when the line is no longer in the file (e.g. edited elsewhere).
+pub fn replace_entry_line(file_path: &Path, old_line: &str, replacement: Option<&str>) -> Result<bool, String> {
+    let content = fs::read_to_string(file_path)
+        .map_err(|error| format!("파일 읽기 실패: {error}, 경로: {:?}", file_path))?;
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    let Some(position) = lines.iter().rposition(|line| line.trim_end() == old_line) else {
+        return Ok(false);
+    };
+    match replacement {
+        Some(line) => lines[position] = line,
+        None => {
+            lines.remove(position);
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    fs::write(file_path, updated)
+        .map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", file_path))?;
+    tracing::debug!("항목 수정 완료: {} -> {:?}", old_line, replacement);
+    Ok(true)
+}
+
+/// Case-insensitive search over every daily file, newest day first
+pub fn search_entries(state: &HistoryState, query: &str, limit: usize) -> Result<Vec<EntrySearchHit>, String> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut hits = Vec::new();
+    for file in collect_history(state)?.files {
+        let Ok(content) = fs::read_to_string(&file.path) else {
+            continue;
+        };
+        let mut day_hits: Vec<EntrySearchHit> = content
+            .lines()
+            .filter(|line| !line.starts_with("# ") && line.to_lowercase().contains(&needle))
+            .map(|line| EntrySearchHit {
+                date: file.date.clone(),
+                line: line.to_string(),
+            })
+            .collect();
+        // Latest entries of the day first, like the day order
+        day_hits.reverse();
+        hits.extend(day_hits);
+        if hits.len() >= limit {
+            hits.truncate(limit);
+            break;
+        }
+    }
+    Ok(hits)
+}
+
+static MENTION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_mention_regex() -> &'static Regex {
+    MENTION_REGEX.get_or_init(|| {
+        // "#태그" or "@사람", stopping at whitespace and punctuation
+        Regex::new(r#"(?:^|[\s(\[])([#@][^\s#@()\[\]{},.!?:;'"]+)"#).unwrap()
+    })
+}
+
+/// `#tags` and `@people` mentioned in a line (markdown headings are not tags)
+pub fn extract_mentions(text: &str) -> Vec<&str> {
+    get_mention_regex()
+        .captures_iter(text)
+        .filter_map(|captures| captures.get(1))
+        .map(|mention| mention.as_str())
+        .collect()
+}
+
+/// Every `#tag` and `@person` in the journal, most used first
+pub fn collect_mentions(state: &HistoryState) -> Result<Vec<String>, String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for file in collect_history(state)?.files {
+        let Ok(content) = fs::read_to_string(&file.path) else {
+            continue;
+        };
+        for mention in content.lines().flat_map(extract_mentions) {
+            *counts.entry(mention.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut mentions: Vec<(String, usize)> = counts.into_iter().collect();
+    mentions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    Ok(mentions.into_iter().map(|(mention, _)| mention).collect())
+}
+
 /// Collects all history files from the directory
 pub fn collect_history(state: &HistoryState) -> Result<HistoryOverview, String> {
     ensure_history_dir(&state.directory)?;
@@ -153,6 +454,37 @@ pub fn collect_history(state: &HistoryState) -> Result<HistoryOverview, String>
 /// Emits a history update event to the app
 pub fn emit_history_update(app: &AppHandle, state: &HistoryState) -> Result<(), String> {
     let overview = collect_history(state)?;
+    daemon_service::publish(daemon_service::HISTORY_UPDATED_EVENT, &overview);
     app.emit_all("history_updated", overview)
         .map_err(|error| error.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_entries_in_time_order_once() {
+        let content = "# 2024년 1월 2일 화요일\n\n- 출근 (09:00:00)\n- 코딩 (11:00:00)\n  - 세부 메모\n- 점심 (12:30:00)\n";
+        let line = "- 📅 회의 (10:00-11:00) (10:00:00)";
+
+        let updated = insert_line_chronologically(content, line, "10:00:00").unwrap();
+        assert_eq!(
+            updated,
+            "# 2024년 1월 2일 화요일\n\n- 출근 (09:00:00)\n- 📅 회의 (10:00-11:00) (10:00:00)\n- 코딩 (11:00:00)\n  - 세부 메모\n- 점심 (12:30:00)\n"
+        );
+        assert_eq!(insert_line_chronologically(&updated, line, "10:00:00"), None);
+
+        let late = insert_line_chronologically(content, "- 퇴근 (18:00:00)", "18:00:00").unwrap();
+        assert!(late.ends_with("- 점심 (12:30:00)\n- 퇴근 (18:00:00)\n"));
+    }
+
+    #[test]
+    fn extracts_tags_and_people() {
+        assert_eq!(
+            extract_mentions("- @민수와 API 리뷰 #dev, #회의 (10:00:00)"),
+            vec!["@민수와", "#dev", "#회의"]
+        );
+        assert!(extract_mentions("## 세션: 이슈#12 정리").is_empty());
+    }
+}
@@ -0,0 +1,388 @@
+// services/hook_service.rs
+// Event hooks: run user executables on journal events and keep a log of recent runs
+//
+// Hooks are configured in hooks.json next to the category rules. Each matching hook runs on
+// its own thread with the event payload as JSON on stdin, so writers are never blocked.
+
+use serde::Serialize;
+use std::cell::Cell;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use time::format_description::well_known::Rfc3339;
+
+use crate::models::hooks::{HookConfig, HookEvent, HookPayload, HookRun};
+use crate::utils::current_local_time;
+
+pub const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+const MAX_TIMEOUT_SECONDS: u64 = 300;
+/// Runs kept in the log
+const MAX_RUNS: usize = 100;
+/// Bytes of stderr kept per run
+const MAX_STDERR_BYTES: usize = 2000;
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Set for hook processes; events raised while it is set are not hooked again,
+/// so a hook that runs `hoego log` cannot trigger itself
+pub const HOOK_EVENT_ENV: &str = "HOEGO_HOOK_EVENT";
+
+thread_local! {
+    /// Set while the daemon handles a write requested from inside a hook
+    static SUPPRESSED: Cell<bool> = const { Cell::new(false) };
+}
+
+static PENDING: OnceLock<Mutex<Vec<JoinHandle<()>>>> = OnceLock::new();
+/// Serializes read-modify-write of the run log within this process
+static RUN_LOG_LOCK: Mutex<()> = Mutex::new(());
+
+fn pending() -> &'static Mutex<Vec<JoinHandle<()>>> {
+    PENDING.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Get the hooks file path
+pub fn get_hooks_path() -> Result<PathBuf, String> {
+    let config_dir = tauri::api::path::config_dir()
+        .ok_or_else(|| "설정 디렉토리를 찾을 수 없습니다".to_string())?;
+
+    let app_config_dir = config_dir.join("hoego");
+    fs::create_dir_all(&app_config_dir).map_err(|e| format!("설정 디렉토리 생성 실패: {}", e))?;
+
+    Ok(app_config_dir.join("hooks.json"))
+}
+
+/// Get the run log path
+pub fn get_runs_path() -> Result<PathBuf, String> {
+    let data_dir =
+        dirs::data_dir().ok_or_else(|| "데이터 디렉토리를 찾을 수 없습니다".to_string())?;
+    let app_data_dir = data_dir.join("hoego");
+    fs::create_dir_all(&app_data_dir).map_err(|e| format!("데이터 디렉토리 생성 실패: {}", e))?;
+    Ok(app_data_dir.join("hook_runs.json"))
+}
+
+/// Load hooks from file (none configured when the file is missing)
+pub fn load_hooks() -> Result<Vec<HookConfig>, String> {
+    let path = get_hooks_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("훅 설정 읽기 실패: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("훅 설정 파싱 실패: {}", e))
+}
+
+fn validate_hooks(hooks: &[HookConfig]) -> Result<(), String> {
+    for hook in hooks {
+        if hook.command.trim().is_empty() {
+            return Err(format!("훅 명령이 비어 있습니다: {}", display_name(hook)));
+        }
+        if let Some(timeout) = hook.timeout_seconds {
+            if !(1..=MAX_TIMEOUT_SECONDS).contains(&timeout) {
+                return Err(format!(
+                    "제한 시간은 1~{}초 사이여야 합니다: {}",
+                    MAX_TIMEOUT_SECONDS,
+                    display_name(hook)
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate and save hooks
+pub fn save_hooks(hooks: &[HookConfig]) -> Result<(), String> {
+    validate_hooks(hooks)?;
+    let path = get_hooks_path()?;
+    let content =
+        serde_json::to_string_pretty(hooks).map_err(|e| format!("훅 설정 직렬화 실패: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("훅 설정 저장 실패: {}", e))?;
+    tracing::info!("훅 설정 저장 완료: {:?}", path);
+    Ok(())
+}
+
+/// Recent runs, newest first
+pub fn load_runs(limit: usize) -> Result<Vec<HookRun>, String> {
+    let path = get_runs_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("훅 실행 기록 읽기 실패: {}", e))?;
+    let mut runs: Vec<HookRun> =
+        serde_json::from_str(&content).map_err(|e| format!("훅 실행 기록 파싱 실패: {}", e))?;
+    runs.truncate(limit);
+    Ok(runs)
+}
+
+fn record_run(run: HookRun) -> Result<(), String> {
+    let _guard = RUN_LOG_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut runs = load_runs(MAX_RUNS).unwrap_or_default();
+    runs.insert(0, run);
+    runs.truncate(MAX_RUNS);
+    let content = serde_json::to_string_pretty(&runs)
+        .map_err(|e| format!("훅 실행 기록 직렬화 실패: {}", e))?;
+    fs::write(get_runs_path()?, content).map_err(|e| format!("훅 실행 기록 저장 실패: {}", e))
+}
+
+fn display_name(hook: &HookConfig) -> String {
+    if hook.name.trim().is_empty() {
+        hook.command.clone()
+    } else {
+        hook.name.clone()
+    }
+}
+
+fn expand_home(command: &str) -> PathBuf {
+    match (command.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(command),
+    }
+}
+
+/// Keep the end of the output, on a char boundary
+fn tail(text: &str, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text.to_string();
+    }
+    let mut start = text.len() - max_bytes;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    text[start..].to_string()
+}
+
+/// Run one hook to completion (or until its timeout) and describe the run
+pub fn run_hook(hook: &HookConfig, payload: &str) -> HookRun {
+    let started = Instant::now();
+    let started_at = current_local_time()
+        .ok()
+        .and_then(|now| now.format(&Rfc3339).ok())
+        .unwrap_or_default();
+    let timeout = Duration::from_secs(hook.timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_SECONDS));
+
+    let mut run = HookRun {
+        name: display_name(hook),
+        event: hook.event,
+        command: hook.command.clone(),
+        started_at,
+        duration_ms: 0,
+        exit_code: None,
+        timed_out: false,
+        error: None,
+        stderr: String::new(),
+    };
+
+    let spawned = Command::new(expand_home(&hook.command))
+        .args(&hook.args)
+        .env(HOOK_EVENT_ENV, hook.event.as_str())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(error) => {
+            run.error = Some(format!("실행 실패: {}", error));
+            return run;
+        }
+    };
+
+    // 큰 페이로드를 읽지 않는 훅 때문에 막히지 않도록 stdin/stderr는 별도 스레드에서 처리
+    if let Some(mut stdin) = child.stdin.take() {
+        let payload = payload.to_string();
+        thread::spawn(move || {
+            let _ = stdin.write_all(payload.as_bytes());
+        });
+    }
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        thread::spawn(move || {
+            let mut output = Vec::new();
+            let _ = stderr.read_to_end(&mut output);
+            output
+        })
+    });
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                run.timed_out = true;
+                break None;
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(error) => {
+                run.error = Some(format!("대기 실패: {}", error));
+                break None;
+            }
+        }
+    };
+
+    run.exit_code = status.and_then(|status| status.code());
+    // 훅이 백그라운드로 띄운 프로세스가 stderr를 잡고 있을 수 있으므로 잠깐만 기다림
+    if let Some(reader) = stderr_reader {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while !reader.is_finished() && Instant::now() < deadline {
+            thread::sleep(POLL_INTERVAL);
+        }
+        if reader.is_finished() {
+            if let Ok(output) = reader.join() {
+                run.stderr = tail(
+                    String::from_utf8_lossy(&output).trim_end(),
+                    MAX_STDERR_BYTES,
+                );
+            }
+        }
+    }
+    run.duration_ms = started.elapsed().as_millis() as u64;
+    run
+}
+
+/// This process was started by a hook
+pub fn running_in_hook() -> bool {
+    std::env::var_os(HOOK_EVENT_ENV).is_some()
+}
+
+/// Events raised on this thread are not hooked (inside a hook or `without_hooks`)
+pub fn hooks_suppressed() -> bool {
+    running_in_hook() || SUPPRESSED.with(Cell::get)
+}
+
+/// Run `f` without firing hooks on this thread, for writes a hook asked another process to do
+pub fn without_hooks<R>(f: impl FnOnce() -> R) -> R {
+    let previous = SUPPRESSED.with(|suppressed| suppressed.replace(true));
+    let result = f();
+    SUPPRESSED.with(|suppressed| suppressed.set(previous));
+    result
+}
+
+/// Run the enabled hooks for `event` in the background
+///
+/// Failures are logged and recorded in the run log; they never fail the write that raised
+/// the event.
+pub fn fire<T: Serialize>(event: HookEvent, data: &T) {
+    if hooks_suppressed() {
+        tracing::debug!("훅 안에서 발생한 이벤트는 건너뜀: {}", event.as_str());
+        return;
+    }
+
+    let hooks: Vec<HookConfig> = match load_hooks() {
+        Ok(hooks) => hooks
+            .into_iter()
+            .filter(|hook| hook.enabled && hook.event == event)
+            .collect(),
+        Err(error) => {
+            tracing::warn!("훅 설정 불러오기 실패: {}", error);
+            return;
+        }
+    };
+    if hooks.is_empty() {
+        return;
+    }
+
+    let payload = HookPayload {
+        event,
+        timestamp: current_local_time()
+            .ok()
+            .and_then(|now| now.format(&Rfc3339).ok())
+            .unwrap_or_default(),
+        data,
+    };
+    let payload = match serde_json::to_string(&payload) {
+        Ok(payload) => payload,
+        Err(error) => {
+            tracing::warn!("훅 페이로드 직렬화 실패: {}", error);
+            return;
+        }
+    };
+
+    let mut pending = pending().lock().unwrap_or_else(|e| e.into_inner());
+    pending.retain(|handle| !handle.is_finished());
+    for hook in hooks {
+        let payload = payload.clone();
+        pending.push(thread::spawn(move || {
+            let run = run_hook(&hook, &payload);
+            if run.succeeded() {
+                tracing::debug!("훅 실행 완료: {} ({}ms)", run.name, run.duration_ms);
+            } else {
+                tracing::warn!(
+                    "훅 실패: {} (종료 코드 {:?}, 시간 초과 {})",
+                    run.name,
+                    run.exit_code,
+                    run.timed_out
+                );
+            }
+            if let Err(error) = record_run(run) {
+                tracing::warn!("{}", error);
+            }
+        }));
+    }
+}
+
+/// Wait for hooks still running (each is bounded by its timeout); used before the CLI exits
+pub fn wait_pending() {
+    let handles: Vec<JoinHandle<()>> = pending()
+        .lock()
+        .map(|mut pending| pending.drain(..).collect())
+        .unwrap_or_default();
+    for handle in handles {
+        let _ = handle.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(command: &str, args: &[&str], timeout_seconds: Option<u64>) -> HookConfig {
+        HookConfig {
+            name: String::new(),
+            event: HookEvent::EntryAppended,
+            command: command.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            timeout_seconds,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn runs_hook_with_payload_on_stdin() {
+        let run = run_hook(
+            &hook(
+                "/bin/sh",
+                &[
+                    "-c",
+                    "read line; echo \"$HOEGO_HOOK_EVENT $line\" >&2; exit 3",
+                ],
+                None,
+            ),
+            "{\"text\":\"hi\"}\n",
+        );
+        assert_eq!(run.exit_code, Some(3));
+        assert!(!run.succeeded());
+        assert_eq!(run.stderr, "entry_appended {\"text\":\"hi\"}");
+        assert_eq!(run.name, "/bin/sh");
+    }
+
+    #[test]
+    fn kills_hooks_that_time_out() {
+        let run = run_hook(&hook("/bin/sh", &["-c", "sleep 5"], Some(1)), "");
+        assert!(run.timed_out);
+        assert_eq!(run.exit_code, None);
+        assert!(run.duration_ms < 4000);
+
+        let run = run_hook(&hook("/nonexistent/hook", &[], None), "");
+        assert!(run.error.is_some());
+    }
+
+    #[test]
+    fn validates_and_tails() {
+        assert!(validate_hooks(&[hook(" ", &[], None)]).is_err());
+        assert!(validate_hooks(&[hook("/bin/true", &[], Some(0))]).is_err());
+        assert!(validate_hooks(&[hook("/bin/true", &[], Some(30))]).is_ok());
+        assert_eq!(tail("가나다", 4), "다");
+    }
+}
@@ -0,0 +1,149 @@
+// LLM-assisted categorization for entries that no category rule matched
+use super::{prompts::ChatMessage, LLMManager};
+
+/// Entries sent to the model per request
+pub const BATCH_SIZE: usize = 20;
+
+const SYSTEM_PROMPT: &str = "당신은 하루 일지 항목을 카테고리로 분류하는 도우미입니다. \
+각 항목에 대해 주어진 카테고리 목록 중 가장 알맞은 하나를 고르세요. \
+확실하지 않으면 \"기타\"를 고르세요. JSON으로만 답하세요.";
+
+/// JSON schema restricting the answer to one known category per entry
+pub fn classification_schema(categories: &[String], count: usize) -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "results": {
+                "type": "array",
+                "minItems": count,
+                "maxItems": count,
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "index": { "type": "integer" },
+                        "category": { "type": "string", "enum": categories }
+                    },
+                    "required": ["index", "category"],
+                    "additionalProperties": false
+                }
+            }
+        },
+        "required": ["results"],
+        "additionalProperties": false
+    })
+}
+
+fn build_messages(batch: &[String], categories: &[String]) -> Vec<ChatMessage> {
+    let mut user = format!("카테고리: {}\n\n항목:\n", categories.join(", "));
+    for (index, text) in batch.iter().enumerate() {
+        user.push_str(&format!("{}. {}\n", index, text));
+    }
+
+    vec![
+        ChatMessage {
+            role: "system".to_string(),
+            content: SYSTEM_PROMPT.to_string(),
+        },
+        ChatMessage {
+            role: "user".to_string(),
+            content: user,
+        },
+    ]
+}
+
+/// Map the model answer back to (entry text, category)
+///
+/// Unknown indices and categories outside the list are dropped.
+pub fn parse_classifications(
+    response: &str,
+    batch: &[String],
+    categories: &[String],
+) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+    // Some models wrap JSON in a code fence despite the grammar
+    let json = response
+        .trim()
+        .trim_start_matches("```json")
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim();
+
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    let results = value
+        .get("results")
+        .and_then(|r| r.as_array())
+        .ok_or("Missing results array")?;
+
+    let mut classified = Vec::new();
+    for item in results {
+        let index = item.get("index").and_then(|i| i.as_u64());
+        let category = item.get("category").and_then(|c| c.as_str());
+        if let (Some(index), Some(category)) = (index, category) {
+            let Some(text) = batch.get(index as usize) else {
+                continue;
+            };
+            if categories.iter().any(|c| c == category)
+                && !classified.iter().any(|(t, _): &(String, String)| t == text)
+            {
+                classified.push((text.clone(), category.to_string()));
+            }
+        }
+    }
+
+    Ok(classified)
+}
+
+/// Classify entries in batches with the local backend (selected endpoint or loaded model)
+///
+/// The engine is locked per batch so feedback and summaries can run in between.
+/// A failing batch is logged and skipped so one bad answer does not lose the rest.
+pub async fn classify_entries(
+    llm: &LLMManager,
+    entries: &[String],
+    categories: &[String],
+) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+    if llm.local_model_name().await.is_none() {
+        return Err("No model loaded".into());
+    }
+
+    let mut classified = Vec::new();
+    for batch in entries.chunks(BATCH_SIZE) {
+        let schema = classification_schema(categories, batch.len());
+        let response = llm
+            .chat_complete_json(build_messages(batch, categories), schema, Some(64 * batch.len()))
+            .await;
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                eprintln!("[Categorize] Batch failed: {}", e);
+                continue;
+            }
+        };
+
+        match parse_classifications(&response, batch, categories) {
+            Ok(results) => {
+                eprintln!("[Categorize] Classified {}/{} entries", results.len(), batch.len());
+                classified.extend(results);
+            }
+            Err(e) => eprintln!("[Categorize] Failed to parse response: {} ({})", e, response),
+        }
+    }
+
+    Ok(classified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_results_and_drops_unknown_values() {
+        let batch = vec!["선물 포장".to_string(), "은행 업무".to_string()];
+        let categories = vec!["약속".to_string(), "기타".to_string()];
+        let response = r#"```json
+{"results":[{"index":0,"category":"약속"},{"index":1,"category":"쇼핑"},{"index":7,"category":"기타"}]}
+```"#;
+
+        let results = parse_classifications(response, &batch, &categories).unwrap();
+        assert_eq!(results, vec![("선물 포장".to_string(), "약속".to_string())]);
+    }
+}
@@ -26,6 +26,19 @@ impl CloudLLMState {
             current_provider: Arc::new(RwLock::new(None)),
         }
     }
+
+    /// 저장된 API 키 (환경변수 또는 키 저장소)로 provider를 만듭니다 (앱 밖에서 사용할 때)
+    pub fn from_saved_key(provider_name: &str) -> Result<Self, String> {
+        let api_key = SecureKeyStore::retrieve_api_key(provider_name).map_err(|e| e.to_string())?;
+        let provider: Box<dyn CloudLLMProvider> = match provider_name {
+            "openai" => Box::new(OpenAIProvider::new(api_key)),
+            _ => return Err(format!("Unsupported provider: {}", provider_name)),
+        };
+
+        Ok(Self {
+            current_provider: Arc::new(RwLock::new(Some(provider))),
+        })
+    }
 }
 
 /// API 키 설정 및 검증
@@ -0,0 +1,322 @@
+// 외부 OpenAI 호환 로컬 서버 (Ollama, LM Studio 등)를 번들 llama-server 대신 사용
+// 서버는 사용자가 직접 띄우고, 여기서는 /v1/models와 /v1/chat/completions만 호출
+
+use std::time::Duration;
+
+use super::prompts::ChatMessage;
+use super::LlamaCppEngine;
+
+/// Same default as the bundled engine profiles
+const DEFAULT_TEMPERATURE: f32 = 0.3;
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(5);
+const CHAT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// An OpenAI-compatible server and the model to use on it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalEndpoint {
+    base_url: String,
+    model: String,
+}
+
+impl LocalEndpoint {
+    pub fn new(base_url: &str, model: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        if model.trim().is_empty() {
+            return Err("Endpoint model name is empty".into());
+        }
+        Ok(Self {
+            base_url: normalize_base_url(base_url)?,
+            model: model.trim().to_string(),
+        })
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Server answers `/v1/models` and serves the selected model
+    pub async fn health(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let models = list_models(&self.base_url).await?;
+        if !models.iter().any(|id| serves_model(id, &self.model)) {
+            return Err(format!(
+                "Model {} is not available on {} (found: {})",
+                self.model,
+                self.base_url,
+                models.join(", ")
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    fn request_body(
+        &self,
+        messages: Vec<ChatMessage>,
+        max_tokens: Option<usize>,
+        temperature: Option<f32>,
+        stream: bool,
+    ) -> serde_json::Value {
+        let msgs: Vec<serde_json::Value> = messages
+            .into_iter()
+            .map(|m| serde_json::json!({ "role": m.role, "content": m.content }))
+            .collect();
+
+        // max_tokens가 없으면 서버 기본값(모델 상한)에 맡김
+        let mut body = serde_json::json!({
+            "model": self.model,
+            "messages": msgs,
+            "temperature": temperature.unwrap_or(DEFAULT_TEMPERATURE),
+            "stream": stream,
+        });
+        if let Some(max_tokens) = max_tokens {
+            body["max_tokens"] = max_tokens.into();
+        }
+        body
+    }
+
+    pub async fn chat_complete(
+        &self,
+        messages: Vec<ChatMessage>,
+        max_tokens: Option<usize>,
+        temperature: Option<f32>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let body = self.request_body(messages, max_tokens, temperature, false);
+        self.send_chat_request(body).await
+    }
+
+    /// Like `LlamaCppEngine::chat_complete_json`: the answer is constrained to `schema`
+    pub async fn chat_complete_json(
+        &self,
+        messages: Vec<ChatMessage>,
+        schema: serde_json::Value,
+        max_tokens: Option<usize>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut body = self.request_body(messages, max_tokens, Some(0.0), false);
+        body["response_format"] = serde_json::json!({
+            "type": "json_schema",
+            "json_schema": { "name": "response", "strict": true, "schema": schema }
+        });
+        self.send_chat_request(body).await
+    }
+
+    async fn send_chat_request(
+        &self,
+        body: serde_json::Value,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let url = format!("{}/v1/chat/completions", self.base_url);
+        eprintln!(
+            "LLM Endpoint - Sending chat request to {} ({})",
+            url, self.model
+        );
+
+        let response = reqwest::Client::new()
+            .post(url)
+            .json(&body)
+            .timeout(CHAT_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to reach local endpoint: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            eprintln!("LLM Endpoint - Server returned {}: {}", status, text);
+            return Err(format!("Local endpoint returned {}: {}", status, text).into());
+        }
+
+        let result: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse chat response: {}", e))?;
+
+        let content = result
+            .get("choices")
+            .and_then(|c| c.get(0))
+            .and_then(|c| c.get("message"))
+            .and_then(|m| m.get("content"))
+            .and_then(|s| s.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        if content.trim().is_empty() {
+            eprintln!("LLM Endpoint - Empty chat response: {:?}", result);
+            return Err("Empty response from model".into());
+        }
+        Ok(content)
+    }
+
+    /// SSE streaming; stops reading (and drops the connection) once `should_stop` returns true
+    pub async fn chat_complete_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        max_tokens: Option<usize>,
+        temperature: Option<f32>,
+        should_stop: impl Fn() -> bool + Send,
+        mut on_delta: impl FnMut(&str) + Send,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        use futures::StreamExt;
+
+        let response = reqwest::Client::new()
+            .post(format!("{}/v1/chat/completions", self.base_url))
+            .json(&self.request_body(messages, max_tokens, temperature, true))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to reach local endpoint: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(format!("Local endpoint returned {} (stream): {}", status, text).into());
+        }
+
+        let mut stream = response.bytes_stream();
+        // 청크 경계에서 멀티바이트 문자가 잘릴 수 있으므로 바이트로 모아 완성된 줄만 디코딩
+        let mut buf: Vec<u8> = Vec::new();
+        let mut acc = String::new();
+
+        while let Some(chunk) = stream.next().await {
+            if should_stop() {
+                eprintln!("LLM Endpoint - Stream stopped by caller");
+                return Ok(acc);
+            }
+            let bytes = chunk.map_err(|e| format!("Stream error: {}", e))?;
+            buf.extend_from_slice(&bytes);
+
+            while let Some(line) = take_line(&mut buf) {
+                let Some(data) = line.trim().strip_prefix("data:").map(str::trim) else {
+                    continue;
+                };
+                if data == "[DONE]" {
+                    return Ok(acc);
+                }
+                if let Some(delta) = LlamaCppEngine::extract_sse_delta_text(data) {
+                    if !delta.is_empty() {
+                        acc.push_str(&delta);
+                        on_delta(&delta);
+                    }
+                }
+            }
+        }
+
+        Ok(acc)
+    }
+}
+
+/// Remove and decode the first complete line of `buf`; `None` until a `\n` arrives
+fn take_line(buf: &mut Vec<u8>) -> Option<String> {
+    let pos = buf.iter().position(|&b| b == b'\n')?;
+    let line: Vec<u8> = buf.drain(..=pos).collect();
+    Some(String::from_utf8_lossy(&line).into_owned())
+}
+
+/// `http://localhost:11434/v1/` -> `http://localhost:11434`
+fn normalize_base_url(base_url: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let url = base_url.trim().trim_end_matches('/');
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err(format!(
+            "Endpoint URL must start with http:// or https://: {}",
+            base_url
+        )
+        .into());
+    }
+    let url = url.strip_suffix("/v1").unwrap_or(url).trim_end_matches('/');
+    if url.ends_with(':') || url.ends_with("//") {
+        return Err(format!("Endpoint URL has no host: {}", base_url).into());
+    }
+    Ok(url.to_string())
+}
+
+/// Ollama lists `llama3.2:latest` for a model pulled as `llama3.2`
+fn serves_model(id: &str, model: &str) -> bool {
+    id == model || id.strip_suffix(":latest") == Some(model)
+}
+
+fn parse_model_ids(body: &serde_json::Value) -> Vec<String> {
+    body.get("data")
+        .and_then(|d| d.as_array())
+        .map(|models| {
+            models
+                .iter()
+                .filter_map(|m| m.get("id").and_then(|id| id.as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Model ids from `GET /v1/models`; doubles as the health check
+pub async fn list_models(base_url: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let url = format!("{}/v1/models", normalize_base_url(base_url)?);
+    let response = reqwest::Client::new()
+        .get(&url)
+        .timeout(HEALTH_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| format!("Could not connect to {}: {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("{} returned {}", url, response.status()).into());
+    }
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("{} did not return a model list: {}", url, e))?;
+    Ok(parse_model_ids(&body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_url_is_normalized_and_validated() {
+        assert_eq!(
+            normalize_base_url(" http://localhost:11434/v1/ ").unwrap(),
+            "http://localhost:11434"
+        );
+        assert_eq!(
+            normalize_base_url("http://127.0.0.1:1234").unwrap(),
+            "http://127.0.0.1:1234"
+        );
+        assert!(normalize_base_url("localhost:11434").is_err());
+        assert!(normalize_base_url("http://").is_err());
+        assert!(LocalEndpoint::new("http://localhost:1234", " ").is_err());
+    }
+
+    #[test]
+    fn stream_lines_keep_characters_split_across_chunks() {
+        let data = "data: {\"text\":\"회고\"}\n".as_bytes();
+        // "회" 중간에서 청크가 나뉜 경우
+        let split = data.iter().position(|&b| b >= 0x80).unwrap() + 1;
+
+        let mut buf = data[..split].to_vec();
+        assert_eq!(take_line(&mut buf), None);
+        buf.extend_from_slice(&data[split..]);
+        assert_eq!(
+            take_line(&mut buf).as_deref(),
+            Some("data: {\"text\":\"회고\"}\n")
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn model_list_is_parsed_and_matched() {
+        let body = serde_json::json!({
+            "object": "list",
+            "data": [
+                { "id": "llama3.2:latest", "object": "model" },
+                { "id": "qwen2.5:7b", "object": "model" },
+                { "object": "model" }
+            ]
+        });
+        let ids = parse_model_ids(&body);
+        assert_eq!(ids, vec!["llama3.2:latest", "qwen2.5:7b"]);
+        assert!(serves_model(&ids[0], "llama3.2"));
+        assert!(serves_model(&ids[1], "qwen2.5:7b"));
+        assert!(!serves_model(&ids[1], "qwen2.5"));
+        assert!(parse_model_ids(&serde_json::json!({ "error": "nope" })).is_empty());
+    }
+}
@@ -1,14 +1,22 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
+use std::process::Command;
+use std::ffi::OsString;
 use std::path::Path;
+use std::sync::Arc;
+
+use super::supervisor::{ServerMonitor, ServerSpec, Supervisor};
 
 #[derive(Debug)]
 pub struct LlamaCppEngine {
     binary_path: Option<PathBuf>,
     model_path: Option<PathBuf>,
-    process: Option<Child>,
+    /// Owns the llama-server process while it runs
+    supervisor: Option<Supervisor>,
+    monitor: Arc<ServerMonitor>,
+    /// Port of the server started by this engine
+    port: Option<u16>,
     config: super::LLMConfig,
 }
 
@@ -33,12 +41,18 @@ impl LlamaCppEngine {
         let mut s = Self {
             binary_path: None,
             model_path: None,
-            process: None,
+            supervisor: None,
+            monitor: Arc::new(ServerMonitor::default()),
+            port: None,
             config: super::LLMConfig::default(),
         };
         // Auto-detect reasonable CPU threads
         let threads = num_cpus::get_physical().max(1);
         s.config.cpu_threads = threads;
+        // 설정된 포트가 없으면 시작할 때마다 빈 포트를 고름
+        s.config.port = crate::services::storage_service::load_settings()
+            .ok()
+            .and_then(|settings| settings.llm_server_port);
         Ok(s)
     }
 
@@ -108,6 +122,14 @@ impl LlamaCppEngine {
         Ok(cache_dir.join("prompt-cache.bin"))
     }
 
+    /// PID file of the server spawned by the process `owner_pid`
+    fn get_pid_path(owner_pid: u32) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let data_dir = dirs::data_dir().ok_or("Could not determine data directory")?;
+        let llm_dir = data_dir.join("hoego").join("llm");
+        std::fs::create_dir_all(&llm_dir)?;
+        Ok(llm_dir.join(format!("llama-server.{}.pid", owner_pid)))
+    }
+
     fn binary_supports_prompt_cache(binary_path: &Path) -> bool {
         match Command::new(binary_path).arg("-h").output() {
             Ok(out) => {
@@ -181,42 +203,21 @@ impl LlamaCppEngine {
             return Err(format!("Model file does not exist: {:?}", model_path).into());
         }
 
-        // Adjust configuration based on model name
-        let model_name = model_path
-            .file_name()
+        // Model id is the file name without ".gguf"; its profile decides the runtime settings
+        let model_id = model_path
+            .file_stem()
             .and_then(|n| n.to_str())
-            .unwrap_or("");
-
-        // Update config based on model type
-        if model_name.contains("qwen3") || model_name.contains("Qwen3") || model_name.contains("Qwen_Qwen3") {
-            // Qwen3 models support longer context and better reasoning
-            self.config.max_context = 12288; // 충분한 여유, 과도한 ctx로 인한 속도 저하 방지
-            self.config.max_tokens = 4096; // 응답 길이 최대로 허용
-            self.config.gpu_layers = 999; // 가능한 전층 GPU 오프로딩
-            eprintln!("LLM Config - Using Qwen3 configuration");
-        } else if model_name.contains("qwen2.5-7b") {
-            self.config.max_context = 12288;
-            self.config.max_tokens = 4096;
-            self.config.gpu_layers = 999;
-            eprintln!("LLM Config - Using Qwen2.5-7B configuration");
-        } else if model_name.contains("qwen2.5-3b") {
-            self.config.max_context = 8192;
-            self.config.max_tokens = 2048;
-            self.config.gpu_layers = 999;
-            eprintln!("LLM Config - Using Qwen2.5-3B configuration");
-        } else if model_name.contains("phi") {
-            self.config.max_context = 16384;
-            self.config.max_tokens = 2048;
-            eprintln!("LLM Config - Using Phi configuration");
-        } else {
-            // Default for smaller models
-            self.config.max_context = 8192;
-            self.config.max_tokens = 2048;
-            eprintln!("LLM Config - Using default configuration");
-        }
+            .unwrap_or("")
+            .to_string();
+        let profile = super::profiles::get_profile(&model_id, &model_path);
+        eprintln!(
+            "LLM Config - Using {} profile",
+            if profile.customized { "saved" } else { "preset" }
+        );
+        self.apply_profile(&profile);
 
         eprintln!("LLM Config - Model: {}, Context: {}, Max Tokens: {}",
-                 model_name, self.config.max_context, self.config.max_tokens);
+                 model_id, self.config.max_context, self.config.max_tokens);
 
         self.model_path = Some(model_path);
 
@@ -254,19 +255,14 @@ impl LlamaCppEngine {
         ).into())
     }
 
+    /// True while the server process is up (starting, loading or ready)
     pub fn is_running(&self) -> bool {
-        if let Some(ref process) = self.process {
-            // Cast away mutability for try_wait - this is a read-only check
-            let process_ptr = process as *const Child as *mut Child;
-            unsafe {
-                match (*process_ptr).try_wait() {
-                    Ok(None) => true, // Process is still running
-                    _ => false,       // Process has exited or error occurred
-                }
-            }
-        } else {
-            false
-        }
+        self.supervisor.is_some() && self.monitor.state().status.is_alive()
+    }
+
+    /// Shared status and output buffer, usable without holding the engine lock
+    pub fn monitor(&self) -> Arc<ServerMonitor> {
+        self.monitor.clone()
     }
 
     pub async fn wait_for_ready(&self) -> Result<bool, Box<dyn std::error::Error>> {
@@ -278,9 +274,10 @@ impl LlamaCppEngine {
             return Ok(false);
         }
 
+        let url = self.endpoint("/health")?;
         let client = reqwest::Client::new();
         let response = client
-            .get("http://127.0.0.1:8080/health")
+            .get(url)
             .timeout(std::time::Duration::from_secs(2))
             .send()
             .await;
@@ -316,135 +313,183 @@ impl LlamaCppEngine {
         eprintln!("LLM Starting - Model file size: {} bytes ({:.2} GB)",
             model_size, model_size as f64 / (1024.0 * 1024.0 * 1024.0));
 
-        // Check if port 8080 is already in use
-        if Self::is_port_in_use(8080) {
-            eprintln!("LLM Warning - Port 8080 is already in use, attempting to kill existing process");
-            // Try to stop any existing llama-server process
-            #[cfg(unix)]
-            {
-                let _ = Command::new("pkill")
-                    .arg("-f")
-                    .arg("llama-server")
-                    .output();
-                std::thread::sleep(std::time::Duration::from_millis(500));
+        // Only servers we spawned are ever stopped; other llama-server processes are left alone
+        Self::stop_orphaned_servers(binary_path);
+
+        let port = match self.config.port {
+            Some(port) if Self::is_port_in_use(port) => {
+                return Err(format!(
+                    "Port {} is already in use; choose another llmServerPort or leave it unset",
+                    port
+                )
+                .into());
             }
-        }
+            Some(port) => port,
+            None => Self::pick_free_port()?,
+        };
+        eprintln!("LLM Starting - Port: {}", port);
 
         eprintln!("LLM Starting - Binary: {:?}", binary_path);
         eprintln!("LLM Starting - Model: {:?}", model_path);
 
-        let mut cmd = Command::new(binary_path);
-        cmd.arg("-m")
-            .arg(model_path)
-            .arg("-c")
-            .arg(self.config.max_context.to_string())
-            .arg("-n")
-            .arg(self.config.max_tokens.to_string())
-            .arg("-t")
-            .arg(self.config.cpu_threads.to_string())
-            .arg("--temp")
-            .arg(self.config.temperature.to_string())
-            .arg("--port")
-            .arg("8080")
-            .arg("--host")
-            .arg("127.0.0.1");
-
-        // Add special handling for Qwen models
-        let model_name = model_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("");
-
-        // For Qwen models, we need to use jinja template
-        if model_name.contains("qwen") || model_name.contains("Qwen") {
-            // Add jinja flag for Qwen models (required for Qwen3)
-            cmd.arg("--jinja");
-            eprintln!("LLM Starting - Qwen model detected, enabling jinja template");
+        let mut args: Vec<OsString> = vec![
+            "-m".into(),
+            model_path.into(),
+            "-c".into(),
+            self.config.max_context.to_string().into(),
+            "-n".into(),
+            self.config.max_tokens.to_string().into(),
+            "-t".into(),
+            self.config.cpu_threads.to_string().into(),
+            "--temp".into(),
+            self.config.temperature.to_string().into(),
+            "--port".into(),
+            port.to_string().into(),
+            "--host".into(),
+            "127.0.0.1".into(),
+        ];
+
+        // Chat template from the model profile (Qwen3 needs --jinja)
+        if self.config.jinja {
+            args.push("--jinja".into());
+        }
+        if let Some(template) = &self.config.chat_template {
+            args.push("--chat-template".into());
+            args.push(template.into());
         }
-
-        cmd.stdin(Stdio::null())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
 
         if self.config.gpu_layers > 0 {
-            cmd.arg("-ngl").arg(self.config.gpu_layers.to_string());
+            args.push("-ngl".into());
+            args.push(self.config.gpu_layers.to_string().into());
         }
         // Enable prompt cache only if this llama-server supports the flag
         if Self::binary_supports_prompt_cache(binary_path) {
             if let Ok(cache_path) = Self::get_cache_path() {
-                cmd.arg("--prompt-cache").arg(cache_path);
-                cmd.arg("--prompt-cache-all");
+                args.push("--prompt-cache".into());
+                args.push(cache_path.into());
+                args.push("--prompt-cache-all".into());
             }
         } else {
             eprintln!("LLM Info - prompt cache flags not supported by this llama-server; skipping");
         }
+        args.extend(self.config.extra_args.iter().map(OsString::from));
 
         eprintln!("LLM Starting - Spawning process...");
-        eprintln!("LLM Starting - Command: {:?}", cmd);
-
-        let mut child = cmd.spawn().map_err(|e| {
-            eprintln!("LLM Error - Failed to spawn process: {}", e);
-            eprintln!("LLM Error - Command was: {:?}", binary_path);
-            eprintln!("LLM Error - Model was: {:?}", model_path);
+        eprintln!("LLM Starting - Args: {:?}", args);
+
+        // The supervisor drains stdout/stderr and restarts the server if it crashes later
+        let spec = ServerSpec {
+            binary_path: binary_path.clone(),
+            args,
+            port,
+            pid_path: Self::get_pid_path(std::process::id()).ok(),
+        };
+        let supervisor = Supervisor::start(spec, self.monitor.clone()).map_err(|e| {
+            eprintln!("LLM Error - {}", e);
             e
         })?;
 
-        eprintln!("LLM Starting - Process spawned, waiting for initialization...");
+        eprintln!("LLM Starting - Process is running, server will initialize in background");
+        self.supervisor = Some(supervisor);
+        self.port = Some(port);
 
-        // Give it more time to initialize (especially for large models)
-        std::thread::sleep(std::time::Duration::from_millis(500));
+        Ok(())
+    }
 
-        // Check if process crashed immediately and capture output
-        if let Ok(Some(status)) = child.try_wait() {
-            eprintln!("LLM Error - Process exited immediately with status: {:?}", status);
+    fn is_port_in_use(port: u16) -> bool {
+        use std::net::TcpListener;
+        TcpListener::bind(("127.0.0.1", port)).is_err()
+    }
 
-            // Try to read stderr/stdout for more details
-            use std::io::Read;
-            let mut stderr_output = String::new();
-            let mut stdout_output = String::new();
+    /// Let the OS choose a free port (the listener is closed right before the server binds it)
+    fn pick_free_port() -> Result<u16, Box<dyn std::error::Error>> {
+        use std::net::TcpListener;
+        let listener = TcpListener::bind(("127.0.0.1", 0))?;
+        Ok(listener.local_addr()?.port())
+    }
 
-            if let Some(mut stderr) = child.stderr.take() {
-                let _ = stderr.read_to_string(&mut stderr_output);
-            }
-            if let Some(mut stdout) = child.stdout.take() {
-                let _ = stdout.read_to_string(&mut stdout_output);
-            }
+    /// Command line of a running process, `None` when it is not running
+    fn process_command(pid: u32) -> Option<String> {
+        let output = Command::new("ps")
+            .args(["-p", &pid.to_string(), "-o", "command="])
+            .output()
+            .ok()?;
+        let command = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (output.status.success() && !command.is_empty()).then_some(command)
+    }
 
-            if !stderr_output.is_empty() {
-                eprintln!("LLM Error - stderr: {}", stderr_output);
-            }
-            if !stdout_output.is_empty() {
-                eprintln!("LLM Error - stdout: {}", stdout_output);
+    /// Stop servers whose owning app or CLI process died without stopping them
+    ///
+    /// Each owner records its server's PID in `llama-server.<owner pid>.pid`. A server is only
+    /// killed when its owner is gone and the PID still runs our bundled binary.
+    fn stop_orphaned_servers(binary_path: &Path) {
+        let Some(llm_dir) = Self::get_pid_path(std::process::id())
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+        else {
+            return;
+        };
+        let Ok(entries) = std::fs::read_dir(&llm_dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let Some(owner_pid) = file_name
+                .strip_prefix("llama-server.")
+                .and_then(|rest| rest.strip_suffix(".pid"))
+                .and_then(|pid| pid.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            if owner_pid == std::process::id() || Self::process_command(owner_pid).is_some() {
+                continue;
             }
 
-            return Err(format!(
-                "llama.cpp process crashed on startup: {:?}\nstderr: {}\nstdout: {}",
-                status, stderr_output, stdout_output
-            ).into());
+            let server_pid = std::fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|pid| pid.trim().parse::<u32>().ok());
+            if let Some(server_pid) = server_pid {
+                let owned = Self::process_command(server_pid)
+                    .is_some_and(|command| command.contains(&*binary_path.to_string_lossy()));
+                if owned {
+                    eprintln!("LLM Starting - Stopping orphaned llama-server (pid {})", server_pid);
+                    let _ = Command::new("kill").arg(server_pid.to_string()).output();
+                    std::thread::sleep(std::time::Duration::from_millis(500));
+                }
+            }
+            let _ = std::fs::remove_file(entry.path());
         }
+    }
 
-        self.process = Some(child);
-        eprintln!("LLM Starting - Process is running, server will initialize in background");
-
+    /// Stop the server this engine spawned (never any other process)
+    pub fn stop_process(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.port = None;
+        if let Some(supervisor) = self.supervisor.take() {
+            // The supervisor kills its child, waits for it and removes the pid file
+            supervisor.stop();
+        }
         Ok(())
     }
 
-    fn is_port_in_use(port: u16) -> bool {
-        use std::net::TcpListener;
-        TcpListener::bind(("127.0.0.1", port)).is_err()
+    /// Port of the running server
+    pub fn port(&self) -> Option<u16> {
+        self.port
     }
 
-    pub fn stop_process(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if let Some(mut process) = self.process.take() {
-            process.kill()?;
-            process.wait()?;
-        }
-        Ok(())
+    /// Base URL of the running server, e.g. "http://127.0.0.1:51234"
+    pub fn base_url(&self) -> Option<String> {
+        self.port.map(|port| format!("http://127.0.0.1:{}", port))
+    }
+
+    fn endpoint(&self, path: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let base_url = self.base_url().ok_or("No model loaded")?;
+        Ok(format!("{}{}", base_url, path))
     }
 
     #[allow(dead_code)]
     pub async fn complete(&mut self, prompt: String) -> Result<String, Box<dyn std::error::Error>> {
-        if self.process.is_none() {
+        if self.supervisor.is_none() {
             return Err("No model loaded".into());
         }
 
@@ -463,10 +508,11 @@ impl LlamaCppEngine {
             "stream": false
         });
 
-        eprintln!("LLM Request - Sending to http://127.0.0.1:8080/completion");
+        let url = self.endpoint("/completion")?;
+        eprintln!("LLM Request - Sending to {}", url);
 
         let response = client
-            .post("http://127.0.0.1:8080/completion")
+            .post(url)
             .json(&request_body)
             .timeout(std::time::Duration::from_secs(600))  // 10분: 큰 모델과 긴 응답을 위한 충분한 시간
             .send()
@@ -512,7 +558,32 @@ impl LlamaCppEngine {
         max_tokens: Option<usize>,
         temperature: Option<f32>,
     ) -> Result<String, Box<dyn std::error::Error>> {
-        if self.process.is_none() {
+        self.send_chat_request(messages, max_tokens, temperature, None).await
+    }
+
+    // Chat completion constrained to a JSON schema (llama.cpp converts it to a grammar)
+    pub async fn chat_complete_json(
+        &mut self,
+        messages: Vec<super::prompts::ChatMessage>,
+        schema: serde_json::Value,
+        max_tokens: Option<usize>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let response_format = serde_json::json!({
+            "type": "json_schema",
+            "json_schema": { "name": "response", "strict": true, "schema": schema }
+        });
+        self.send_chat_request(messages, max_tokens, Some(0.0), Some(response_format))
+            .await
+    }
+
+    async fn send_chat_request(
+        &mut self,
+        messages: Vec<super::prompts::ChatMessage>,
+        max_tokens: Option<usize>,
+        temperature: Option<f32>,
+        response_format: Option<serde_json::Value>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        if self.supervisor.is_none() {
             return Err("No model loaded".into());
         }
 
@@ -526,7 +597,7 @@ impl LlamaCppEngine {
             .map(|mi| mi.name)
             .unwrap_or_else(|| "unknown".to_string());
 
-        let request_body = serde_json::json!({
+        let mut request_body = serde_json::json!({
             "model": model_name,
             "messages": msgs,
             "max_tokens": max_tokens.unwrap_or(self.config.max_tokens),
@@ -534,12 +605,16 @@ impl LlamaCppEngine {
             "stream": false,
             "stop": ["</s>", "<|im_end|>", "<|endoftext|>"]
         });
+        if let Some(format) = response_format {
+            request_body["response_format"] = format;
+        }
 
-        eprintln!("LLM Chat Request - Sending to http://127.0.0.1:8080/v1/chat/completions");
+        let url = self.endpoint("/v1/chat/completions")?;
+        eprintln!("LLM Chat Request - Sending to {}", url);
 
         let client = reqwest::Client::new();
         let response = client
-            .post("http://127.0.0.1:8080/v1/chat/completions")
+            .post(url)
             .json(&request_body)
             .timeout(std::time::Duration::from_secs(600))
             .send()
@@ -558,8 +633,9 @@ impl LlamaCppEngine {
             eprintln!("LLM Chat v1 route failed ({}): {}", status, text);
 
             eprintln!("LLM Chat Request - Falling back to /chat/completions");
+            let legacy_url = self.endpoint("/chat/completions")?;
             let resp2 = client
-                .post("http://127.0.0.1:8080/chat/completions")
+                .post(legacy_url)
                 .json(&request_body)
                 .timeout(std::time::Duration::from_secs(600))
                 .send()
@@ -636,7 +712,7 @@ impl LlamaCppEngine {
     ) -> Result<String, Box<dyn std::error::Error>> {
         use futures::StreamExt;
 
-        if self.process.is_none() {
+        if self.supervisor.is_none() {
             return Err("No model loaded".into());
         }
 
@@ -659,9 +735,11 @@ impl LlamaCppEngine {
             "stop": ["</s>", "<|im_end|>", "<|endoftext|>"]
         });
 
+        let url = self.endpoint("/v1/chat/completions")?;
+        let legacy_url = self.endpoint("/chat/completions")?;
         let client = reqwest::Client::new();
         let mut response = client
-            .post("http://127.0.0.1:8080/v1/chat/completions")
+            .post(url)
             .json(&request_body)
             .send()
             .await
@@ -670,7 +748,7 @@ impl LlamaCppEngine {
         if !response.status().is_success() {
             let client = reqwest::Client::new();
             response = client
-                .post("http://127.0.0.1:8080/chat/completions")
+                .post(legacy_url)
                 .json(&request_body)
                 .send()
                 .await
@@ -726,14 +804,34 @@ impl LlamaCppEngine {
                 .and_then(|n| n.to_str())
                 .unwrap_or("unknown")
                 .to_string(),
-            loaded: self.process.is_some(),
+            loaded: self.supervisor.is_some(),
         })
     }
 
-    #[allow(dead_code)]
+    /// Restart the server with the current model so a changed profile takes effect
+    pub fn reload(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let model_path = self.model_path.clone().ok_or("No model loaded")?;
+        self.load_model(model_path)
+    }
+
+    /// Effective configuration (profile of the loaded model applied)
     pub fn get_config(&self) -> super::LLMConfig {
         self.config.clone()
     }
+
+    fn apply_profile(&mut self, profile: &super::profiles::ModelProfile) {
+        self.config.model = profile.model_id.clone();
+        self.config.max_context = profile.max_context;
+        self.config.max_tokens = profile.max_tokens;
+        self.config.cpu_threads = profile
+            .cpu_threads
+            .unwrap_or_else(|| num_cpus::get_physical().max(1));
+        self.config.gpu_layers = profile.gpu_layers;
+        self.config.temperature = profile.temperature;
+        self.config.chat_template = profile.chat_template.clone();
+        self.config.jinja = profile.jinja;
+        self.config.extra_args = profile.extra_args.clone();
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,449 @@
+// GGUF 헤더 파서: 텐서 데이터는 읽지 않고 메타데이터와 텐서 정보만 읽음
+// https://github.com/ggerganov/ggml/blob/master/docs/gguf.md
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"GGUF";
+/// Strings we keep (names, chat template) are never this large in a valid file
+const MAX_KEPT_STRING: u64 = 1024 * 1024;
+/// GGML_MAX_DIMS
+const MAX_TENSOR_DIMS: u32 = 4;
+
+// Metadata value types
+const TYPE_UINT8: u32 = 0;
+const TYPE_INT8: u32 = 1;
+const TYPE_UINT16: u32 = 2;
+const TYPE_INT16: u32 = 3;
+const TYPE_UINT32: u32 = 4;
+const TYPE_INT32: u32 = 5;
+const TYPE_FLOAT32: u32 = 6;
+const TYPE_BOOL: u32 = 7;
+const TYPE_STRING: u32 = 8;
+const TYPE_ARRAY: u32 = 9;
+const TYPE_UINT64: u32 = 10;
+const TYPE_INT64: u32 = 11;
+const TYPE_FLOAT64: u32 = 12;
+
+/// What the app needs to know about a model file
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct GgufMetadata {
+    pub version: u32,
+    /// `general.architecture`, e.g. "llama", "qwen3"
+    pub architecture: Option<String>,
+    /// `general.name`
+    pub name: Option<String>,
+    /// Sum of all tensor element counts
+    pub parameter_count: u64,
+    /// `<arch>.context_length`: context the model was trained with
+    pub context_length: Option<u64>,
+    /// `<arch>.block_count`: number of transformer layers
+    pub block_count: Option<u64>,
+    /// `general.file_type` as a name, e.g. "Q4_K_M"
+    pub quantization: Option<String>,
+    /// `tokenizer.chat_template` (Jinja)
+    pub chat_template: Option<String>,
+    /// `tokenizer.ggml.model`, e.g. "gpt2", "llama"
+    pub tokenizer_model: Option<String>,
+    /// Length of `tokenizer.ggml.tokens`
+    pub vocab_size: Option<u64>,
+}
+
+impl GgufMetadata {
+    /// "8.2B", "494M"
+    pub fn parameter_label(&self) -> Option<String> {
+        let count = self.parameter_count as f64;
+        match self.parameter_count {
+            0 => None,
+            n if n >= 1_000_000_000 => Some(format!("{:.1}B", count / 1e9)),
+            _ => Some(format!("{:.0}M", count / 1e6)),
+        }
+    }
+}
+
+pub fn read_metadata(path: &Path) -> Result<GgufMetadata, Box<dyn std::error::Error>> {
+    let file = File::open(path)?;
+    parse(BufReader::new(file))
+}
+
+/// Parse the header (metadata and tensor infos) from the start of a GGUF stream
+pub fn parse<R: Read>(reader: R) -> Result<GgufMetadata, Box<dyn std::error::Error>> {
+    let mut reader = Reader { inner: reader };
+
+    let mut magic = [0u8; 4];
+    reader.inner.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err("Not a GGUF file".into());
+    }
+    let version = reader.u32()?;
+    if version < 2 {
+        // v1 used 32-bit lengths and is no longer produced by llama.cpp
+        return Err(format!("Unsupported GGUF version {}", version).into());
+    }
+    let tensor_count = reader.u64()?;
+    let kv_count = reader.u64()?;
+
+    let mut meta = GgufMetadata {
+        version,
+        ..GgufMetadata::default()
+    };
+    // <arch>.* 키는 architecture를 알아야 해석 가능하므로 일단 모아둠
+    let mut arch_values: Vec<(String, u64)> = Vec::new();
+    let mut file_type = None;
+
+    for _ in 0..kv_count {
+        let key = reader.string(MAX_KEPT_STRING)?;
+        let value_type = reader.u32()?;
+        match key.as_str() {
+            "general.architecture" if value_type == TYPE_STRING => {
+                meta.architecture = Some(reader.string(MAX_KEPT_STRING)?);
+            }
+            "general.name" if value_type == TYPE_STRING => {
+                meta.name = Some(reader.string(MAX_KEPT_STRING)?);
+            }
+            "general.file_type" => file_type = reader.integer(value_type)?,
+            "tokenizer.chat_template" if value_type == TYPE_STRING => {
+                meta.chat_template = Some(reader.string(MAX_KEPT_STRING)?);
+            }
+            "tokenizer.ggml.model" if value_type == TYPE_STRING => {
+                meta.tokenizer_model = Some(reader.string(MAX_KEPT_STRING)?);
+            }
+            "tokenizer.ggml.tokens" if value_type == TYPE_ARRAY => {
+                let element_type = reader.u32()?;
+                let count = reader.u64()?;
+                for _ in 0..count {
+                    reader.skip_value(element_type)?;
+                }
+                meta.vocab_size = Some(count);
+            }
+            _ if key.ends_with(".context_length") || key.ends_with(".block_count") => {
+                if let Some(value) = reader.integer(value_type)? {
+                    arch_values.push((key, value));
+                }
+            }
+            _ => reader.skip_value(value_type)?,
+        }
+    }
+
+    if let Some(arch) = &meta.architecture {
+        let lookup = |suffix: &str| {
+            let key = format!("{}.{}", arch, suffix);
+            arch_values.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+        };
+        meta.context_length = lookup("context_length");
+        meta.block_count = lookup("block_count");
+    }
+    meta.quantization = file_type.and_then(file_type_name).map(str::to_string);
+
+    for _ in 0..tensor_count {
+        reader.skip_string()?;
+        let dims = reader.u32()?;
+        if dims > MAX_TENSOR_DIMS {
+            return Err(format!("Invalid tensor with {} dimensions", dims).into());
+        }
+        let mut elements: u64 = 1;
+        for _ in 0..dims {
+            elements = elements.saturating_mul(reader.u64()?);
+        }
+        let _ggml_type = reader.u32()?;
+        let _offset = reader.u64()?;
+        meta.parameter_count = meta.parameter_count.saturating_add(elements);
+    }
+
+    Ok(meta)
+}
+
+/// `llama_ftype` names (values 4-6 were removed upstream)
+pub fn file_type_name(file_type: u64) -> Option<&'static str> {
+    Some(match file_type {
+        0 => "F32",
+        1 => "F16",
+        2 => "Q4_0",
+        3 => "Q4_1",
+        7 => "Q8_0",
+        8 => "Q5_0",
+        9 => "Q5_1",
+        10 => "Q2_K",
+        11 => "Q3_K_S",
+        12 => "Q3_K_M",
+        13 => "Q3_K_L",
+        14 => "Q4_K_S",
+        15 => "Q4_K_M",
+        16 => "Q5_K_S",
+        17 => "Q5_K_M",
+        18 => "Q6_K",
+        19 => "IQ2_XXS",
+        20 => "IQ2_XS",
+        21 => "Q2_K_S",
+        22 => "IQ3_XS",
+        23 => "IQ3_XXS",
+        24 => "IQ1_S",
+        25 => "IQ4_NL",
+        26 => "IQ3_S",
+        27 => "IQ3_M",
+        28 => "IQ2_S",
+        29 => "IQ2_M",
+        30 => "IQ4_XS",
+        31 => "IQ1_M",
+        32 => "BF16",
+        36 => "TQ1_0",
+        37 => "TQ2_0",
+        _ => return None,
+    })
+}
+
+struct Reader<R: Read> {
+    inner: R,
+}
+
+impl<R: Read> Reader<R> {
+    fn bytes<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut buf = [0u8; N];
+        self.inner.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes()?))
+    }
+
+    fn u64(&mut self) -> io::Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes()?))
+    }
+
+    fn string(&mut self, max_len: u64) -> Result<String, Box<dyn std::error::Error>> {
+        let len = self.u64()?;
+        if len > max_len {
+            return Err(format!("GGUF string too long ({} bytes)", len).into());
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.inner.read_exact(&mut buf)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    fn skip(&mut self, len: u64) -> io::Result<()> {
+        let skipped = io::copy(&mut self.inner.by_ref().take(len), &mut io::sink())?;
+        if skipped < len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(())
+    }
+
+    fn skip_string(&mut self) -> io::Result<()> {
+        let len = self.u64()?;
+        self.skip(len)
+    }
+
+    /// Unsigned value of any integer type; `None` for other types (which are skipped)
+    fn integer(&mut self, value_type: u32) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+        let value = match value_type {
+            TYPE_UINT8 => u8::from_le_bytes(self.bytes()?) as u64,
+            TYPE_UINT16 => u16::from_le_bytes(self.bytes()?) as u64,
+            TYPE_UINT32 => self.u32()? as u64,
+            TYPE_UINT64 => self.u64()?,
+            TYPE_INT32 => i32::from_le_bytes(self.bytes()?).max(0) as u64,
+            TYPE_INT64 => i64::from_le_bytes(self.bytes()?).max(0) as u64,
+            other => {
+                self.skip_value(other)?;
+                return Ok(None);
+            }
+        };
+        Ok(Some(value))
+    }
+
+    fn skip_value(&mut self, value_type: u32) -> Result<(), Box<dyn std::error::Error>> {
+        match value_type {
+            TYPE_UINT8 | TYPE_INT8 | TYPE_BOOL => self.skip(1)?,
+            TYPE_UINT16 | TYPE_INT16 => self.skip(2)?,
+            TYPE_UINT32 | TYPE_INT32 | TYPE_FLOAT32 => self.skip(4)?,
+            TYPE_UINT64 | TYPE_INT64 | TYPE_FLOAT64 => self.skip(8)?,
+            TYPE_STRING => self.skip_string()?,
+            TYPE_ARRAY => {
+                let element_type = self.u32()?;
+                let count = self.u64()?;
+                match fixed_size(element_type) {
+                    Some(size) => self.skip(count.saturating_mul(size))?,
+                    None => {
+                        for _ in 0..count {
+                            self.skip_value(element_type)?;
+                        }
+                    }
+                }
+            }
+            other => return Err(format!("Unknown GGUF value type {}", other).into()),
+        }
+        Ok(())
+    }
+}
+
+fn fixed_size(value_type: u32) -> Option<u64> {
+    match value_type {
+        TYPE_UINT8 | TYPE_INT8 | TYPE_BOOL => Some(1),
+        TYPE_UINT16 | TYPE_INT16 => Some(2),
+        TYPE_UINT32 | TYPE_INT32 | TYPE_FLOAT32 => Some(4),
+        TYPE_UINT64 | TYPE_INT64 | TYPE_FLOAT64 => Some(8),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds small GGUF headers for tests
+    struct Fixture {
+        version: u32,
+        kv: Vec<u8>,
+        kv_count: u64,
+        tensors: Vec<u8>,
+        tensor_count: u64,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                version: 3,
+                kv: Vec::new(),
+                kv_count: 0,
+                tensors: Vec::new(),
+                tensor_count: 0,
+            }
+        }
+
+        fn key(&mut self, key: &str, value_type: u32) -> &mut Vec<u8> {
+            self.kv_count += 1;
+            write_string(&mut self.kv, key);
+            self.kv.extend(value_type.to_le_bytes());
+            &mut self.kv
+        }
+
+        fn string(mut self, key: &str, value: &str) -> Self {
+            let buf = self.key(key, TYPE_STRING);
+            write_string(buf, value);
+            self
+        }
+
+        fn u32(mut self, key: &str, value: u32) -> Self {
+            self.key(key, TYPE_UINT32).extend(value.to_le_bytes());
+            self
+        }
+
+        fn f32(mut self, key: &str, value: f32) -> Self {
+            self.key(key, TYPE_FLOAT32).extend(value.to_le_bytes());
+            self
+        }
+
+        fn strings(mut self, key: &str, values: &[&str]) -> Self {
+            let buf = self.key(key, TYPE_ARRAY);
+            buf.extend(TYPE_STRING.to_le_bytes());
+            buf.extend((values.len() as u64).to_le_bytes());
+            for value in values {
+                write_string(buf, value);
+            }
+            self
+        }
+
+        fn i32s(mut self, key: &str, values: &[i32]) -> Self {
+            let buf = self.key(key, TYPE_ARRAY);
+            buf.extend(TYPE_INT32.to_le_bytes());
+            buf.extend((values.len() as u64).to_le_bytes());
+            for value in values {
+                buf.extend(value.to_le_bytes());
+            }
+            self
+        }
+
+        fn tensor(mut self, name: &str, dims: &[u64]) -> Self {
+            self.tensor_count += 1;
+            write_string(&mut self.tensors, name);
+            self.tensors.extend((dims.len() as u32).to_le_bytes());
+            for dim in dims {
+                self.tensors.extend(dim.to_le_bytes());
+            }
+            self.tensors.extend(12u32.to_le_bytes()); // GGML_TYPE_Q4_K
+            self.tensors.extend(0u64.to_le_bytes());
+            self
+        }
+
+        fn build(&self) -> Vec<u8> {
+            let mut out = MAGIC.to_vec();
+            out.extend(self.version.to_le_bytes());
+            out.extend(self.tensor_count.to_le_bytes());
+            out.extend(self.kv_count.to_le_bytes());
+            out.extend(&self.kv);
+            out.extend(&self.tensors);
+            // 실제 파일처럼 뒤에 텐서 데이터가 붙어 있어도 무시해야 함
+            out.extend([0xAB; 64]);
+            out
+        }
+    }
+
+    fn write_string(buf: &mut Vec<u8>, value: &str) {
+        buf.extend((value.len() as u64).to_le_bytes());
+        buf.extend(value.as_bytes());
+    }
+
+    #[test]
+    fn reads_model_metadata_and_parameter_count() {
+        let bytes = Fixture::new()
+            .string("general.architecture", "qwen3")
+            .string("general.name", "Qwen3 8B")
+            .u32("general.file_type", 15)
+            .u32("qwen3.context_length", 40960)
+            .u32("qwen3.block_count", 36)
+            .f32("qwen3.rope.freq_base", 1_000_000.0)
+            .u32("llama.context_length", 4096)
+            .string("tokenizer.ggml.model", "gpt2")
+            .strings("tokenizer.ggml.tokens", &["<s>", "</s>", "안녕"])
+            .i32s("tokenizer.ggml.token_type", &[3, 3, 1])
+            .string(
+                "tokenizer.chat_template",
+                "{% for m in messages %}{{ m.content }}{% endfor %}",
+            )
+            .tensor("token_embd.weight", &[4096, 151936])
+            .tensor("output_norm.weight", &[4096])
+            .build();
+
+        let meta = parse(bytes.as_slice()).unwrap();
+        assert_eq!(meta.version, 3);
+        assert_eq!(meta.architecture.as_deref(), Some("qwen3"));
+        assert_eq!(meta.name.as_deref(), Some("Qwen3 8B"));
+        assert_eq!(meta.quantization.as_deref(), Some("Q4_K_M"));
+        // 다른 architecture의 키는 무시
+        assert_eq!(meta.context_length, Some(40960));
+        assert_eq!(meta.block_count, Some(36));
+        assert_eq!(meta.tokenizer_model.as_deref(), Some("gpt2"));
+        assert_eq!(meta.vocab_size, Some(3));
+        assert!(meta.chat_template.as_deref().unwrap().contains("messages"));
+        assert_eq!(meta.parameter_count, 4096 * 151936 + 4096);
+        assert_eq!(meta.parameter_label().as_deref(), Some("622M"));
+    }
+
+    #[test]
+    fn missing_keys_stay_empty() {
+        let bytes = Fixture::new()
+            .string("general.architecture", "llama")
+            .build();
+        let meta = parse(bytes.as_slice()).unwrap();
+        assert_eq!(meta.architecture.as_deref(), Some("llama"));
+        assert_eq!(meta.context_length, None);
+        assert_eq!(meta.quantization, None);
+        assert_eq!(meta.chat_template, None);
+        assert_eq!(meta.parameter_label(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_headers() {
+        assert!(parse(&b"GGML\x03\x00\x00\x00"[..]).is_err());
+
+        let mut old = Fixture::new();
+        old.version = 1;
+        assert!(parse(old.build().as_slice()).is_err());
+
+        // 헤더 중간에서 잘린 파일
+        let bytes = Fixture::new().string("general.name", "truncated").build();
+        assert!(parse(&bytes[..bytes.len() - 70]).is_err());
+    }
+}
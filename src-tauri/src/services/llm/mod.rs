@@ -1,11 +1,16 @@
 // 로컬 LLM 모듈 (기존)
+pub mod categorize;
 pub mod download;
+pub mod endpoint;
 pub mod engine;
+pub mod gguf;
 // pub mod native_engine;  // Disabled - llama-cpp-2 has macOS compatibility issues
 pub mod models;
+pub mod profiles;
 pub mod prompt_config;
 pub mod prompts;
 pub mod summarize;
+pub mod supervisor;
 
 // 클라우드 LLM 모듈 (신규)
 pub mod commands;
@@ -14,34 +19,120 @@ pub mod security;
 pub mod traits;
 pub mod types;
 
+pub use endpoint::LocalEndpoint;
 pub use engine::LlamaCppEngine;
 // pub use native_engine::NativeLlamaEngine;  // Disabled for now
 pub use models::ModelManager;
+pub use supervisor::ServerMonitor;
 
 // 클라우드 LLM exports
 pub use commands::CloudLLMState;
 
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 
 pub struct LLMManager {
     pub engine: Arc<Mutex<LlamaCppEngine>>,
     pub model_manager: Arc<ModelManager>,
+    /// Status and logs of the local server; read without waiting on the engine lock
+    pub monitor: Arc<ServerMonitor>,
+    /// External OpenAI-compatible server used instead of the bundled engine while selected
+    pub endpoint: Arc<RwLock<Option<LocalEndpoint>>>,
 }
 
 impl LLMManager {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         // Use the process-based engine which works well with our --jinja fix
-        let engine = Arc::new(Mutex::new(LlamaCppEngine::new()?));
+        let engine = LlamaCppEngine::new()?;
+        let monitor = engine.monitor();
+        let engine = Arc::new(Mutex::new(engine));
         let model_manager = Arc::new(ModelManager::new()?);
 
         Ok(Self {
             engine,
             model_manager,
+            monitor,
+            endpoint: Arc::new(RwLock::new(Self::saved_endpoint())),
         })
     }
 
-    #[allow(dead_code)]
+    /// Endpoint selected in the app, so the CLI uses the same server
+    fn saved_endpoint() -> Option<LocalEndpoint> {
+        let saved = crate::services::storage_service::load_settings()
+            .ok()?
+            .llm_endpoint?;
+        LocalEndpoint::new(&saved.base_url, &saved.model)
+            .map_err(|e| eprintln!("LLM Endpoint - Ignoring saved endpoint: {}", e))
+            .ok()
+    }
+
+    /// The selected external endpoint, if any
+    pub async fn endpoint(&self) -> Option<LocalEndpoint> {
+        self.endpoint.read().await.clone()
+    }
+
+    pub async fn set_endpoint(&self, endpoint: Option<LocalEndpoint>) {
+        *self.endpoint.write().await = endpoint;
+    }
+
+    /// Chat completion on the local backend: the selected endpoint, else llama-server
+    pub async fn chat_complete(
+        &self,
+        messages: Vec<prompts::ChatMessage>,
+        max_tokens: Option<usize>,
+        temperature: Option<f32>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        if let Some(endpoint) = self.endpoint().await {
+            return endpoint.chat_complete(messages, max_tokens, temperature).await;
+        }
+
+        let mut engine = self.engine.lock().await;
+        if !engine.is_running() {
+            return Err("No model loaded".into());
+        }
+        engine.chat_complete(messages, max_tokens, temperature).await
+    }
+
+    /// Chat completion constrained to a JSON schema on the local backend
+    ///
+    /// The engine is locked for this request only.
+    pub async fn chat_complete_json(
+        &self,
+        messages: Vec<prompts::ChatMessage>,
+        schema: serde_json::Value,
+        max_tokens: Option<usize>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        if let Some(endpoint) = self.endpoint().await {
+            return endpoint
+                .chat_complete_json(messages, schema, max_tokens)
+                .await;
+        }
+
+        let mut engine = self.engine.lock().await;
+        if !engine.is_running() {
+            return Err("No model loaded".into());
+        }
+        engine
+            .chat_complete_json(messages, schema, max_tokens)
+            .await
+    }
+
+    /// Name of the model answering local requests; `None` when nothing is loaded
+    pub async fn local_model_name(&self) -> Option<String> {
+        if let Some(endpoint) = self.endpoint().await {
+            return Some(endpoint.model().to_string());
+        }
+        let engine = self.engine.lock().await;
+        if !engine.is_running() {
+            return None;
+        }
+        Some(
+            engine
+                .get_model_info()
+                .map_or_else(|| "unknown".to_string(), |info| info.name),
+        )
+    }
+
     pub async fn initialize(&self) -> Result<(), Box<dyn std::error::Error>> {
         // Ensure binary exists (no-op for native engine, extracts binary for process engine)
         self.engine.lock().await.ensure_binary_exists()?;
@@ -64,6 +155,16 @@ pub struct LLMConfig {
     pub max_tokens: usize,
     pub cpu_threads: usize,
     pub gpu_layers: usize,
+    /// llama-server port; `None` picks a free port on each start
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// `--chat-template`; `None` uses the template in the GGUF
+    #[serde(default)]
+    pub chat_template: Option<String>,
+    #[serde(default)]
+    pub jinja: bool,
+    #[serde(default)]
+    pub extra_args: Vec<String>,
 }
 
 impl Default for LLMConfig {
@@ -76,6 +177,10 @@ impl Default for LLMConfig {
             max_tokens: 2048,  // 충분한 응답 길이
             cpu_threads: 8,
             gpu_layers: 35,  // Metal GPU 가속 사용
+            port: None,
+            chat_template: None,
+            jinja: false,
+            extra_args: Vec::new(),
         }
     }
 }
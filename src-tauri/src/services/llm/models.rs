@@ -297,8 +297,18 @@ impl ModelManager {
 
         if index_path.exists() {
             let json_data = std::fs::read_to_string(&index_path)?;
-            if let Ok(models_map) = serde_json::from_str::<HashMap<String, LocalModel>>(&json_data)
+            if let Ok(mut models_map) =
+                serde_json::from_str::<HashMap<String, LocalModel>>(&json_data)
             {
+                // Models registered before the GGUF reader existed only have placeholder info
+                for model in models_map
+                    .values_mut()
+                    .filter(|m| m.info.url.is_empty() && m.info.quantization == "unknown")
+                {
+                    model.info =
+                        Self::detect_model_info(&model.info.id, &model.path, model.size_on_disk);
+                }
+
                 // Load the models into memory
                 let mut local_models = self.local_models.blocking_write();
                 *local_models = models_map;
@@ -328,19 +338,8 @@ impl ModelManager {
 
                         let size_on_disk = std::fs::metadata(&path)?.len();
 
-                        let info = matched.unwrap_or(ModelInfo {
-                            id: file_name.clone(),
-                            name: file_name.clone(),
-                            size: size_on_disk,
-                            url: String::new(),
-                            quantization: "unknown".to_string(),
-                            description: "Detected local GGUF model".to_string(),
-                            requirements: ModelRequirements {
-                                min_ram: 512,
-                                recommended_ram: 1024,
-                                supports_gpu: true,
-                            },
-                        });
+                        let info = matched
+                            .unwrap_or_else(|| Self::detect_model_info(&file_name, &path, size_on_disk));
 
                         let local_model = LocalModel {
                             info: info.clone(),
@@ -359,6 +358,55 @@ impl ModelManager {
         Ok(())
     }
 
+    /// Describe a GGUF file that is not in the catalog from its header
+    fn detect_model_info(id: &str, path: &std::path::Path, size_on_disk: u64) -> ModelInfo {
+        let mut info = ModelInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            size: size_on_disk,
+            url: String::new(),
+            quantization: "unknown".to_string(),
+            description: "Detected local GGUF model".to_string(),
+            requirements: ModelRequirements {
+                min_ram: 512,
+                recommended_ram: 1024,
+                supports_gpu: true,
+            },
+        };
+
+        let meta = match super::gguf::read_metadata(path) {
+            Ok(meta) => meta,
+            Err(e) => {
+                eprintln!("LLM Models - Could not read GGUF header of {:?}: {}", path, e);
+                return info;
+            }
+        };
+
+        if let Some(name) = &meta.name {
+            info.name = name.clone();
+        }
+        if let Some(quantization) = &meta.quantization {
+            info.quantization = quantization.clone();
+        }
+        let details: Vec<String> = [
+            meta.architecture.clone(),
+            meta.parameter_label().map(|label| format!("{} params", label)),
+            meta.context_length.map(|ctx| format!("{} ctx", ctx)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !details.is_empty() {
+            info.description = format!("Local GGUF model ({})", details.join(", "));
+        }
+
+        // Weights are memory-mapped: the file itself plus room for the KV cache and runtime
+        let size_mb = size_on_disk / (1024 * 1024);
+        info.requirements.min_ram = size_mb + size_mb / 10 + 512;
+        info.requirements.recommended_ram = size_mb + size_mb / 2 + 1024;
+        info
+    }
+
     async fn save_local_models_index(&self) -> Result<(), Box<dyn std::error::Error>> {
         let index_path = self.models_dir.join("models.json");
         let models = self.local_models.read().await;
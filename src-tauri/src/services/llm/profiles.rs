@@ -0,0 +1,370 @@
+// 모델별 실행 프로필: 컨텍스트 크기, 생성 토큰 수, 스레드, GPU 레이어, 채팅 템플릿 등
+// 기본 프리셋 위에 사용자가 저장한 프로필(model_profiles.json)이 덮어씀
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::gguf::{self, GgufMetadata};
+
+/// Flags the engine sets itself; not allowed in `extra_args`
+const RESERVED_ARGS: [&str; 6] = ["-m", "--model", "--port", "--host", "-c", "--ctx-size"];
+
+/// How llama-server is started for one model
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModelProfile {
+    /// Model id, i.e. the GGUF file name without `.gguf`
+    pub model_id: String,
+    /// `-c`
+    pub max_context: usize,
+    /// `-n` (n_predict)
+    pub max_tokens: usize,
+    /// `-t`; `None` uses the number of physical cores
+    #[serde(default)]
+    pub cpu_threads: Option<usize>,
+    /// `-ngl`; 999 offloads every layer
+    pub gpu_layers: usize,
+    pub temperature: f32,
+    /// `--chat-template` name (e.g. "chatml"); `None` uses the template stored in the GGUF
+    #[serde(default)]
+    pub chat_template: Option<String>,
+    /// `--jinja`
+    #[serde(default)]
+    pub jinja: bool,
+    /// Appended verbatim to the llama-server command line
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    /// True when the user saved this profile (false for shipped presets)
+    #[serde(default)]
+    pub customized: bool,
+}
+
+impl ModelProfile {
+    /// Profile for models without a preset or saved profile
+    pub fn fallback(model_id: &str) -> Self {
+        Self {
+            model_id: model_id.to_string(),
+            max_context: 8192,
+            max_tokens: 2048,
+            cpu_threads: None,
+            gpu_layers: 35,
+            temperature: 0.3,
+            chat_template: None,
+            jinja: false,
+            extra_args: Vec::new(),
+            customized: false,
+        }
+    }
+
+    /// Profile for a model without a preset, from its GGUF header
+    pub fn from_gguf(model_id: &str, meta: &GgufMetadata) -> Self {
+        let fallback = Self::fallback(model_id);
+        Self {
+            // 학습된 컨텍스트보다 길게 잡으면 품질이 떨어지고 메모리만 씀
+            max_context: meta.context_length.map_or(fallback.max_context, |ctx| {
+                (ctx as usize).clamp(2048, fallback.max_context)
+            }),
+            // 전층 + 출력층 GPU 오프로딩
+            gpu_layers: meta
+                .block_count
+                .map_or(fallback.gpu_layers, |layers| layers as usize + 1),
+            // llama-server applies the template stored in the GGUF only with --jinja
+            jinja: meta.chat_template.is_some(),
+            ..fallback
+        }
+    }
+
+    fn preset(
+        model_id: &str,
+        max_context: usize,
+        max_tokens: usize,
+        gpu_layers: usize,
+        jinja: bool,
+    ) -> Self {
+        Self {
+            max_context,
+            max_tokens,
+            gpu_layers,
+            jinja,
+            ..Self::fallback(model_id)
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.model_id.trim().is_empty() {
+            return Err("Profile model_id is empty".into());
+        }
+        if self.max_context < 512 {
+            return Err("max_context must be at least 512".into());
+        }
+        if self.max_tokens == 0 || self.max_tokens > self.max_context {
+            return Err(format!(
+                "max_tokens must be between 1 and max_context ({})",
+                self.max_context
+            )
+            .into());
+        }
+        if self.cpu_threads == Some(0) {
+            return Err("cpu_threads must be at least 1".into());
+        }
+        if !(0.0..=2.0).contains(&self.temperature) {
+            return Err("temperature must be between 0.0 and 2.0".into());
+        }
+        if let Some(arg) = self
+            .extra_args
+            .iter()
+            .find(|arg| RESERVED_ARGS.contains(&arg.split('=').next().unwrap_or(arg)))
+        {
+            return Err(format!("{} is set by the engine and cannot be overridden", arg).into());
+        }
+        Ok(())
+    }
+}
+
+/// Presets for the models in the download catalog
+pub fn presets() -> Vec<ModelProfile> {
+    vec![
+        ModelProfile::preset("qwen2.5-0.5b-q4", 8192, 2048, 35, true),
+        ModelProfile::preset("qwen2.5-3b-q4", 8192, 2048, 999, true),
+        // 12288: 충분한 여유, 과도한 ctx로 인한 속도 저하 방지
+        ModelProfile::preset("qwen2.5-7b-q4", 12288, 4096, 999, true),
+        ModelProfile::preset("qwen3-8b-q4", 12288, 4096, 999, true),
+        ModelProfile::preset("phi-3.5-mini-q4", 16384, 2048, 35, false),
+        ModelProfile::preset("phi4-mini-q4", 16384, 2048, 35, false),
+        ModelProfile::preset("llama-3.2-1b-q4", 8192, 2048, 35, false),
+        ModelProfile::preset("gemma-3-4b-qat", 8192, 2048, 35, false),
+    ]
+}
+
+/// Saved profile, else preset
+fn lookup(saved: &[ModelProfile], model_id: &str) -> Option<ModelProfile> {
+    saved
+        .iter()
+        .find(|profile| profile.model_id == model_id)
+        .cloned()
+        .or_else(|| {
+            presets()
+                .into_iter()
+                .find(|profile| profile.model_id == model_id)
+        })
+}
+
+/// Saved profile, else preset, else fallback
+pub fn resolve(saved: &[ModelProfile], model_id: &str) -> ModelProfile {
+    lookup(saved, model_id).unwrap_or_else(|| ModelProfile::fallback(model_id))
+}
+
+/// Saved profile, else preset, else derived from the GGUF header at `model_path`
+fn resolve_local(saved: &[ModelProfile], model_id: &str, model_path: &Path) -> ModelProfile {
+    lookup(saved, model_id).unwrap_or_else(|| match gguf::read_metadata(model_path) {
+        Ok(meta) => ModelProfile::from_gguf(model_id, &meta),
+        Err(e) => {
+            eprintln!("LLM Profiles - Could not read GGUF header: {}", e);
+            ModelProfile::fallback(model_id)
+        }
+    })
+}
+
+/// Presets with saved profiles applied, saved profiles for other models, then the local
+/// models (`(model_id, path)`) that have neither
+pub fn merge(saved: &[ModelProfile], local_models: &[(String, PathBuf)]) -> Vec<ModelProfile> {
+    let mut profiles: Vec<ModelProfile> = presets()
+        .into_iter()
+        .map(|preset| resolve(saved, &preset.model_id))
+        .collect();
+    for profile in saved {
+        if !profiles.iter().any(|p| p.model_id == profile.model_id) {
+            profiles.push(profile.clone());
+        }
+    }
+    for (model_id, model_path) in local_models {
+        if !profiles.iter().any(|p| &p.model_id == model_id) {
+            profiles.push(resolve_local(saved, model_id, model_path));
+        }
+    }
+    profiles
+}
+
+fn profiles_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let data_dir = dirs::data_dir().ok_or("Could not determine data directory")?;
+    Ok(data_dir.join("hoego").join("model_profiles.json"))
+}
+
+/// Profiles the user saved (presets are not written to disk)
+fn load_saved() -> Result<Vec<ModelProfile>, Box<dyn std::error::Error>> {
+    let path = profiles_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+fn write_saved(profiles: &[ModelProfile]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = profiles_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(profiles)?)?;
+    Ok(())
+}
+
+pub fn list_profiles(
+    local_models: &[(String, PathBuf)],
+) -> Result<Vec<ModelProfile>, Box<dyn std::error::Error>> {
+    Ok(merge(&load_saved()?, local_models))
+}
+
+/// Profile applied when `model_id` is loaded from `model_path`
+///
+/// Saved profile, else preset, else derived from the GGUF header. A broken profile file falls
+/// back to presets.
+pub fn get_profile(model_id: &str, model_path: &Path) -> ModelProfile {
+    let saved = load_saved().unwrap_or_else(|e| {
+        eprintln!("LLM Profiles - Failed to read saved profiles: {}", e);
+        Vec::new()
+    });
+    resolve_local(&saved, model_id, model_path)
+}
+
+pub fn save_profile(mut profile: ModelProfile) -> Result<ModelProfile, Box<dyn std::error::Error>> {
+    profile.validate()?;
+    profile.customized = true;
+
+    let mut saved = load_saved()?;
+    saved.retain(|p| p.model_id != profile.model_id);
+    saved.push(profile.clone());
+    write_saved(&saved)?;
+    Ok(profile)
+}
+
+/// Drop the saved profile so the preset (or the GGUF-derived profile) applies again
+pub fn reset_profile(
+    model_id: &str,
+    model_path: &Path,
+) -> Result<ModelProfile, Box<dyn std::error::Error>> {
+    let mut saved = load_saved()?;
+    saved.retain(|p| p.model_id != model_id);
+    write_saved(&saved)?;
+    Ok(resolve_local(&saved, model_id, model_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_profile_overrides_preset_and_unknown_models_fall_back() {
+        let custom = ModelProfile {
+            max_context: 4096,
+            customized: true,
+            ..resolve(&[], "qwen3-8b-q4")
+        };
+        let saved = vec![custom.clone()];
+
+        assert_eq!(resolve(&saved, "qwen3-8b-q4"), custom);
+        assert!(resolve(&[], "qwen3-8b-q4").jinja);
+        assert_eq!(
+            resolve(&saved, "my-model"),
+            ModelProfile::fallback("my-model")
+        );
+
+        let merged = merge(&[custom, ModelProfile::fallback("my-model")], &[]);
+        assert_eq!(merged.len(), presets().len() + 1);
+        assert_eq!(
+            merged
+                .iter()
+                .find(|p| p.model_id == "qwen3-8b-q4")
+                .unwrap()
+                .max_context,
+            4096
+        );
+    }
+
+    #[test]
+    fn local_models_without_profiles_use_their_gguf_header() {
+        fn kv_string(buf: &mut Vec<u8>, value: &str) {
+            buf.extend((value.len() as u64).to_le_bytes());
+            buf.extend(value.as_bytes());
+        }
+        // GGUF v3: general.architecture = "llama", llama.context_length = 4096
+        let mut header = b"GGUF".to_vec();
+        header.extend(3u32.to_le_bytes());
+        header.extend(0u64.to_le_bytes());
+        header.extend(2u64.to_le_bytes());
+        kv_string(&mut header, "general.architecture");
+        header.extend(8u32.to_le_bytes());
+        kv_string(&mut header, "llama");
+        kv_string(&mut header, "llama.context_length");
+        header.extend(4u32.to_le_bytes());
+        header.extend(4096u32.to_le_bytes());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("my-model.gguf");
+        fs::write(&path, header).unwrap();
+        let local = vec![
+            ("my-model".to_string(), path.clone()),
+            ("qwen3-8b-q4".to_string(), dir.path().join("qwen3-8b-q4.gguf")),
+        ];
+
+        let merged = merge(&[], &local);
+        assert_eq!(merged.len(), presets().len() + 1);
+        let derived = merged.iter().find(|p| p.model_id == "my-model").unwrap();
+        assert_eq!(derived.max_context, 4096);
+        assert_eq!(derived, &resolve_local(&[], "my-model", &path));
+        assert_eq!(
+            resolve_local(&[], "missing", &dir.path().join("missing.gguf")),
+            ModelProfile::fallback("missing")
+        );
+    }
+
+    #[test]
+    fn gguf_profile_follows_model_header() {
+        let meta = GgufMetadata {
+            context_length: Some(4096),
+            block_count: Some(28),
+            chat_template: Some("{{ messages }}".to_string()),
+            ..GgufMetadata::default()
+        };
+        let profile = ModelProfile::from_gguf("custom", &meta);
+        assert_eq!(profile.max_context, 4096);
+        assert_eq!(profile.gpu_layers, 29);
+        assert!(profile.jinja);
+        assert!(profile.validate().is_ok());
+
+        // 긴 컨텍스트 모델도 기본 상한까지만 사용
+        let long = GgufMetadata {
+            context_length: Some(131072),
+            ..GgufMetadata::default()
+        };
+        let profile = ModelProfile::from_gguf("custom", &long);
+        assert_eq!(
+            profile.max_context,
+            ModelProfile::fallback("custom").max_context
+        );
+        assert!(!profile.jinja);
+    }
+
+    #[test]
+    fn validate_rejects_bad_values_and_reserved_args() {
+        assert!(ModelProfile::fallback("m").validate().is_ok());
+
+        let too_many_tokens = ModelProfile {
+            max_tokens: 10_000,
+            ..ModelProfile::fallback("m")
+        };
+        assert!(too_many_tokens.validate().is_err());
+
+        let port_override = ModelProfile {
+            extra_args: vec!["--port=9000".to_string()],
+            ..ModelProfile::fallback("m")
+        };
+        assert!(port_override.validate().is_err());
+
+        let flash_attn = ModelProfile {
+            extra_args: vec!["--flash-attn".to_string()],
+            ..ModelProfile::fallback("m")
+        };
+        assert!(flash_attn.validate().is_ok());
+    }
+}
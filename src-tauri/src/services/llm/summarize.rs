@@ -1,6 +1,6 @@
 use super::{
     prompts::{PromptTemplate, SummarizationStyle},
-    LLMManager,
+    LLMManager, LocalEndpoint,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -51,6 +51,56 @@ pub struct NoteSummary {
     pub summary: String,
 }
 
+// Select prompt template based on model type
+fn select_prompt(request: SummaryRequest) -> (PromptTemplate, SummarizationStyle) {
+    if request.style.is_none() || matches!(request.style, Some(SummarizationStyle::Paragraph)) {
+        // Determine which prompt template to use
+        let use_local = request.use_local_prompt.unwrap_or(false);
+        let prompt = if use_local {
+            eprintln!("[Summarize] Using local model prompt (simplified 3-section)");
+            PromptTemplate::for_local_model(&request.content)
+        } else {
+            eprintln!("[Summarize] Using cloud prompt (deep cognitive analysis)");
+            PromptTemplate::for_business_journal_coach(&request.content)
+        };
+        (prompt, SummarizationStyle::Paragraph)
+    } else {
+        // Use specific style if requested
+        let style = request.style.unwrap();
+        (
+            PromptTemplate::for_summarization(&style, &request.content, request.max_length),
+            style
+        )
+    }
+}
+
+// Summarize on the selected external endpoint (the bundled engine is not started)
+async fn summarize_with_endpoint(
+    endpoint: LocalEndpoint,
+    request: SummaryRequest,
+    start_time: std::time::Instant,
+) -> Result<SummaryResult, String> {
+    endpoint.health().await.map_err(|e| e.to_string())?;
+
+    let (prompt, style) = select_prompt(request);
+    let chat_messages = prompt.to_chat_format();
+
+    eprintln!("[Summarize] Using endpoint {} ({})", endpoint.base_url(), endpoint.model());
+
+    let summary = endpoint
+        .chat_complete(chat_messages, None, None)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(SummaryResult {
+        summary,
+        style,
+        model_used: endpoint.model().to_string(),
+        tokens_used: 0,
+        processing_time_ms: start_time.elapsed().as_millis() as u64,
+    })
+}
+
 // Tauri command handlers
 #[tauri::command]
 pub async fn summarize_note(
@@ -59,6 +109,10 @@ pub async fn summarize_note(
 ) -> Result<SummaryResult, String> {
     let start_time = std::time::Instant::now();
 
+    if let Some(endpoint) = state.endpoint().await {
+        return summarize_with_endpoint(endpoint, request, start_time).await;
+    }
+
     // Get the engine
     let mut engine = state.engine.lock().await;
 
@@ -107,26 +161,7 @@ pub async fn summarize_note(
         }
     }
 
-    // Select prompt template based on model type
-    let (prompt, style) = if request.style.is_none() || matches!(request.style, Some(SummarizationStyle::Paragraph)) {
-        // Determine which prompt template to use
-        let use_local = request.use_local_prompt.unwrap_or(false);
-        let prompt = if use_local {
-            eprintln!("[Summarize] Using local model prompt (simplified 3-section)");
-            PromptTemplate::for_local_model(&request.content)
-        } else {
-            eprintln!("[Summarize] Using cloud prompt (deep cognitive analysis)");
-            PromptTemplate::for_business_journal_coach(&request.content)
-        };
-        (prompt, SummarizationStyle::Paragraph)
-    } else {
-        // Use specific style if requested
-        let style = request.style.unwrap();
-        (
-            PromptTemplate::for_summarization(&style, &request.content, request.max_length),
-            style
-        )
-    };
+    let (prompt, style) = select_prompt(request);
 
     // Get model info
     let model_info = engine.get_model_info().ok_or("No model loaded")?;
@@ -228,19 +263,13 @@ pub async fn get_note_insights(
     state: tauri::State<'_, Arc<LLMManager>>,
     content: String,
 ) -> Result<String, String> {
-    let mut engine = state.engine.lock().await;
-
-    if !engine.is_running() {
-        return Err("No model loaded".to_string());
-    }
-
     // Use business journal coach format (for_note_insights now uses it)
     let prompt = PromptTemplate::for_note_insights(&content);
 
     let chat_messages = prompt.to_chat_format();
 
     // brief 응답 목표: 200 토큰
-    engine
+    state
         .chat_complete(chat_messages, Some(200), None)
         .await
         .map_err(|e| e.to_string())
@@ -251,19 +280,13 @@ pub async fn create_meeting_minutes(
     state: tauri::State<'_, Arc<LLMManager>>,
     content: String,
 ) -> Result<String, String> {
-    let mut engine = state.engine.lock().await;
-
-    if !engine.is_running() {
-        return Err("No model loaded".to_string());
-    }
-
     // Use business journal coach format (for_meeting_minutes now uses it)
     let prompt = PromptTemplate::for_meeting_minutes(&content);
 
     let chat_messages = prompt.to_chat_format();
 
     // 회의록/인사이트: 512 토큰 제한
-    engine
+    state
         .chat_complete(chat_messages, Some(512), None)
         .await
         .map_err(|e| e.to_string())
@@ -274,19 +297,13 @@ pub async fn daily_review(
     state: tauri::State<'_, Arc<LLMManager>>,
     notes: Vec<String>,
 ) -> Result<String, String> {
-    let mut engine = state.engine.lock().await;
-
-    if !engine.is_running() {
-        return Err("No model loaded".to_string());
-    }
-
     // Use business journal coach format (which is already set in for_daily_review)
     let prompt = PromptTemplate::for_daily_review(notes);
 
     let chat_messages = prompt.to_chat_format();
 
     // 데일리 리뷰: 600 토큰 제한
-    engine
+    state
         .chat_complete(chat_messages, Some(600), None)
         .await
         .map_err(|e| e.to_string())
@@ -0,0 +1,538 @@
+// llama-server 감시: 출력을 링 버퍼로 수집하고, 비정상 종료 시 재시작하며 상태 변화를 알림
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Tauri event emitted on every status change
+pub const STATUS_EVENT: &str = "llm_status_changed";
+
+const MAX_LOG_LINES: usize = 500;
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+const HEALTH_INTERVAL: Duration = Duration::from_secs(1);
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(1);
+/// Give up after this many restarts in a row
+const MAX_RESTARTS: u32 = 5;
+const BACKOFF_BASE: Duration = Duration::from_secs(1);
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// A server that ran this long before crashing starts over with a fresh restart budget
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerStatus {
+    /// Process is being spawned
+    Starting,
+    /// Process is running, model is still loading (`/health` not ok yet)
+    Loading,
+    Ready,
+    /// Process exited unexpectedly; a restart may be pending
+    Crashed,
+    #[default]
+    Stopped,
+}
+
+impl ServerStatus {
+    pub fn is_alive(self) -> bool {
+        matches!(
+            self,
+            ServerStatus::Starting | ServerStatus::Loading | ServerStatus::Ready
+        )
+    }
+}
+
+/// Payload of `llm_status_changed`
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ServerState {
+    pub status: ServerStatus,
+    pub port: Option<u16>,
+    pub pid: Option<u32>,
+    /// Restarts since the last stable run
+    pub restarts: u32,
+    /// Why the server crashed or could not be restarted
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogSource {
+    Stdout,
+    Stderr,
+    /// Lines written by the supervisor itself (crashes, restarts)
+    Supervisor,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineLogLine {
+    /// RFC 3339
+    pub timestamp: String,
+    pub source: LogSource,
+    pub text: String,
+}
+
+pub type StatusListener = Arc<dyn Fn(&ServerState) + Send + Sync>;
+
+/// Status and recent output of the server, readable without locking the engine
+#[derive(Default)]
+pub struct ServerMonitor {
+    state: Mutex<ServerState>,
+    logs: Mutex<VecDeque<EngineLogLine>>,
+    listener: Mutex<Option<StatusListener>>,
+}
+
+impl std::fmt::Debug for ServerMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerMonitor")
+            .field("state", &self.state())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ServerMonitor {
+    pub fn state(&self) -> ServerState {
+        self.state.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    /// Most recent `limit` lines (all buffered lines when `None`), oldest first
+    pub fn logs(&self, limit: Option<usize>) -> Vec<EngineLogLine> {
+        let Ok(logs) = self.logs.lock() else {
+            return Vec::new();
+        };
+        let skip = limit.map_or(0, |limit| logs.len().saturating_sub(limit));
+        logs.iter().skip(skip).cloned().collect()
+    }
+
+    /// Called on every status change (the app forwards it as `llm_status_changed`)
+    pub fn set_listener(&self, listener: StatusListener) {
+        if let Ok(mut slot) = self.listener.lock() {
+            *slot = Some(listener);
+        }
+    }
+
+    fn update(&self, change: impl FnOnce(&mut ServerState)) {
+        let state = match self.state.lock() {
+            Ok(mut state) => {
+                let before = state.clone();
+                change(&mut state);
+                if *state == before {
+                    return;
+                }
+                state.clone()
+            }
+            Err(_) => return,
+        };
+        eprintln!("LLM Supervisor - Status: {:?}", state.status);
+
+        let listener = self.listener.lock().ok().and_then(|slot| slot.clone());
+        if let Some(listener) = listener {
+            listener(&state);
+        }
+    }
+
+    fn set_status(&self, status: ServerStatus) {
+        self.update(|state| state.status = status);
+    }
+
+    fn push_log(&self, source: LogSource, text: String) {
+        if let Ok(mut logs) = self.logs.lock() {
+            if logs.len() == MAX_LOG_LINES {
+                logs.pop_front();
+            }
+            logs.push_back(EngineLogLine {
+                timestamp: chrono::Local::now().to_rfc3339(),
+                source,
+                text,
+            });
+        }
+    }
+
+    fn note(&self, text: String) {
+        eprintln!("LLM Supervisor - {}", text);
+        self.push_log(LogSource::Supervisor, text);
+    }
+
+    /// Last lines of output as one string, for startup errors
+    fn tail_text(&self, lines: usize) -> String {
+        self.logs(Some(lines))
+            .into_iter()
+            .map(|line| line.text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Everything needed to (re)spawn the server
+#[derive(Debug, Clone)]
+pub struct ServerSpec {
+    pub binary_path: PathBuf,
+    pub args: Vec<OsString>,
+    pub port: u16,
+    /// Rewritten with the PID after every spawn so orphans can be found later
+    pub pid_path: Option<PathBuf>,
+}
+
+/// Owns the running server: drains its output, restarts it after crashes
+#[derive(Debug)]
+pub struct Supervisor {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Supervisor {
+    /// Spawn the server; a crash right at startup is returned as an error, later crashes are
+    /// restarted with backoff
+    pub fn start(spec: ServerSpec, monitor: Arc<ServerMonitor>) -> Result<Self, String> {
+        monitor.update(|state| {
+            *state = ServerState {
+                status: ServerStatus::Starting,
+                port: Some(spec.port),
+                ..ServerState::default()
+            }
+        });
+
+        let (mut child, readers) = spawn(&spec, &monitor).map_err(|e| {
+            monitor.update(|state| {
+                state.status = ServerStatus::Crashed;
+                state.error = Some(e.to_string());
+            });
+            format!("Failed to spawn llama-server: {}", e)
+        })?;
+
+        // Give it a moment: a bad flag or model makes it exit right away
+        std::thread::sleep(Duration::from_millis(500));
+        if let Ok(Some(status)) = child.try_wait() {
+            for reader in readers {
+                let _ = reader.join();
+            }
+            remove_pid_file(&spec);
+            let output = monitor.tail_text(20);
+            monitor.update(|state| {
+                state.status = ServerStatus::Crashed;
+                state.pid = None;
+                state.error = Some(format!("exited on startup: {}", status));
+            });
+            return Err(format!(
+                "llama.cpp process crashed on startup: {}\n{}",
+                status, output
+            ));
+        }
+
+        monitor.update(|state| {
+            state.status = ServerStatus::Loading;
+            state.pid = Some(child.id());
+        });
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let thread = std::thread::Builder::new()
+            .name("llama-server-supervisor".to_string())
+            .spawn(move || supervise(child, spec, monitor, thread_stop))
+            .map_err(|e| format!("Failed to start supervisor thread: {}", e))?;
+
+        Ok(Self {
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Kill the server and wait for the supervisor to finish
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+enum Exit {
+    Stopped,
+    Crashed(String),
+}
+
+fn supervise(
+    mut child: Child,
+    spec: ServerSpec,
+    monitor: Arc<ServerMonitor>,
+    stop: Arc<AtomicBool>,
+) {
+    let mut restarts = 0;
+    loop {
+        let started = Instant::now();
+        let mut error = match watch(&mut child, &spec, &monitor, &stop) {
+            Exit::Stopped => break,
+            Exit::Crashed(error) => error,
+        };
+        if started.elapsed() >= STABLE_AFTER {
+            restarts = 0;
+        }
+
+        // 재시작: 실패하면 대기 시간을 늘려가며 MAX_RESTARTS번까지 시도
+        let respawned = loop {
+            monitor.note(error.clone());
+            if restarts >= MAX_RESTARTS {
+                monitor.note(format!("Giving up after {} restarts", restarts));
+                monitor.update(|state| {
+                    state.status = ServerStatus::Crashed;
+                    state.pid = None;
+                    state.error = Some(format!("{} (gave up after {} restarts)", error, restarts));
+                });
+                remove_pid_file(&spec);
+                return;
+            }
+            monitor.update(|state| {
+                state.status = ServerStatus::Crashed;
+                state.pid = None;
+                state.error = Some(error.clone());
+            });
+
+            let delay = BACKOFF_BASE
+                .saturating_mul(1u32 << restarts.min(16))
+                .min(BACKOFF_MAX);
+            restarts += 1;
+            monitor.note(format!(
+                "Restarting in {}s (attempt {}/{})",
+                delay.as_secs(),
+                restarts,
+                MAX_RESTARTS
+            ));
+            if sleep_unless_stopped(delay, &stop) {
+                break None;
+            }
+
+            monitor.update(|state| {
+                state.status = ServerStatus::Starting;
+                state.restarts = restarts;
+            });
+            match spawn(&spec, &monitor) {
+                Ok((new_child, _)) => break Some(new_child),
+                Err(e) => error = format!("Failed to respawn llama-server: {}", e),
+            }
+        };
+
+        match respawned {
+            Some(new_child) => {
+                monitor.update(|state| {
+                    state.status = ServerStatus::Loading;
+                    state.pid = Some(new_child.id());
+                    state.error = None;
+                });
+                child = new_child;
+            }
+            None => break,
+        }
+    }
+
+    remove_pid_file(&spec);
+    monitor.update(|state| {
+        state.status = ServerStatus::Stopped;
+        state.pid = None;
+    });
+}
+
+/// Poll the process until it exits or a stop is requested, marking it ready once `/health` is ok
+fn watch(child: &mut Child, spec: &ServerSpec, monitor: &ServerMonitor, stop: &AtomicBool) -> Exit {
+    let health_url = format!("http://127.0.0.1:{}/health", spec.port);
+    let client = reqwest::blocking::Client::builder()
+        .timeout(HEALTH_TIMEOUT)
+        .build()
+        .ok();
+    let mut ready = false;
+    let mut last_health_check: Option<Instant> = None;
+
+    loop {
+        if stop.load(Ordering::SeqCst) {
+            eprintln!("LLM Supervisor - Stopping process {}", child.id());
+            let _ = child.kill();
+            let _ = child.wait();
+            return Exit::Stopped;
+        }
+
+        match child.try_wait() {
+            Ok(None) => {}
+            Ok(Some(status)) => return Exit::Crashed(format!("llama-server exited: {}", status)),
+            Err(e) => return Exit::Crashed(format!("Failed to check llama-server: {}", e)),
+        }
+
+        let health_due = !matches!(last_health_check, Some(at) if at.elapsed() < HEALTH_INTERVAL);
+        if !ready && health_due {
+            last_health_check = Some(Instant::now());
+            let healthy = client.as_ref().is_some_and(|client| {
+                client
+                    .get(&health_url)
+                    .send()
+                    .map(|response| response.status().is_success())
+                    .unwrap_or(false)
+            });
+            if healthy {
+                ready = true;
+                monitor.set_status(ServerStatus::Ready);
+            }
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Sleep in small steps; `true` when a stop was requested meanwhile
+fn sleep_unless_stopped(duration: Duration, stop: &AtomicBool) -> bool {
+    let deadline = Instant::now() + duration;
+    while Instant::now() < deadline {
+        if stop.load(Ordering::SeqCst) {
+            return true;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+    stop.load(Ordering::SeqCst)
+}
+
+fn spawn(
+    spec: &ServerSpec,
+    monitor: &Arc<ServerMonitor>,
+) -> std::io::Result<(Child, Vec<JoinHandle<()>>)> {
+    let mut child = Command::new(&spec.binary_path)
+        .args(&spec.args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // 파이프를 계속 비워야 출력이 많을 때 서버가 멈추지 않음
+    let mut readers = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        readers.push(drain(stdout, LogSource::Stdout, monitor.clone()));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        readers.push(drain(stderr, LogSource::Stderr, monitor.clone()));
+    }
+
+    if let Some(pid_path) = &spec.pid_path {
+        if let Err(e) = std::fs::write(pid_path, child.id().to_string()) {
+            eprintln!("LLM Warning - Failed to write pid file: {}", e);
+        }
+    }
+    monitor.note(format!(
+        "Started llama-server (pid {}, port {})",
+        child.id(),
+        spec.port
+    ));
+    Ok((child, readers))
+}
+
+fn drain(
+    pipe: impl Read + Send + 'static,
+    source: LogSource,
+    monitor: Arc<ServerMonitor>,
+) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(pipe);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buf).trim_end().to_string();
+                    if !line.is_empty() {
+                        monitor.push_log(source, line);
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn remove_pid_file(spec: &ServerSpec) {
+    if let Some(pid_path) = &spec.pid_path {
+        let _ = std::fs::remove_file(pid_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_ring_buffer_keeps_newest_lines() {
+        let monitor = ServerMonitor::default();
+        for i in 0..(MAX_LOG_LINES + 10) {
+            monitor.push_log(LogSource::Stderr, format!("line {}", i));
+        }
+
+        let logs = monitor.logs(None);
+        assert_eq!(logs.len(), MAX_LOG_LINES);
+        assert_eq!(logs[0].text, "line 10");
+
+        let tail = monitor.logs(Some(2));
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail[1].text, format!("line {}", MAX_LOG_LINES + 9));
+    }
+
+    #[test]
+    fn listener_sees_status_changes_once() {
+        let monitor = ServerMonitor::default();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        monitor.set_listener(Arc::new(move |state: &ServerState| {
+            sink.lock().unwrap().push(state.status);
+        }));
+
+        monitor.set_status(ServerStatus::Starting);
+        monitor.set_status(ServerStatus::Starting);
+        monitor.set_status(ServerStatus::Ready);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![ServerStatus::Starting, ServerStatus::Ready]
+        );
+        assert!(monitor.state().status.is_alive());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn restarts_crashed_process_and_captures_output() {
+        let monitor = Arc::new(ServerMonitor::default());
+        // 잠깐 살아 있다가 종료되는 가짜 서버
+        let spec = ServerSpec {
+            binary_path: PathBuf::from("/bin/sh"),
+            args: vec!["-c".into(), "echo booting; sleep 1; exit 3".into()],
+            port: 1,
+            pid_path: None,
+        };
+
+        let supervisor = Supervisor::start(spec, monitor.clone()).unwrap();
+        assert_eq!(monitor.state().status, ServerStatus::Loading);
+
+        // 첫 종료 후 1초 대기, 재시작까지 기다림 (느린 환경을 고려해 넉넉한 기한)
+        let deadline = Instant::now() + Duration::from_secs(15);
+        while monitor.state().restarts == 0 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert_eq!(monitor.state().restarts, 1);
+        let logs = monitor.logs(None);
+        assert!(logs
+            .iter()
+            .any(|l| l.source == LogSource::Stdout && l.text == "booting"));
+        assert!(logs.iter().any(|l| l.text.contains("llama-server exited")));
+
+        supervisor.stop();
+        assert_eq!(monitor.state().status, ServerStatus::Stopped);
+        assert_eq!(monitor.state().pid, None);
+    }
+}
@@ -2,10 +2,20 @@
 // Business logic layer organized by domain
 
 pub mod ai_service;
+pub mod calendar_service;
+pub mod category_service;
+pub mod daemon_service;
 pub mod feedback_service;
+pub mod focus_service;
+pub mod git_service;
 pub mod history_service;
+pub mod hook_service;
 pub mod llm;
+pub mod session_service;
+pub mod snippet_service;
+pub mod stats_cache_service;
 pub mod storage_service;
+pub mod task_service;
 pub mod weekly_service;
 
 // Re-export commonly used services (TODO: uncomment when implemented)
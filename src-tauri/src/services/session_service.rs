@@ -0,0 +1,456 @@
+// services/session_service.rs
+// Work sessions: start/pause/resume/end markers in the dump and per-session stats
+
+use regex::Regex;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::OnceLock;
+use time::OffsetDateTime;
+
+use crate::models::dump::HistoryState;
+use crate::models::hooks::{HookEvent, SessionHookData};
+use crate::models::session::WorkSession;
+use crate::services::history_service::{self, ensure_daily_file};
+use crate::services::{hook_service, weekly_service};
+use crate::utils::{current_local_time, format_time_with_seconds};
+
+/// Session marker written as a `## 세션…` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionMarker {
+    Start,
+    Pause,
+    Resume,
+    End,
+}
+
+impl SessionMarker {
+    /// Header label, e.g. `## 세션 일시정지: 제목 (HH:MM:SS)`
+    pub fn label(self) -> &'static str {
+        match self {
+            SessionMarker::Start => "세션",
+            SessionMarker::Pause => "세션 일시정지",
+            SessionMarker::Resume => "세션 재개",
+            SessionMarker::End => "세션 종료",
+        }
+    }
+
+    fn from_keyword(keyword: Option<&str>) -> Option<Self> {
+        match keyword {
+            None => Some(SessionMarker::Start),
+            Some("일시정지") => Some(SessionMarker::Pause),
+            Some("재개") => Some(SessionMarker::Resume),
+            Some("종료") => Some(SessionMarker::End),
+            Some(_) => None,
+        }
+    }
+}
+
+static MARKER_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_marker_regex() -> &'static Regex {
+    MARKER_REGEX.get_or_init(|| {
+        // "## 세션: 제목 (14:00:00)", "## 세션 일시정지: 제목 (15:10:00)"
+        Regex::new(r"^##\s*세션(?:\s+(\S+))?:\s*(.*?)\s*\((\d{1,2}):(\d{2}):(\d{2})\)\s*$").unwrap()
+    })
+}
+
+/// Parse "HH:MM:SS" into seconds since midnight
+fn clock_seconds(label: &str) -> Option<u32> {
+    let mut parts = label.split(':').map(|part| part.parse::<u32>().ok());
+    let (hour, minute, second) = (parts.next()??, parts.next()??, parts.next()??);
+    (hour < 24 && minute < 60 && second < 60).then_some(hour * 3600 + minute * 60 + second)
+}
+
+fn clock_label(seconds: u32) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Parse a marker header into (marker, title, seconds since midnight)
+pub fn parse_marker(line: &str) -> Option<(SessionMarker, String, u32)> {
+    let caps = get_marker_regex().captures(line.trim())?;
+    let marker = SessionMarker::from_keyword(caps.get(1).map(|m| m.as_str()))?;
+    let seconds = clock_seconds(&format!("{}:{}:{}", &caps[3], &caps[4], &caps[5]))?;
+    Some((marker, caps[2].to_string(), seconds))
+}
+
+/// Marker header line without surrounding blank lines
+pub fn marker_line(marker: SessionMarker, title: &str, time_label: &str) -> String {
+    format!("## {}: {} ({})", marker.label(), title, time_label)
+}
+
+/// Append a marker header to a dump file, returning its time label
+pub fn append_marker(
+    file_path: &Path,
+    marker: SessionMarker,
+    title: &str,
+) -> Result<String, String> {
+    let now = current_local_time()?;
+    let time_label = format_time_with_seconds(&now)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path)
+        .map_err(|e| format!("파일 열기 실패: {}", e))?;
+
+    writeln!(file, "\n{}\n", marker_line(marker, title, &time_label))
+        .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+
+    file.flush()
+        .map_err(|e| format!("파일 flush 실패: {}", e))?;
+
+    Ok(time_label)
+}
+
+/// Session being reconstructed while walking the dump
+struct OpenSession {
+    session: WorkSession,
+    /// Start of the current running segment
+    segment_start: u32,
+    paused_at: Option<u32>,
+    last_seen: u32,
+}
+
+impl OpenSession {
+    fn new(title: String, at: u32) -> Self {
+        Self {
+            session: WorkSession {
+                title,
+                started_at: clock_label(at),
+                ended_at: None,
+                active_seconds: 0,
+                paused_seconds: 0,
+                entry_count: 0,
+                paused: false,
+            },
+            segment_start: at,
+            paused_at: None,
+            last_seen: at,
+        }
+    }
+
+    fn pause(&mut self, at: u32) {
+        if self.paused_at.is_none() {
+            self.session.active_seconds += at.saturating_sub(self.segment_start) as i64;
+            self.paused_at = Some(at);
+        }
+    }
+
+    fn resume(&mut self, at: u32) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.session.paused_seconds += at.saturating_sub(paused_at) as i64;
+            self.segment_start = at;
+        }
+    }
+
+    /// Stop counting at `at`; `ended` is false for sessions still open at the end of the dump
+    fn close(mut self, at: u32, ended: bool) -> WorkSession {
+        match self.paused_at {
+            Some(paused_at) => {
+                self.session.paused_seconds += at.saturating_sub(paused_at) as i64;
+                self.session.paused = !ended;
+            }
+            None => self.session.active_seconds += at.saturating_sub(self.segment_start) as i64,
+        }
+        if ended {
+            self.session.ended_at = Some(clock_label(at));
+        }
+        self.session
+    }
+}
+
+/// Reconstruct sessions from a day's dump, in file order
+///
+/// Entries count toward the running session; entries written while paused or outside a session
+/// are not attributed. A new start implicitly ends the previous session. A session still open at
+/// the end of the dump runs until `now` (seconds since midnight) or its last entry.
+pub fn parse_sessions(dump_content: &str, now: Option<u32>) -> Vec<WorkSession> {
+    let mut sessions = Vec::new();
+    let mut current: Option<OpenSession> = None;
+
+    for line in dump_content.lines() {
+        if let Some((marker, title, at)) = parse_marker(line) {
+            match marker {
+                SessionMarker::Start => {
+                    if let Some(open) = current.take() {
+                        sessions.push(open.close(at, true));
+                    }
+                    current = Some(OpenSession::new(title, at));
+                }
+                SessionMarker::Pause => {
+                    if let Some(open) = current.as_mut() {
+                        open.pause(at);
+                        open.last_seen = at;
+                    }
+                }
+                SessionMarker::Resume => match current.as_mut() {
+                    Some(open) => {
+                        open.resume(at);
+                        open.last_seen = at;
+                    }
+                    None => current = Some(OpenSession::new(title, at)),
+                },
+                SessionMarker::End => {
+                    if let Some(open) = current.take() {
+                        sessions.push(open.close(at, true));
+                    }
+                }
+            }
+            continue;
+        }
+
+        let Some(open) = current.as_mut() else {
+            continue;
+        };
+        if let Some(at) = history_service::entry_time_label(line).and_then(clock_seconds) {
+            if open.paused_at.is_none() {
+                open.session.entry_count += 1;
+            }
+            open.last_seen = open.last_seen.max(at);
+        }
+    }
+
+    if let Some(open) = current {
+        let until = now.unwrap_or(open.last_seen).max(open.last_seen);
+        sessions.push(open.close(until, false));
+    }
+    sessions
+}
+
+/// The session still open at the end of the dump, if any
+pub fn current_session(dump_content: &str, now: Option<u32>) -> Option<WorkSession> {
+    parse_sessions(dump_content, now)
+        .pop()
+        .filter(|session| session.ended_at.is_none())
+}
+
+/// Sessions of a day; today's open session runs until now
+pub fn load_sessions(
+    state: &HistoryState,
+    date: &OffsetDateTime,
+) -> Result<Vec<WorkSession>, String> {
+    let content = weekly_service::load_daily_dump(state, date)?;
+    let now = current_local_time()?;
+    let live = (now.date() == date.date()).then(|| now_seconds(&now));
+    Ok(parse_sessions(&content, live))
+}
+
+/// Today's latest session with `title` (the one a marker was just written for)
+pub fn latest_session(state: &HistoryState, title: &str) -> Result<Option<WorkSession>, String> {
+    let sessions = load_sessions(state, &current_local_time()?)?;
+    Ok(sessions
+        .into_iter()
+        .rev()
+        .find(|session| session.title == title))
+}
+
+fn now_seconds(now: &OffsetDateTime) -> u32 {
+    now.hour() as u32 * 3600 + now.minute() as u32 * 60 + now.second() as u32
+}
+
+/// Write a marker to today's dump after checking it makes sense for the open session
+///
+/// `title` is required to start a session; other markers default to the open session's title.
+/// Returns the session title the marker was written for.
+pub fn record_marker(
+    state: &HistoryState,
+    marker: SessionMarker,
+    title: Option<&str>,
+) -> Result<String, String> {
+    let now = current_local_time()?;
+    let (file_path, date_key) = ensure_daily_file(state, &now)?;
+    let content = std::fs::read_to_string(&file_path).unwrap_or_default();
+    let open = current_session(&content, None);
+
+    let title = match (marker, &open) {
+        (SessionMarker::Start, _) => title
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+            .ok_or("세션 제목이 필요합니다")?
+            .to_string(),
+        (_, None) => return Err("진행 중인 세션이 없습니다".to_string()),
+        (SessionMarker::Pause, Some(session)) if session.paused => {
+            return Err(format!(
+                "'{}' 세션은 이미 일시정지 상태입니다",
+                session.title
+            ))
+        }
+        (SessionMarker::Resume, Some(session)) if !session.paused => {
+            return Err(format!(
+                "'{}' 세션은 일시정지 상태가 아닙니다",
+                session.title
+            ))
+        }
+        (_, Some(session)) => session.title.clone(),
+    };
+
+    // 이전 세션이 열려 있으면 새 세션 시작 전에 닫음
+    if let (SessionMarker::Start, Some(session)) = (marker, &open) {
+        append_marker(&file_path, SessionMarker::End, &session.title)?;
+    }
+    append_marker(&file_path, marker, &title)?;
+    tracing::debug!("세션 마커 기록: {} {}", marker.label(), title);
+
+    let ended = match (marker, &open) {
+        (SessionMarker::End, _) => Some(title.clone()),
+        (SessionMarker::Start, Some(session)) => Some(session.title.clone()),
+        _ => None,
+    };
+    if let Some(ended) = ended {
+        let content = std::fs::read_to_string(&file_path).unwrap_or_default();
+        let session = parse_sessions(&content, None)
+            .into_iter()
+            .rev()
+            .find(|session| session.title == ended && session.ended_at.is_some());
+        hook_service::fire(
+            HookEvent::SessionEnded,
+            &SessionHookData {
+                date: date_key,
+                title: ended,
+                session,
+            },
+        );
+    }
+    Ok(title)
+}
+
+/// Compact duration such as "2h40m" or "45m"
+pub fn format_duration(seconds: i64) -> String {
+    let minutes = seconds.max(0) / 60;
+    if minutes >= 60 {
+        format!("{}h{:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// One-line summary, e.g. "Phase 1 구현: 2h40m, 14 entries"
+pub fn format_session_summary(session: &WorkSession) -> String {
+    let mut summary = format!(
+        "{}: {}, {} {}",
+        session.title,
+        format_duration(session.active_seconds),
+        session.entry_count,
+        if session.entry_count == 1 {
+            "entry"
+        } else {
+            "entries"
+        }
+    );
+    if session.ended_at.is_none() {
+        summary.push_str(if session.paused {
+            " (일시정지)"
+        } else {
+            " (진행 중)"
+        });
+    }
+    summary
+}
+
+/// Session summary section appended to a dump before asking for feedback
+pub fn feedback_context(dump_content: &str) -> Option<String> {
+    let sessions = parse_sessions(dump_content, None);
+    if sessions.is_empty() {
+        return None;
+    }
+
+    let mut section = String::from("## 작업 세션 요약");
+    for session in &sessions {
+        section.push_str("\n- ");
+        section.push_str(&format_session_summary(session));
+    }
+    Some(section)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DUMP: &str = "# 2024-03-04
+
+## 세션: Phase 1 구현 (09:00:00)
+
+- 스키마 설계 (09:05:00)
+- API 초안 (10:30:00)
+
+## 세션 일시정지: Phase 1 구현 (11:00:00)
+
+- 점심 (12:00:00)
+
+## 세션 재개: Phase 1 구현 (12:30:00)
+
+- 테스트 작성 (13:10:00)
+
+## 세션 종료: Phase 1 구현 (14:10:00)
+
+- 메일 확인 (14:20:00)
+
+## 세션: 리뷰 (15:00:00)
+
+- PR 리뷰 (15:30:00)
+";
+
+    #[test]
+    fn parses_sessions_with_pauses_and_open_tail() {
+        let sessions = parse_sessions(DUMP, None);
+        assert_eq!(sessions.len(), 2);
+
+        let phase = &sessions[0];
+        assert_eq!(phase.title, "Phase 1 구현");
+        assert_eq!(phase.started_at, "09:00:00");
+        assert_eq!(phase.ended_at.as_deref(), Some("14:10:00"));
+        assert_eq!(phase.active_seconds, 2 * 3600 + 3600 + 40 * 60);
+        assert_eq!(phase.paused_seconds, 90 * 60);
+        assert_eq!(phase.entry_count, 3);
+        assert_eq!(
+            format_session_summary(phase),
+            "Phase 1 구현: 3h40m, 3 entries"
+        );
+
+        let review = &sessions[1];
+        assert_eq!(review.ended_at, None);
+        assert_eq!(review.active_seconds, 30 * 60);
+        assert_eq!(review.entry_count, 1);
+
+        let live = parse_sessions(DUMP, Some(16 * 3600));
+        assert_eq!(live[1].active_seconds, 3600);
+    }
+
+    #[test]
+    fn new_start_closes_previous_session() {
+        let dump = "## 세션: A (09:00:00)\n- a (09:10:00)\n## 세션: B (10:00:00)\n## 세션 일시정지: B (10:30:00)\n";
+        let sessions = parse_sessions(dump, None);
+        assert_eq!(sessions[0].ended_at.as_deref(), Some("10:00:00"));
+        assert_eq!(sessions[0].active_seconds, 3600);
+        assert!(sessions[1].paused);
+        assert_eq!(sessions[1].active_seconds, 30 * 60);
+        assert_eq!(
+            current_session(dump, None).map(|s| s.title),
+            Some("B".to_string())
+        );
+    }
+
+    #[test]
+    fn marker_lines_round_trip() {
+        for marker in [
+            SessionMarker::Start,
+            SessionMarker::Pause,
+            SessionMarker::Resume,
+            SessionMarker::End,
+        ] {
+            let line = marker_line(marker, "Phase 1: 구현", "09:30:05");
+            assert_eq!(
+                parse_marker(&line),
+                Some((marker, "Phase 1: 구현".to_string(), 9 * 3600 + 30 * 60 + 5))
+            );
+        }
+        assert_eq!(parse_marker("## 세션 메모: x (09:00:00)"), None);
+        assert_eq!(format_duration(9 * 60), "9m");
+    }
+}
@@ -0,0 +1,92 @@
+// services/snippet_service.rs
+// Text snippet expansion shared by the CLI input and the GUI overlay
+
+use crate::models::settings::Snippet;
+
+fn find_snippet<'a>(word: &str, snippets: &'a [Snippet]) -> Option<&'a Snippet> {
+    if word.is_empty() {
+        return None;
+    }
+    snippets.iter().find(|snippet| snippet.trigger == word)
+}
+
+/// Replace every whitespace-separated word that equals a trigger
+///
+/// When an expansion already ends with whitespace the separator after the trigger is
+/// dropped, so ";mtg 기획" becomes "회의: 기획" rather than "회의:  기획".
+pub fn expand_snippets(text: &str, snippets: &[Snippet]) -> String {
+    let mut expanded = String::with_capacity(text.len());
+    for segment in text.split_inclusive(char::is_whitespace) {
+        let word = segment.trim_end();
+        match find_snippet(word, snippets) {
+            Some(snippet) => {
+                expanded.push_str(&snippet.expansion);
+                if !snippet.expansion.ends_with(char::is_whitespace) {
+                    expanded.push_str(&segment[word.len()..]);
+                }
+            }
+            None => expanded.push_str(segment),
+        }
+    }
+    expanded
+}
+
+/// Trigger ending exactly at the cursor: returns its byte offset and the snippet
+pub fn trailing_trigger<'a>(
+    before_cursor: &str,
+    snippets: &'a [Snippet],
+) -> Option<(usize, &'a Snippet)> {
+    let start = before_cursor
+        .rfind(char::is_whitespace)
+        .map(|index| {
+            index
+                + before_cursor[index..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8)
+        })
+        .unwrap_or(0);
+    find_snippet(&before_cursor[start..], snippets).map(|snippet| (start, snippet))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippets() -> Vec<Snippet> {
+        vec![
+            Snippet {
+                trigger: ";mtg".to_string(),
+                expansion: "회의: ".to_string(),
+            },
+            Snippet {
+                trigger: ";pr".to_string(),
+                expansion: "PR 리뷰".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn expands_whole_words_only() {
+        let snippets = snippets();
+        assert_eq!(
+            expand_snippets(";mtg 주간 기획", &snippets),
+            "회의: 주간 기획"
+        );
+        assert_eq!(
+            expand_snippets("오전 ;pr 2건", &snippets),
+            "오전 PR 리뷰 2건"
+        );
+        assert_eq!(expand_snippets("a;mtg ;mtgx", &snippets), "a;mtg ;mtgx");
+    }
+
+    #[test]
+    fn finds_trigger_before_cursor() {
+        let snippets = snippets();
+        let (start, snippet) = trailing_trigger("오늘 ;mtg", &snippets).unwrap();
+        assert_eq!(start, "오늘 ".len());
+        assert_eq!(snippet.expansion, "회의: ");
+        assert!(trailing_trigger(";mtg ", &snippets).is_none());
+        assert!(trailing_trigger("", &snippets).is_none());
+    }
+}
@@ -0,0 +1,486 @@
+// services/stats_cache_service.rs
+// On-disk cache of per-day time statistics, invalidated by file changes and rule changes
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use time::OffsetDateTime;
+
+use crate::models::dump::HistoryState;
+use crate::models::settings::TimeTrackingSettings;
+use crate::models::weekly::TimeBreakdown;
+use crate::services::weekly_service::{self, compute_day_stats, DayStats};
+use crate::services::{category_service, task_service};
+use crate::utils::*;
+
+/// Bump when the parsing logic changes so old caches are discarded
+const CACHE_VERSION: u32 = 3;
+
+/// Cached statistics of one dump file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedDay {
+    modified_ms: u128,
+    size: u64,
+    content_hash: String,
+    categorized_time: HashMap<String, i64>,
+    time_breakdown: HashMap<String, TimeBreakdown>,
+    untracked_seconds: i64,
+    entry_count: usize,
+    /// Names, mtimes and sizes of the day's AI feedback files
+    feedback_stamp: String,
+    has_feedback: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsCacheFile {
+    version: u32,
+    directory: PathBuf,
+    /// Hash of category rules, classifications and time tracking settings
+    fingerprint: String,
+    days: HashMap<String, CachedDay>,
+}
+
+static STATS_CACHE: OnceLock<Mutex<Option<StatsCacheFile>>> = OnceLock::new();
+
+/// Get the stats cache file path
+pub fn get_cache_path() -> Result<PathBuf, String> {
+    let data_dir =
+        dirs::data_dir().ok_or_else(|| "데이터 디렉토리를 찾을 수 없습니다".to_string())?;
+    let app_data_dir = data_dir.join("hoego");
+    fs::create_dir_all(&app_data_dir).map_err(|e| format!("데이터 디렉토리 생성 실패: {}", e))?;
+    Ok(app_data_dir.join("stats_cache.json"))
+}
+
+fn fingerprint(settings: &TimeTrackingSettings) -> String {
+    fingerprint_of(&category_service::fingerprint(), settings)
+}
+
+fn fingerprint_of(categories: &str, settings: &TimeTrackingSettings) -> String {
+    let settings_json = serde_json::to_string(settings).unwrap_or_default();
+    let input = format!("{}|{}|{}", CACHE_VERSION, categories, settings_json);
+    hex::encode(Sha256::digest(input.as_bytes()))
+}
+
+fn modified_ms(metadata: &fs::Metadata) -> u128 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_millis())
+        .unwrap_or(0)
+}
+
+/// Identifies the current state of a day's feedback files
+fn feedback_stamp(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            match fs::metadata(path) {
+                Ok(metadata) => format!("{}:{}:{}", name, modified_ms(&metadata), metadata.len()),
+                Err(_) => format!("{}:-", name),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+fn content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+fn load_cache_file(path: &Path) -> StatsCacheFile {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache_file(path: &Path, cache: &StatsCacheFile) -> Result<(), String> {
+    let content =
+        serde_json::to_string(cache).map_err(|e| format!("통계 캐시 직렬화 실패: {}", e))?;
+    fs::write(path, content).map_err(|e| format!("통계 캐시 저장 실패: {}", e))
+}
+
+fn to_day_stats(date: &OffsetDateTime, date_key: String, cached: &CachedDay) -> DayStats {
+    DayStats {
+        date: *date,
+        date_key,
+        categorized_time: cached.categorized_time.clone(),
+        time_breakdown: cached.time_breakdown.clone(),
+        untracked_seconds: cached.untracked_seconds,
+        entry_count: cached.entry_count,
+        has_feedback: cached.has_feedback,
+    }
+}
+
+/// Statistics for each date, reusing cached results for unchanged dump files
+///
+/// `feedback` is the AI feedback index from [`weekly_service::feedback_index`].
+///
+/// A day is recomputed when its file's mtime or size changed and the content hash differs.
+/// Whether it has AI feedback is re-checked only when its feedback files change.
+/// The whole cache is dropped when the history directory, category rules, classifications
+/// or time tracking settings change.
+pub fn cached_day_stats(
+    history_state: &HistoryState,
+    dates: &[OffsetDateTime],
+    settings: &TimeTrackingSettings,
+    feedback: &HashMap<String, Vec<PathBuf>>,
+) -> Result<Vec<DayStats>, String> {
+    let cache_path = get_cache_path()?;
+    let current_fingerprint = fingerprint(settings);
+
+    let mut guard = STATS_CACHE
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .map_err(|e| format!("통계 캐시 잠금 실패: {}", e))?;
+    let cache = guard.get_or_insert_with(|| load_cache_file(&cache_path));
+
+    let refresh = refresh_days(
+        cache,
+        history_state,
+        dates,
+        settings,
+        current_fingerprint,
+        feedback,
+    )?;
+    if refresh.dirty {
+        save_cache_file(&cache_path, cache)?;
+        tracing::debug!("통계 캐시 갱신: {}일 재계산", refresh.recomputed);
+    }
+
+    Ok(refresh.days)
+}
+
+struct CacheRefresh {
+    days: Vec<DayStats>,
+    /// Days whose statistics were computed from the dump
+    recomputed: usize,
+    /// The cache changed and needs saving
+    dirty: bool,
+}
+
+fn refresh_days(
+    cache: &mut StatsCacheFile,
+    history_state: &HistoryState,
+    dates: &[OffsetDateTime],
+    settings: &TimeTrackingSettings,
+    current_fingerprint: String,
+    feedback: &HashMap<String, Vec<PathBuf>>,
+) -> Result<CacheRefresh, String> {
+    let mut dirty = false;
+    if cache.version != CACHE_VERSION
+        || cache.directory != history_state.directory
+        || cache.fingerprint != current_fingerprint
+    {
+        tracing::debug!("통계 캐시 무효화 (규칙/설정/경로 변경)");
+        *cache = StatsCacheFile {
+            version: CACHE_VERSION,
+            directory: history_state.directory.clone(),
+            fingerprint: current_fingerprint,
+            days: HashMap::new(),
+        };
+        dirty = true;
+    }
+
+    let mut days = Vec::with_capacity(dates.len());
+    let mut recomputed = 0;
+
+    for date in dates {
+        let date_key = format_date_key(date)?;
+        let file_path = history_state.directory.join(format!("{date_key}.md"));
+
+        let metadata = match fs::metadata(&file_path) {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                // No dump for this day
+                if cache.days.remove(&date_key).is_some() {
+                    dirty = true;
+                }
+                let mut day = compute_day_stats(date, "", settings)?;
+                day.has_feedback =
+                    weekly_service::load_indexed_feedback(feedback, &date_key).is_some();
+                days.push(day);
+                continue;
+            }
+        };
+        let modified_ms = modified_ms(&metadata);
+        let size = metadata.len();
+
+        let cached = match cache.days.entry(date_key.clone()) {
+            Entry::Occupied(entry)
+                if entry.get().modified_ms == modified_ms && entry.get().size == size =>
+            {
+                entry.into_mut()
+            }
+            entry => {
+                let content = fs::read_to_string(&file_path)
+                    .map_err(|e| format!("Failed to read dump file: {}", e))?;
+                let hash = content_hash(&content);
+                dirty = true;
+
+                match entry {
+                    // Touched but unchanged (e.g. saved without edits)
+                    Entry::Occupied(entry) if entry.get().content_hash == hash => {
+                        let cached = entry.into_mut();
+                        cached.modified_ms = modified_ms;
+                        cached.size = size;
+                        cached
+                    }
+                    entry => {
+                        let day = compute_day_stats(date, &content, settings)?;
+                        recomputed += 1;
+                        entry
+                            .insert_entry(CachedDay {
+                                modified_ms,
+                                size,
+                                content_hash: hash,
+                                categorized_time: day.categorized_time,
+                                time_breakdown: day.time_breakdown,
+                                untracked_seconds: day.untracked_seconds,
+                                entry_count: day.entry_count,
+                                feedback_stamp: String::new(),
+                                has_feedback: false,
+                            })
+                            .into_mut()
+                    }
+                }
+            }
+        };
+
+        let stamp = feedback_stamp(
+            feedback
+                .get(&date_key)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+        );
+        if cached.feedback_stamp != stamp {
+            cached.has_feedback =
+                weekly_service::load_indexed_feedback(feedback, &date_key).is_some();
+            cached.feedback_stamp = stamp;
+            dirty = true;
+        }
+        days.push(to_day_stats(date, date_key, cached));
+    }
+
+    Ok(CacheRefresh {
+        days,
+        recomputed,
+        dirty,
+    })
+}
+
+/// Drop the cache and recompute statistics for every dump file
+///
+/// Returns the number of days in the rebuilt cache.
+pub fn rebuild(
+    history_state: &HistoryState,
+    settings: &TimeTrackingSettings,
+) -> Result<usize, String> {
+    let cache_path = get_cache_path()?;
+
+    {
+        let mut guard = STATS_CACHE
+            .get_or_init(|| Mutex::new(None))
+            .lock()
+            .map_err(|e| format!("통계 캐시 잠금 실패: {}", e))?;
+        *guard = Some(StatsCacheFile::default());
+    }
+    if cache_path.exists() {
+        fs::remove_file(&cache_path).map_err(|e| format!("통계 캐시 삭제 실패: {}", e))?;
+    }
+
+    if !history_state.directory.exists() {
+        return Ok(0);
+    }
+
+    let dates = task_service::dump_date_keys(&history_state.directory)?
+        .iter()
+        .map(|key| parse_date_key(key))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    category_service::refresh()?;
+    cached_day_stats(
+        history_state,
+        &dates,
+        settings,
+        &weekly_service::feedback_index()?,
+    )?;
+
+    tracing::info!("통계 캐시 재생성 완료: {}일", dates.len());
+    Ok(dates.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    const DATE_KEY: &str = "20240304";
+
+    struct Fixture {
+        directory: tempfile::TempDir,
+        state: HistoryState,
+        cache: StatsCacheFile,
+        settings: TimeTrackingSettings,
+        fingerprint: String,
+        feedback: HashMap<String, Vec<PathBuf>>,
+    }
+
+    impl Fixture {
+        fn new(dump: &str) -> Self {
+            let directory = tempfile::tempdir().expect("tempdir");
+            let state = HistoryState {
+                directory: directory.path().to_path_buf(),
+            };
+            let settings = TimeTrackingSettings::default();
+            let fixture = Fixture {
+                fingerprint: fingerprint_of("rules:classifications", &settings),
+                directory,
+                state,
+                cache: StatsCacheFile::default(),
+                settings,
+                feedback: HashMap::new(),
+            };
+            fixture.write_dump(dump, 0);
+            fixture
+        }
+
+        fn dump_path(&self) -> PathBuf {
+            self.directory.path().join(format!("{DATE_KEY}.md"))
+        }
+
+        /// Write the dump with an mtime `offset_secs` past a fixed instant
+        fn write_dump(&self, content: &str, offset_secs: u64) {
+            fs::write(self.dump_path(), content).expect("write dump");
+            let modified =
+                SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000 + offset_secs);
+            fs::File::options()
+                .write(true)
+                .open(self.dump_path())
+                .and_then(|file| file.set_modified(modified))
+                .expect("set mtime");
+        }
+
+        fn refresh(&mut self) -> CacheRefresh {
+            let date = parse_date_key(DATE_KEY).expect("date");
+            refresh_days(
+                &mut self.cache,
+                &self.state,
+                &[date],
+                &self.settings,
+                self.fingerprint.clone(),
+                &self.feedback,
+            )
+            .expect("refresh")
+        }
+    }
+
+    #[test]
+    fn unchanged_days_come_from_the_cache() {
+        let mut fixture = Fixture::new("- 코딩 (09:00:00)\n");
+        let first = fixture.refresh();
+        assert_eq!((first.recomputed, first.dirty), (1, true));
+
+        let second = fixture.refresh();
+        assert_eq!((second.recomputed, second.dirty), (0, false));
+        assert_eq!(second.days[0].entry_count, 1);
+    }
+
+    #[test]
+    fn size_changes_recompute_the_day() {
+        let mut fixture = Fixture::new("- 코딩 (09:00:00)\n");
+        fixture.refresh();
+
+        fixture.write_dump("- 코딩 (09:00:00)\n- 점심 (12:00:00)\n", 0);
+        let refresh = fixture.refresh();
+        assert_eq!(refresh.recomputed, 1);
+        assert_eq!(refresh.days[0].entry_count, 2);
+    }
+
+    #[test]
+    fn mtime_changes_recompute_the_day_when_the_content_differs() {
+        let mut fixture = Fixture::new("- 코딩 (09:00:00)\n- 점심 (12:00:00)\n");
+        fixture.refresh();
+
+        // Same size, different content
+        fixture.write_dump("- 코딩 (10:00:00)\n- 점심 (12:00:00)\n", 60);
+        let edited = fixture.refresh();
+        assert_eq!(edited.recomputed, 1);
+        assert_eq!(edited.days[0].categorized_time["개발"], 2 * 3600);
+
+        // Saved without edits: only the recorded mtime moves
+        fixture.write_dump("- 코딩 (10:00:00)\n- 점심 (12:00:00)\n", 120);
+        let touched = fixture.refresh();
+        assert_eq!((touched.recomputed, touched.dirty), (0, true));
+        let cached = &fixture.cache.days[DATE_KEY];
+        assert_eq!(
+            cached.modified_ms,
+            modified_ms(&fs::metadata(fixture.dump_path()).unwrap())
+        );
+    }
+
+    #[test]
+    fn stale_hashes_recompute_the_day() {
+        let mut fixture = Fixture::new("- 코딩 (09:00:00)\n");
+        fixture.refresh();
+
+        let cached = fixture.cache.days.get_mut(DATE_KEY).unwrap();
+        cached.modified_ms = 0;
+        cached.content_hash = "stale".to_string();
+        cached.entry_count = 99;
+
+        let refresh = fixture.refresh();
+        assert_eq!(refresh.recomputed, 1);
+        assert_eq!(refresh.days[0].entry_count, 1);
+    }
+
+    #[test]
+    fn category_and_settings_changes_drop_the_cache() {
+        let mut fixture = Fixture::new("- 코딩 (09:00:00)\n");
+        fixture.refresh();
+
+        fixture.fingerprint = fingerprint_of("rules:reclassified", &fixture.settings);
+        assert_eq!(fixture.refresh().recomputed, 1);
+        assert_eq!(fixture.refresh().recomputed, 0);
+
+        fixture.settings.max_gap_minutes = Some(30);
+        fixture.fingerprint = fingerprint_of("rules:reclassified", &fixture.settings);
+        assert_eq!(fixture.refresh().recomputed, 1);
+        assert_eq!(fixture.refresh().recomputed, 0);
+    }
+
+    #[test]
+    fn feedback_is_rechecked_when_its_files_change() {
+        let mut fixture = Fixture::new("- 코딩 (09:00:00)\n");
+        assert!(!fixture.refresh().days[0].has_feedback);
+
+        let feedback_path = fixture
+            .directory
+            .path()
+            .join(format!("ai-feedback-{DATE_KEY}-210000.md"));
+        fs::write(&feedback_path, "잘했어요").unwrap();
+        fixture
+            .feedback
+            .insert(DATE_KEY.to_string(), vec![feedback_path.clone()]);
+
+        let added = fixture.refresh();
+        assert!(added.days[0].has_feedback);
+        assert_eq!((added.recomputed, added.dirty), (0, true));
+        assert!(!fixture.refresh().dirty);
+
+        fs::remove_file(&feedback_path).unwrap();
+        let removed = fixture.refresh();
+        assert!(!removed.days[0].has_feedback);
+        assert_eq!(removed.recomputed, 0);
+    }
+}
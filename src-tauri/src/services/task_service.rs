@@ -0,0 +1,327 @@
+// services/task_service.rs
+// Checkbox task parsing, toggling and day-to-day carry-over
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::models::dump::{HistoryState, TaskItem};
+use crate::utils::*;
+
+/// Section title used for tasks carried over from the previous day
+pub const CARRY_OVER_SECTION_TITLE: &str = "이월된 할 일";
+
+/// Split a trailing "(HH:MM:SS)" timestamp off an entry text
+fn split_timestamp(text: &str) -> (&str, Option<String>) {
+    let trimmed = text.trim_end();
+    if let (Some(start), true) = (trimmed.rfind('('), trimmed.ends_with(')')) {
+        let inner = &trimmed[start + 1..trimmed.len() - 1];
+        let parts: Vec<&str> = inner.split(':').collect();
+        if parts.len() == 3 && parts.iter().all(|p| p.len() == 2 && p.parse::<u32>().is_ok()) {
+            return (trimmed[..start].trim_end(), Some(inner.to_string()));
+        }
+    }
+    (trimmed, None)
+}
+
+/// Parse a single markdown line as a checkbox task
+///
+/// Returns (indent, done, rest-of-line) for `- [ ] ...`, `- [x] ...` and `* [ ] ...`
+fn parse_task_line(line: &str) -> Option<(usize, bool, &str)> {
+    let indent = line.len() - line.trim_start().len();
+    let body = line.trim_start();
+    let body = body.strip_prefix("- ").or_else(|| body.strip_prefix("* "))?;
+
+    let (done, rest) = if let Some(rest) = body.strip_prefix("[ ]") {
+        (false, rest)
+    } else if let Some(rest) = body
+        .strip_prefix("[x]")
+        .or_else(|| body.strip_prefix("[X]"))
+    {
+        (true, rest)
+    } else {
+        return None;
+    };
+
+    // "- [ ]" must be followed by whitespace or end of line
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some((indent, done, rest.trim()))
+}
+
+/// Parse all checkbox tasks from dump content
+pub fn parse_tasks(date_key: &str, content: &str) -> Vec<TaskItem> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(line_number, line)| {
+            let (indent, done, rest) = parse_task_line(line)?;
+            let (text, timestamp) = split_timestamp(rest);
+            if text.is_empty() {
+                return None;
+            }
+            Some(TaskItem {
+                date: date_key.to_string(),
+                line_number,
+                indent,
+                text: text.to_string(),
+                timestamp,
+                done,
+            })
+        })
+        .collect()
+}
+
+/// Flip the checkbox on a specific line and return the new content and task
+///
+/// `expected_text` guards against toggling a line that changed since it was listed.
+pub fn toggle_task_in_content(
+    date_key: &str,
+    content: &str,
+    line_number: usize,
+    expected_text: Option<&str>,
+) -> Result<(String, TaskItem), String> {
+    let mut lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
+    let line = lines
+        .get(line_number)
+        .ok_or_else(|| format!("할 일을 찾을 수 없습니다: {}번째 줄", line_number + 1))?;
+
+    let (indent, done, rest) =
+        parse_task_line(line).ok_or_else(|| format!("할 일 항목이 아닙니다: {}", line))?;
+    let (text, timestamp) = split_timestamp(rest);
+
+    if let Some(expected) = expected_text {
+        if expected.trim() != text {
+            return Err(format!(
+                "할 일 내용이 변경되었습니다. 다시 불러와 주세요: {}",
+                text
+            ));
+        }
+    }
+
+    // Checkbox always sits right after the "- " bullet
+    let checkbox_pos = indent + 2;
+    let toggled = format!(
+        "{}{}{}",
+        &line[..checkbox_pos],
+        if done { "[ ]" } else { "[x]" },
+        &line[checkbox_pos + 3..]
+    );
+
+    let task = TaskItem {
+        date: date_key.to_string(),
+        line_number,
+        indent,
+        text: text.to_string(),
+        timestamp,
+        done: !done,
+    };
+
+    lines[line_number] = toggled;
+    let mut new_content = lines.join("\n");
+    if content.ends_with('\n') {
+        new_content.push('\n');
+    }
+
+    Ok((new_content, task))
+}
+
+/// List tasks of a single day
+pub fn list_tasks(state: &HistoryState, date_key: &str) -> Result<Vec<TaskItem>, String> {
+    parse_date_key(date_key)?;
+    let file_path = state.directory.join(format!("{date_key}.md"));
+    if !file_path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&file_path)
+        .map_err(|error| format!("파일 읽기 실패: {error}, 경로: {:?}", file_path))?;
+    Ok(parse_tasks(date_key, &content))
+}
+
+/// Toggle a task in place and persist the dump file
+pub fn toggle_task(
+    state: &HistoryState,
+    date_key: &str,
+    line_number: usize,
+    expected_text: Option<&str>,
+) -> Result<TaskItem, String> {
+    parse_date_key(date_key)?;
+    let file_path = state.directory.join(format!("{date_key}.md"));
+    let content = fs::read_to_string(&file_path)
+        .map_err(|error| format!("파일 읽기 실패: {error}, 경로: {:?}", file_path))?;
+
+    let (new_content, task) = toggle_task_in_content(date_key, &content, line_number, expected_text)?;
+    fs::write(&file_path, new_content)
+        .map_err(|error| format!("파일 쓰기 실패: {error}, 경로: {:?}", file_path))?;
+
+    tracing::debug!("할 일 토글 완료: {} -> {}", task.text, task.done);
+    Ok(task)
+}
+
+/// Date keys of all dump files, newest first
+pub fn dump_date_keys(directory: &Path) -> Result<Vec<String>, String> {
+    let mut keys: Vec<String> = fs::read_dir(directory)
+        .map_err(|error| error.to_string())?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().to_string_lossy().into_owned();
+            let key = name.strip_suffix(".md")?;
+            parse_date_key(key).ok()?;
+            Some(key.to_string())
+        })
+        .collect();
+    keys.sort_by(|a, b| b.cmp(a));
+    Ok(keys)
+}
+
+/// List unfinished tasks across days, newest first
+///
+/// A task that appears on several days (e.g. after carry-over) is reported once,
+/// from its most recent day. If the most recent occurrence is done, it is dropped.
+pub fn list_open_tasks(state: &HistoryState, days: Option<usize>) -> Result<Vec<TaskItem>, String> {
+    if !state.directory.exists() {
+        return Ok(Vec::new());
+    }
+
+    let keys = dump_date_keys(&state.directory)?;
+    let limit = days.unwrap_or(keys.len());
+
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut open_tasks = Vec::new();
+
+    for key in keys.iter().take(limit) {
+        for task in list_tasks(state, key)? {
+            if !seen.insert(task.text.clone()) {
+                continue;
+            }
+            if !task.done {
+                open_tasks.push(task);
+            }
+        }
+    }
+
+    Ok(open_tasks)
+}
+
+/// Build the carry-over section for a newly created day
+///
+/// Looks up the most recent journaled day before `date_key` and collects its open tasks.
+/// Returns `None` when there is nothing to carry over.
+pub fn build_carry_over_section(directory: &Path, date_key: &str) -> Result<Option<String>, String> {
+    if !directory.exists() {
+        return Ok(None);
+    }
+
+    let previous_key = match dump_date_keys(directory)?
+        .into_iter()
+        .find(|key| key.as_str() < date_key)
+    {
+        Some(key) => key,
+        None => return Ok(None),
+    };
+
+    let content = fs::read_to_string(directory.join(format!("{previous_key}.md")))
+        .map_err(|error| format!("이전 일지 읽기 실패: {error}"))?;
+
+    let mut seen: HashSet<String> = HashSet::new();
+    let open: Vec<TaskItem> = parse_tasks(&previous_key, &content)
+        .into_iter()
+        .filter(|task| !task.done && seen.insert(task.text.clone()))
+        .collect();
+
+    if open.is_empty() {
+        return Ok(None);
+    }
+
+    let mut section = format!("## {}\n\n", CARRY_OVER_SECTION_TITLE);
+    for task in &open {
+        section.push_str(&format!("- [ ] {}\n", task.text));
+    }
+    section.push('\n');
+
+    tracing::debug!("이월된 할 일 {}개 ({} → {})", open.len(), previous_key, date_key);
+    Ok(Some(section))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "# 2024년 1월 2일 화요일\n\n- [ ] 배포 확인\n- [x] 리뷰 반영 (10:00:00)\n  - [ ] 하위 작업\n- 일반 항목 (11:00:00)\n- [ ]\n";
+
+    #[test]
+    fn parse_tasks_reads_checkboxes() {
+        let tasks = parse_tasks("20240102", SAMPLE);
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].text, "배포 확인");
+        assert!(!tasks[0].done);
+        assert_eq!(tasks[1].text, "리뷰 반영");
+        assert_eq!(tasks[1].timestamp.as_deref(), Some("10:00:00"));
+        assert!(tasks[1].done);
+        assert_eq!(tasks[2].indent, 2);
+        assert_eq!(tasks[2].line_number, 4);
+    }
+
+    #[test]
+    fn toggle_task_flips_state_and_keeps_rest() {
+        let (content, task) = toggle_task_in_content("20240102", SAMPLE, 2, Some("배포 확인")).unwrap();
+        assert!(task.done);
+        assert!(content.contains("- [x] 배포 확인\n"));
+        assert!(content.ends_with('\n'));
+
+        let (content, task) = toggle_task_in_content("20240102", &content, 3, None).unwrap();
+        assert!(!task.done);
+        assert!(content.contains("- [ ] 리뷰 반영 (10:00:00)"));
+    }
+
+    #[test]
+    fn toggle_task_rejects_stale_text() {
+        assert!(toggle_task_in_content("20240102", SAMPLE, 2, Some("다른 작업")).is_err());
+        assert!(toggle_task_in_content("20240102", SAMPLE, 5, None).is_err());
+    }
+
+    #[test]
+    fn list_tasks_rejects_paths_outside_the_journal() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = HistoryState {
+            directory: dir.path().join("dumps"),
+        };
+        fs::write(dir.path().join("secret.md"), "- [ ] 비밀\n").unwrap();
+
+        assert!(list_tasks(&state, "../secret").is_err());
+        assert!(list_tasks(&state, "20240102").unwrap().is_empty());
+    }
+
+    #[test]
+    fn open_tasks_and_carry_over_use_latest_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = HistoryState {
+            directory: dir.path().to_path_buf(),
+        };
+        fs::write(dir.path().join("20240101.md"), "- [ ] 배포 확인\n- [ ] 문서 정리\n").unwrap();
+        fs::write(dir.path().join("20240102.md"), SAMPLE).unwrap();
+        fs::write(dir.path().join("notes.md"), "- [ ] 무시\n").unwrap();
+
+        // 20240102의 최신 상태가 우선: "배포 확인"은 한 번만, 완료된 "리뷰 반영"은 제외
+        let open: Vec<String> = list_open_tasks(&state, None)
+            .unwrap()
+            .into_iter()
+            .map(|task| task.text)
+            .collect();
+        assert_eq!(open, vec!["배포 확인", "하위 작업", "문서 정리"]);
+        assert_eq!(list_open_tasks(&state, Some(1)).unwrap().len(), 2);
+
+        let section = build_carry_over_section(dir.path(), "20240103")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            section,
+            format!("## {}\n\n- [ ] 배포 확인\n- [ ] 하위 작업\n\n", CARRY_OVER_SECTION_TITLE)
+        );
+        // 이전 날짜가 없으면 이월하지 않음
+        assert!(build_carry_over_section(dir.path(), "20240101")
+            .unwrap()
+            .is_none());
+    }
+}
@@ -1,12 +1,21 @@
 // services/weekly_service.rs
 // Weekly data processing and aggregation service
 
+use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
-use time::{Duration, OffsetDateTime, Weekday};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use time::{Date, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday};
 
+use crate::models::category::ProductivityClass;
 use crate::models::dump::HistoryState;
-use crate::models::weekly::{AggregatedStats, DailyEntry, DailyTrend, ProductivityStats};
+use crate::models::settings::TimeTrackingSettings;
+use crate::models::weekly::{
+    AggregatedStats, CategoryDelta, DailyEntry, DailyTrend, PeriodComparison, PeriodData,
+    PeriodRollup, ProductivityStats, TimeBreakdown,
+};
+use crate::services::{category_service, focus_service, session_service, stats_cache_service};
 use crate::utils::*;
 
 /// Parse week start day from string
@@ -49,277 +58,482 @@ pub fn load_daily_dump(history_state: &HistoryState, date: &OffsetDateTime) -> R
     }
 }
 
-/// Load AI feedback files for a specific date
-pub fn load_ai_feedback(date: &OffsetDateTime) -> Result<Option<String>, String> {
+/// AI feedback files grouped by date key, scanned once per request
+pub fn feedback_index() -> Result<HashMap<String, Vec<PathBuf>>, String> {
     let summaries_dir = summaries_directory_path()?;
+    let mut index: HashMap<String, Vec<PathBuf>> = HashMap::new();
 
     if !summaries_dir.exists() {
-        return Ok(None);
+        return Ok(index);
     }
 
-    let date_key = date
-        .format(&time::macros::format_description!("[year][month][day]"))
-        .map_err(|e| e.to_string())?;
-
-    // Find all AI feedback files for this date
     let entries = fs::read_dir(&summaries_dir)
         .map_err(|e| format!("Failed to read summaries directory: {}", e))?;
 
-    let mut feedbacks = Vec::new();
-
     for entry in entries.flatten() {
         let filename = entry.file_name().to_string_lossy().to_string();
-        if filename.starts_with(&format!("ai-feedback-{}", date_key)) && filename.ends_with(".md") {
-            if let Ok(content) = fs::read_to_string(entry.path()) {
-                feedbacks.push(content);
-            }
+        // ai-feedback-YYYYMMDD-HHMMSS.md
+        let date_key = filename
+            .strip_prefix("ai-feedback-")
+            .filter(|_| filename.ends_with(".md"))
+            .and_then(|rest| rest.get(..8));
+        if let Some(date_key) = date_key {
+            index.entry(date_key.to_string()).or_default().push(entry.path());
         }
     }
 
+    for paths in index.values_mut() {
+        paths.sort();
+    }
+    Ok(index)
+}
+
+/// Load AI feedback for a date from a prebuilt index
+pub fn load_indexed_feedback(index: &HashMap<String, Vec<PathBuf>>, date_key: &str) -> Option<String> {
+    let feedbacks: Vec<String> = index
+        .get(date_key)?
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .collect();
+
     if feedbacks.is_empty() {
-        Ok(None)
+        None
     } else {
         // Combine all feedbacks for the day
-        Ok(Some(feedbacks.join("\n\n---\n\n")))
+        Some(feedbacks.join("\n\n---\n\n"))
     }
 }
 
-/// Entry with timestamp parsed from dump content
+/// Load AI feedback files for a specific date
+pub fn load_ai_feedback(date: &OffsetDateTime) -> Result<Option<String>, String> {
+    let date_key = format_date_key(date)?;
+    Ok(load_indexed_feedback(&feedback_index()?, &date_key))
+}
+
+/// Extract category from text using the active category rules and cached classifications
+fn extract_category(text: &str) -> String {
+    category_service::categorize(text).category
+}
+
+/// How the duration of a time block was determined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeSource {
+    /// Explicit duration, time range, start/stop markers or a focus timer block
+    Measured,
+    /// Guessed from the gap to the next entry
+    Inferred,
+}
+
+/// A categorized span of time reconstructed from dump entries
+#[derive(Debug, Clone)]
+pub struct TimeBlock {
+    pub text: String,
+    pub category: String,
+    /// Seconds since midnight
+    pub start_seconds: u32,
+    pub duration_seconds: i64,
+    pub source: TimeSource,
+}
+
+/// Explicit timing syntax found on an entry
+#[derive(Debug, Clone, PartialEq)]
+enum EntryKind {
+    /// No explicit timing - duration is inferred
+    Plain,
+    /// "(~45m)", "(~1h30m)"
+    Duration(u32),
+    /// "(14:00-15:30)" - start and end in seconds since midnight
+    Range(u32, u32),
+    /// "▶ 작업 시작"
+    Start,
+    /// "■ 작업 끝"
+    Stop,
+}
+
+/// Entry parsed from a dump line
 #[derive(Debug, Clone)]
 struct TimeEntry {
     text: String,
+    /// Seconds since midnight when this entry begins
     total_seconds: u32,
+    kind: EntryKind,
 }
 
-/// Parse a timestamp from format (HH:MM:SS)
-fn parse_timestamp(text: &str) -> Option<(u32, u32, u32)> {
-    // Find the last occurrence of (HH:MM:SS) pattern
-    let start = text.rfind('(')?;
-    let end = text.rfind(')')?;
+const START_MARKER: char = '▶';
+const STOP_MARKER: char = '■';
 
-    if start >= end {
-        return None;
-    }
+const DAY_SECONDS: u32 = 24 * 3600;
 
-    let timestamp_part = &text[start + 1..end];
-    let parts: Vec<&str> = timestamp_part.split(':').collect();
+/// Session header prefix written by the CLI (`## 세션: 제목 (HH:MM:SS)`)
+const SESSION_HEADER_PREFIX: &str = "## 세션";
 
-    if parts.len() != 3 {
-        return None;
-    }
+static TIMESTAMP_REGEX: OnceLock<Regex> = OnceLock::new();
+static DURATION_REGEX: OnceLock<Regex> = OnceLock::new();
+static RANGE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+fn get_timestamp_regex() -> &'static Regex {
+    TIMESTAMP_REGEX.get_or_init(|| {
+        // Trailing "(HH:MM:SS)" written by append_markdown_entry
+        Regex::new(r"\((\d{1,2}):(\d{2}):(\d{2})\)\s*$").unwrap()
+    })
+}
+
+fn get_duration_regex() -> &'static Regex {
+    DURATION_REGEX.get_or_init(|| {
+        // "(~45m)", "(~1h30m)", "(~2h)", "(~90min)"
+        Regex::new(r"\(~\s*(?:(\d+)\s*h)?\s*(?:(\d+)\s*m(?:in)?)?\s*\)").unwrap()
+    })
+}
+
+fn get_range_regex() -> &'static Regex {
+    RANGE_REGEX.get_or_init(|| {
+        // "(14:00-15:30)", "(09:15~10:00)", "(14:00:00-15:30:00)"
+        Regex::new(r"\((\d{1,2}):(\d{2})(?::(\d{2}))?\s*[-~–]\s*(\d{1,2}):(\d{2})(?::(\d{2}))?\)").unwrap()
+    })
+}
 
-    let hour = parts[0].parse::<u32>().ok()?;
-    let minute = parts[1].parse::<u32>().ok()?;
-    let second = parts[2].parse::<u32>().ok()?;
+/// Convert hour/minute/second captures to seconds since midnight
+fn clock_seconds(hour: &str, minute: &str, second: Option<&str>) -> Option<u32> {
+    let hour = hour.parse::<u32>().ok()?;
+    let minute = minute.parse::<u32>().ok()?;
+    let second = second.map(|s| s.parse::<u32>()).transpose().ok()?.unwrap_or(0);
 
     if hour >= 24 || minute >= 60 || second >= 60 {
         return None;
     }
 
-    Some((hour, minute, second))
+    Some(hour * 3600 + minute * 60 + second)
 }
 
-/// Extract category from text using keyword matching
-fn extract_category(text: &str) -> String {
-    let text_lower = text.to_lowercase();
-
-    // Work/개발 related keywords (highest priority - most specific)
-    if text_lower.contains("개발") || text_lower.contains("코딩") || text_lower.contains("프로그래밍")
-        || text_lower.contains("디버깅") || text_lower.contains("버그") || text_lower.contains("코드")
-        || text_lower.contains("구현") || text_lower.contains("리팩토링") || text_lower.contains("테스트")
-        || text_lower.contains("api") || text_lower.contains("ui") || text_lower.contains("레이아웃")
-        || text_lower.contains("컴포넌트") || text_lower.contains("기능") || text_lower.contains("빌드")
-        || text_lower.contains("배포") || text_lower.contains("에러") || text_lower.contains("수정")
-        || text_lower.contains("패널") || text_lower.contains("섹션") || text_lower.contains("추가")
-        || text_lower.contains("변경") || text_lower.contains("조정") || text_lower.contains("최적화")
-        || text_lower.contains("작업") || text_lower.contains("프로젝트") || text_lower.contains("앱")
-        || text_lower.contains("서비스") || text_lower.contains("웹") || text_lower.contains("모바일")
-        || text_lower.contains("css") || text_lower.contains("html") || text_lower.contains("javascript")
-        || text_lower.contains("typescript") || text_lower.contains("react") || text_lower.contains("tauri")
-        || text_lower.contains("rust") || text_lower.contains("프론트") || text_lower.contains("백엔드")
-        || text_lower.contains("db") || text_lower.contains("데이터베이스") || text_lower.contains("쿼리")
-        || text_lower.contains("디자인시스템") || text_lower.contains("와이어프레임")
-        || text_lower.contains("프로토타입") || text_lower.contains("mvp") {
-        return "개발".to_string();
-    }
-
-    // Meetings/회의 related keywords
-    if text_lower.contains("회의") || text_lower.contains("미팅") || text_lower.contains("논의")
-        || text_lower.contains("협의") || text_lower.contains("상의") || text_lower.contains("회의록")
-        || text_lower.contains("브리핑") || text_lower.contains("발표") {
-        return "회의".to_string();
-    }
-
-    // Planning/기획 related keywords
-    if text_lower.contains("기획") || text_lower.contains("계획") || text_lower.contains("설계")
-        || text_lower.contains("구상") || text_lower.contains("아이디어") || text_lower.contains("전략")
-        || text_lower.contains("로드맵") || text_lower.contains("스펙") || text_lower.contains("요구사항") {
-        return "기획".to_string();
-    }
-
-    // Study/학습 related keywords
-    if text_lower.contains("공부") || text_lower.contains("학습") || text_lower.contains("강의")
-        || text_lower.contains("책") || text_lower.contains("독서") || text_lower.contains("읽기")
-        || text_lower.contains("강좌") || text_lower.contains("튜토리얼") || text_lower.contains("문서")
-        || text_lower.contains("docs") || text_lower.contains("매뉴얼") || text_lower.contains("가이드")
-        || text_lower.contains("배우") || text_lower.contains("조사") || text_lower.contains("리서치")
-        || text_lower.contains("아티클") || text_lower.contains("글") || text_lower.contains("자료") {
-        return "학습".to_string();
-    }
-
-    // Exercise/운동 related keywords
-    if text_lower.contains("운동") || text_lower.contains("헬스") || text_lower.contains("달리기")
-        || text_lower.contains("러닝") || text_lower.contains("조깅") || text_lower.contains("산책")
-        || text_lower.contains("요가") || text_lower.contains("스트레칭") || text_lower.contains("근력")
-        || text_lower.contains("웨이트") || text_lower.contains("트레이닝") || text_lower.contains("수영")
-        || text_lower.contains("자전거") || text_lower.contains("등산") || text_lower.contains("필라테스")
-        || text_lower.contains("케이던스") || text_lower.contains("뛰") || text_lower.contains("걷") {
-        return "운동".to_string();
-    }
-
-    // Meal/식사 related keywords
-    if text_lower.contains("식사") || text_lower.contains("아침") || text_lower.contains("점심")
-        || text_lower.contains("저녁") || text_lower.contains("간식") || text_lower.contains("먹")
-        || text_lower.contains("밥") || text_lower.contains("음식") || text_lower.contains("요리")
-        || text_lower.contains("커피") || text_lower.contains("카페") || text_lower.contains("마시")
-        || text_lower.contains("물") || text_lower.contains("차") || text_lower.contains("음료")
-        || text_lower.contains("배고") || text_lower.contains("계란") || text_lower.contains("빵")
-        || text_lower.contains("샐러드") || text_lower.contains("고구마") || text_lower.contains("바나나") {
-        return "식사".to_string();
-    }
-
-    // Rest/휴식 related keywords
-    if text_lower.contains("휴식") || text_lower.contains("쉬") || text_lower.contains("낮잠")
-        || text_lower.contains("잠") || text_lower.contains("수면") || text_lower.contains("침대")
-        || text_lower.contains("눕") || text_lower.contains("누워") || text_lower.contains("취침")
-        || text_lower.contains("일어남") || text_lower.contains("기상") || text_lower.contains("자고")
-        || text_lower.contains("졸") {
-        return "휴식".to_string();
-    }
-
-    // Social/약속 related keywords
-    if text_lower.contains("약속") || text_lower.contains("친구") || text_lower.contains("만남")
-        || text_lower.contains("모임") || text_lower.contains("만나") || text_lower.contains("연락")
-        || text_lower.contains("톡") || text_lower.contains("전화") || text_lower.contains("메시지")
-        || text_lower.contains("엄마") || text_lower.contains("가족") {
-        return "약속".to_string();
-    }
-
-    // Entertainment/오락 related keywords
-    if text_lower.contains("게임") || text_lower.contains("유튜브") || text_lower.contains("sns")
-        || text_lower.contains("영상") || text_lower.contains("드라마") || text_lower.contains("영화")
-        || text_lower.contains("유튜브 돌림") || text_lower.contains("넷플릭스") || text_lower.contains("트위치")
-        || text_lower.contains("스트리밍") || text_lower.contains("기타") && text_lower.contains("연습")
-        || text_lower.contains("악기") || text_lower.contains("취미") {
-        return "오락".to_string();
-    }
-
-    // Personal care/개인관리 related keywords
-    if text_lower.contains("샤워") || text_lower.contains("세면") || text_lower.contains("화장")
-        || text_lower.contains("옷") || text_lower.contains("준비") || text_lower.contains("씻")
-        || text_lower.contains("세수") || text_lower.contains("양치") || text_lower.contains("면도")
-        || text_lower.contains("머리") || text_lower.contains("입고") {
-        return "개인관리".to_string();
-    }
-
-    // Commute/이동 related keywords
-    if text_lower.contains("이동") || text_lower.contains("출근") || text_lower.contains("퇴근")
-        || text_lower.contains("버스") || text_lower.contains("지하철") || text_lower.contains("운전")
-        || text_lower.contains("택시") || text_lower.contains("걷") && text_lower.contains("집")
-        || text_lower.contains("도착") || text_lower.contains("복귀") || text_lower.contains("귀가")
-        || text_lower.contains("집 나섬") || text_lower.contains("외출") {
-        return "이동".to_string();
-    }
-
-    // Thinking/사고 related keywords
-    if text_lower.contains("생각") || text_lower.contains("고민") || text_lower.contains("메모")
-        || text_lower.contains("기록") || text_lower.contains("정리") || text_lower.contains("떠오름")
-        || text_lower.contains("아이디어") && !text_lower.contains("개발")
-        || text_lower.contains("회고") || text_lower.contains("복기") {
-        return "사고".to_string();
+/// "(~Xh Ym)" in seconds, at most one day
+fn duration_seconds(hours: u32, minutes: u32) -> u32 {
+    hours
+        .checked_mul(3600)
+        .zip(minutes.checked_mul(60))
+        .and_then(|(h, m)| h.checked_add(m))
+        .map_or(DAY_SECONDS, |seconds| seconds.min(DAY_SECONDS))
+}
+
+/// Parse a trailing timestamp "(HH:MM:SS)"
+fn parse_timestamp(text: &str) -> Option<u32> {
+    let caps = get_timestamp_regex().captures(text)?;
+    clock_seconds(&caps[1], &caps[2], Some(&caps[3]))
+}
+
+/// Parse one "- ..." dump line into a time entry
+///
+/// Lines need a trailing timestamp, except time ranges which carry their own start.
+fn parse_time_entry(line: &str) -> Option<TimeEntry> {
+    let body = line.trim().strip_prefix("- ")?;
+    let timestamp = parse_timestamp(body);
+
+    let mut text = get_timestamp_regex().replace(body, "").to_string();
+    let mut kind = EntryKind::Plain;
+    let mut start = timestamp;
+
+    if let Some((_, intent, seconds)) = focus_service::parse_record_text(&text) {
+        // "🍅 작업명 25m 완료 (HH:MM:SS)" is stamped when the timer ended
+        let end = timestamp?;
+        kind = EntryKind::Range(end.saturating_sub(seconds), end);
+        start = Some(end.saturating_sub(seconds));
+        text = intent;
+    } else if let Some(caps) = get_range_regex().captures(&text) {
+        let range_start = clock_seconds(&caps[1], &caps[2], caps.get(3).map(|m| m.as_str()))?;
+        let mut range_end = clock_seconds(&caps[4], &caps[5], caps.get(6).map(|m| m.as_str()))?;
+        if range_end < range_start {
+            // Range crosses midnight
+            range_end += 24 * 3600;
+        }
+        kind = EntryKind::Range(range_start, range_end);
+        start = Some(range_start);
+        text = get_range_regex().replace(&text, "").to_string();
+    } else if let Some(caps) = get_duration_regex().captures(&text) {
+        // \d+ only fails to parse on overflow, which is clamped below anyway
+        let hours = caps.get(1).map(|m| m.as_str().parse::<u32>().unwrap_or(u32::MAX));
+        let minutes = caps.get(2).map(|m| m.as_str().parse::<u32>().unwrap_or(u32::MAX));
+        if hours.is_some() || minutes.is_some() {
+            kind = EntryKind::Duration(duration_seconds(hours.unwrap_or(0), minutes.unwrap_or(0)));
+            text = get_duration_regex().replace(&text, "").to_string();
+        }
+    }
+
+    let trimmed = text.trim();
+    if kind == EntryKind::Plain {
+        if let Some(rest) = trimmed.strip_prefix(START_MARKER) {
+            kind = EntryKind::Start;
+            text = rest.to_string();
+        } else if let Some(rest) = trimmed.strip_prefix(STOP_MARKER) {
+            kind = EntryKind::Stop;
+            text = rest.to_string();
+        }
     }
 
-    // Default category
-    "기타".to_string()
+    Some(TimeEntry {
+        text: text.trim().to_string(),
+        total_seconds: start?,
+        kind,
+    })
 }
 
-/// Parse categorized time from dump content
-/// Looks for patterns like "- task (HH:MM:SS)" and calculates time spent
-pub fn parse_categorized_time(dump_content: &str) -> HashMap<String, i64> {
-    let mut categories: HashMap<String, i64> = HashMap::new();
-    let mut entries: Vec<TimeEntry> = Vec::new();
+/// Normalize a start/stop marker title so "작업 시작" and "작업 끝" pair up
+fn marker_title(text: &str) -> String {
+    let mut title = text.trim();
+    for suffix in ["시작", "끝", "종료", "start", "stop", "end"] {
+        if let Some(rest) = title.strip_suffix(suffix) {
+            title = rest.trim_end();
+            break;
+        }
+    }
+    title.to_lowercase()
+}
 
-    // Parse all entries with timestamps
-    for line in dump_content.lines() {
-        let trimmed = line.trim();
+/// Seconds of [start, end) that are not covered by any of the (sorted) intervals
+fn uncovered_seconds(start: u32, end: u32, covered: &[(u32, u32)]) -> u32 {
+    let mut cursor = start;
+    let mut uncovered = 0;
 
-        // Look for lines starting with "-"
-        if !trimmed.starts_with("- ") {
+    for &(c_start, c_end) in covered {
+        if c_end <= cursor {
             continue;
         }
+        if c_start >= end {
+            break;
+        }
+        if c_start > cursor {
+            uncovered += c_start - cursor;
+        }
+        cursor = cursor.max(c_end);
+        if cursor >= end {
+            return uncovered;
+        }
+    }
 
-        // Try to parse timestamp
-        if let Some((hour, minute, second)) = parse_timestamp(trimmed) {
-            let total_seconds = hour * 3600 + minute * 60 + second;
+    uncovered + end.saturating_sub(cursor)
+}
 
-            // Extract text before the timestamp
-            if let Some(end_pos) = trimmed.rfind('(') {
-                let text = trimmed[2..end_pos].trim().to_string();
+/// Parse "HH:MM" from settings into seconds since midnight
+fn parse_clock(value: &str) -> Option<u32> {
+    let (hour, minute) = value.trim().split_once(':')?;
+    clock_seconds(hour, minute, None)
+}
 
-                entries.push(TimeEntry {
-                    text,
-                    total_seconds,
-                });
-            }
-        }
+/// Sleep window as intervals, extended past midnight so late tails are excluded too
+fn sleep_intervals(settings: &TimeTrackingSettings) -> Vec<(u32, u32)> {
+    let start = settings.sleep_start.as_deref().and_then(parse_clock);
+    let end = settings.sleep_end.as_deref().and_then(parse_clock);
+
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => vec![(start, end), (start + DAY_SECONDS, end + DAY_SECONDS)],
+        (Some(start), Some(end)) if start > end => vec![(0, end), (start, end + DAY_SECONDS)],
+        _ => Vec::new(),
     }
+}
+
+/// Timestamps of `## 세션:` headers
+fn session_boundaries(dump_content: &str) -> Vec<u32> {
+    let mut boundaries: Vec<u32> = dump_content
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with(SESSION_HEADER_PREFIX))
+        .filter_map(parse_timestamp)
+        .collect();
+    boundaries.sort_unstable();
+    boundaries
+}
+
+/// Time blocks of a day plus the time nobody accounted for
+#[derive(Debug, Clone, Default)]
+pub struct DayTime {
+    pub blocks: Vec<TimeBlock>,
+    /// Seconds between the first and last entry that were not credited to any category
+    /// (gap overflow, time after stop markers, session boundaries). Sleep is excluded.
+    pub untracked_seconds: i64,
+}
+
+/// Reconstruct categorized time blocks from dump content
+///
+/// Explicit timing always wins over inference:
+/// - `(~45m)` gives the entry a measured duration starting at its timestamp
+/// - `(14:00-15:30)` gives a measured time range
+/// - `▶ 작업 시작` ... `■ 작업 끝` measures the span between the two markers
+/// - `🍅 작업명 25m 완료` from the focus timer measures the block ending at its timestamp
+///
+/// Every other entry is credited with the gap until the next entry (or session header),
+/// minus measured blocks and the sleep window, capped at `max_gap_minutes` when set.
+/// The last entry gets `default_tail_minutes`.
+pub fn parse_day_time(dump_content: &str, settings: &TimeTrackingSettings) -> DayTime {
+    let mut entries: Vec<TimeEntry> = dump_content.lines().filter_map(parse_time_entry).collect();
 
     // Sort entries by time
     entries.sort_by_key(|e| e.total_seconds);
 
-    // Calculate time differences and categorize
-    for i in 0..entries.len() {
-        let current = &entries[i];
+    if entries.is_empty() {
+        return DayTime::default();
+    }
+
+    let mut blocks: Vec<TimeBlock> = Vec::new();
+    let mut measured: Vec<(u32, u32)> = Vec::new();
+    let mut plain: Vec<usize> = Vec::new();
+
+    // Pair start/stop markers; unmatched starts fall back to inference
+    let mut open_starts: Vec<usize> = Vec::new();
+    let mut paired: Vec<Option<u32>> = vec![None; entries.len()];
+    for (i, entry) in entries.iter().enumerate() {
+        match entry.kind {
+            EntryKind::Start => open_starts.push(i),
+            EntryKind::Stop => {
+                let title = marker_title(&entry.text);
+                let position = open_starts
+                    .iter()
+                    .rposition(|&s| marker_title(&entries[s].text) == title)
+                    .or_else(|| open_starts.len().checked_sub(1));
+                if let Some(position) = position {
+                    let start_index = open_starts.remove(position);
+                    paired[start_index] = Some(entry.total_seconds);
+                }
+            }
+            _ => {}
+        }
+    }
 
-        // Calculate duration until next entry (or end of day)
-        let duration_seconds = if i + 1 < entries.len() {
-            let next = &entries[i + 1];
-            (next.total_seconds - current.total_seconds) as i64
-        } else {
-            // Last entry: assume 1 hour duration (could be configurable)
-            3600i64
+    for (i, entry) in entries.iter().enumerate() {
+        let span = match (&entry.kind, paired[i]) {
+            (EntryKind::Duration(seconds), _) => {
+                Some((entry.total_seconds, entry.total_seconds.saturating_add(*seconds)))
+            }
+            (EntryKind::Range(start, end), _) => Some((*start, *end)),
+            (EntryKind::Start, Some(end)) => Some((entry.total_seconds, end)),
+            (EntryKind::Stop, _) => None,
+            _ => {
+                plain.push(i);
+                None
+            }
         };
 
-        // Extract category from text
-        let category = extract_category(&current.text);
+        if let Some((start, end)) = span {
+            measured.push((start, end));
+            blocks.push(TimeBlock {
+                text: entry.text.clone(),
+                category: extract_category(&entry.text),
+                start_seconds: start,
+                duration_seconds: end.saturating_sub(start) as i64,
+                source: TimeSource::Measured,
+            });
+        }
+    }
+
+    // Measured blocks and sleep are never credited to inferred entries
+    let mut excluded = measured.clone();
+    excluded.extend(sleep_intervals(settings));
+    excluded.sort();
+
+    let boundaries = if settings.session_boundaries {
+        session_boundaries(dump_content)
+    } else {
+        Vec::new()
+    };
 
-        // Add to categories
-        *categories.entry(category).or_insert(0) += duration_seconds;
+    let max_gap = settings
+        .max_gap_minutes
+        .map_or(u32::MAX, |minutes| minutes.saturating_mul(60));
+    let tail = settings.default_tail_minutes.saturating_mul(60).min(DAY_SECONDS);
+    let mut timeline_end = entries.iter().map(|e| e.total_seconds).max().unwrap_or(0);
+    let mut inferred_total: i64 = 0;
+
+    // Infer durations for plain entries from the gap to the next entry
+    for i in plain {
+        let current = &entries[i];
+        let next_start = entries[i + 1..]
+            .iter()
+            .map(|e| e.total_seconds)
+            .find(|&t| t > current.total_seconds);
+        let next_boundary = boundaries.iter().copied().find(|&t| t > current.total_seconds);
+
+        let end = match (next_start, next_boundary) {
+            (Some(next), Some(boundary)) => next.min(boundary),
+            (Some(next), None) => next,
+            (None, Some(boundary)) => boundary.min(current.total_seconds.saturating_add(tail)),
+            (None, None) => current.total_seconds.saturating_add(tail),
+        };
+        timeline_end = timeline_end.max(end);
+
+        let duration = uncovered_seconds(current.total_seconds, end, &excluded).min(max_gap);
+        if duration == 0 {
+            continue;
+        }
+        inferred_total += duration as i64;
+
+        blocks.push(TimeBlock {
+            text: current.text.clone(),
+            category: extract_category(&current.text),
+            start_seconds: current.total_seconds,
+            duration_seconds: duration as i64,
+            source: TimeSource::Inferred,
+        });
     }
 
-    categories
+    // Untracked = timeline - (measured ∪ sleep) - inferred
+    let timeline_start = entries[0].total_seconds;
+    timeline_end = measured.iter().map(|&(_, end)| end).fold(timeline_end, u32::max);
+    let timeline = timeline_end.saturating_sub(timeline_start);
+    let covered = timeline - uncovered_seconds(timeline_start, timeline_end, &excluded);
+    let untracked_seconds = (timeline as i64 - covered as i64 - inferred_total).max(0);
+
+    blocks.sort_by_key(|b| b.start_seconds);
+    DayTime {
+        blocks,
+        untracked_seconds,
+    }
 }
 
-/// Classify categories into productive vs waste
-pub fn classify_productivity(categories: &HashMap<String, i64>) -> ProductivityStats {
-    // Productive categories
-    let productive_categories = ["개발", "회의", "기획", "학습", "운동"];
+/// Reconstruct categorized time blocks from dump content (see [`parse_day_time`])
+pub fn parse_time_blocks(dump_content: &str, settings: &TimeTrackingSettings) -> Vec<TimeBlock> {
+    parse_day_time(dump_content, settings).blocks
+}
+
+/// Sum time blocks per category, split into measured and inferred seconds
+pub fn summarize_time_blocks(
+    blocks: &[TimeBlock],
+) -> (HashMap<String, i64>, HashMap<String, TimeBreakdown>) {
+    let mut totals: HashMap<String, i64> = HashMap::new();
+    let mut breakdown: HashMap<String, TimeBreakdown> = HashMap::new();
+
+    for block in blocks {
+        *totals.entry(block.category.clone()).or_insert(0) += block.duration_seconds;
+
+        let entry = breakdown.entry(block.category.clone()).or_default();
+        match block.source {
+            TimeSource::Measured => entry.measured_seconds += block.duration_seconds,
+            TimeSource::Inferred => entry.inferred_seconds += block.duration_seconds,
+        }
+    }
+
+    (totals, breakdown)
+}
 
-    // Non-productive/waste categories
-    let waste_categories = ["오락", "휴식"];
+/// Parse categorized time from dump content
+/// Looks for patterns like "- task (HH:MM:SS)" and calculates time spent
+pub fn parse_categorized_time(
+    dump_content: &str,
+    settings: &TimeTrackingSettings,
+) -> HashMap<String, i64> {
+    summarize_time_blocks(&parse_time_blocks(dump_content, settings)).0
+}
 
-    // Neutral categories (not counted in either): 식사, 개인관리, 이동, 약속, 사고, 기타
+/// Classify categories into productive vs waste
+pub fn classify_productivity(categories: &HashMap<String, i64>) -> ProductivityStats {
+    let matcher = category_service::current_matcher();
 
     let mut productive_seconds = 0i64;
     let mut waste_seconds = 0i64;
 
     for (category, seconds) in categories.iter() {
-        if productive_categories.contains(&category.as_str()) {
-            productive_seconds += seconds;
-        } else if waste_categories.contains(&category.as_str()) {
-            waste_seconds += seconds;
+        match matcher.productivity_of(category) {
+            ProductivityClass::Productive => productive_seconds += seconds,
+            ProductivityClass::Waste => waste_seconds += seconds,
+            // Neutral categories are not counted in either
+            ProductivityClass::Neutral => {}
         }
-        // Neutral categories are not counted in either
     }
 
     let total = productive_seconds + waste_seconds;
@@ -340,52 +554,598 @@ pub fn classify_productivity(categories: &HashMap<String, i64>) -> ProductivityS
     }
 }
 
-/// Build week data from daily entries
-pub fn build_week_data(
+/// Distinct entry texts in the given days that no rule or cached classification covers
+pub fn uncategorized_entries(
     history_state: &HistoryState,
-    week_dates: &[OffsetDateTime],
-) -> Result<(Vec<DailyEntry>, AggregatedStats), String> {
-    let mut daily_entries = Vec::new();
-    let mut total_categories: HashMap<String, i64> = HashMap::new();
-    let mut daily_trends = Vec::new();
+    dates: &[OffsetDateTime],
+) -> Result<Vec<String>, String> {
+    let mut seen = std::collections::HashSet::new();
+    let mut entries = Vec::new();
 
-    for date in week_dates {
+    for date in dates {
         let dump_content = load_daily_dump(history_state, date)?;
-        let ai_feedback = load_ai_feedback(date)?;
-        let categorized_time = parse_categorized_time(&dump_content);
+        for entry in dump_content.lines().filter_map(parse_time_entry) {
+            if entry.kind == EntryKind::Stop || entry.text.is_empty() {
+                continue;
+            }
+            if category_service::is_uncategorized(&entry.text)
+                && seen.insert(category_service::entry_hash(&entry.text))
+            {
+                entries.push(entry.text);
+            }
+        }
+    }
 
-        // TODO: Load retrospect content from localStorage backup or file
-        let retrospect_content = None;
+    Ok(entries)
+}
+
+/// Time statistics of a single day, the unit every period view is built from
+#[derive(Debug, Clone)]
+pub struct DayStats {
+    pub date: OffsetDateTime,
+    pub date_key: String,
+    pub categorized_time: HashMap<String, i64>,
+    pub time_breakdown: HashMap<String, TimeBreakdown>,
+    pub untracked_seconds: i64,
+    pub entry_count: usize,
+    pub has_feedback: bool,
+}
 
-        // Aggregate categories
-        for (category, seconds) in &categorized_time {
+/// Compute day statistics from dump content
+pub fn compute_day_stats(
+    date: &OffsetDateTime,
+    dump_content: &str,
+    settings: &TimeTrackingSettings,
+) -> Result<DayStats, String> {
+    let day_time = parse_day_time(dump_content, settings);
+    let (categorized_time, time_breakdown) = summarize_time_blocks(&day_time.blocks);
+
+    let entry_count = dump_content
+        .lines()
+        .filter_map(parse_time_entry)
+        .filter(|entry| entry.kind != EntryKind::Stop)
+        .count();
+
+    Ok(DayStats {
+        date: *date,
+        date_key: format_date_key(date)?,
+        categorized_time,
+        time_breakdown,
+        untracked_seconds: day_time.untracked_seconds,
+        entry_count,
+        has_feedback: false,
+    })
+}
+
+/// Load statistics for each day (cached on disk) and mark days with AI feedback
+pub fn collect_day_stats(
+    history_state: &HistoryState,
+    dates: &[OffsetDateTime],
+    settings: &TimeTrackingSettings,
+) -> Result<Vec<DayStats>, String> {
+    stats_cache_service::cached_day_stats(history_state, dates, settings, &feedback_index()?)
+}
+
+/// Aggregate day statistics into totals, productivity and a daily trend
+pub fn aggregate_days(days: &[DayStats]) -> AggregatedStats {
+    let mut total_categories: HashMap<String, i64> = HashMap::new();
+    let mut total_breakdown: HashMap<String, TimeBreakdown> = HashMap::new();
+    let mut total_untracked: i64 = 0;
+    let mut daily_trends = Vec::new();
+
+    for day in days {
+        for (category, seconds) in &day.categorized_time {
             *total_categories.entry(category.clone()).or_insert(0) += seconds;
         }
+        for (category, breakdown) in &day.time_breakdown {
+            let total = total_breakdown.entry(category.clone()).or_default();
+            total.measured_seconds += breakdown.measured_seconds;
+            total.inferred_seconds += breakdown.inferred_seconds;
+        }
+        total_untracked += day.untracked_seconds;
 
-        // Add to daily trend
-        let date_str = format_date_key(date)?;
         daily_trends.push(DailyTrend {
-            date: date_str.clone(),
-            categories: categorized_time.clone(),
+            date: day.date_key.clone(),
+            categories: day.categorized_time.clone(),
         });
+    }
+
+    // Calculate productivity stats
+    let productivity_vs_waste = classify_productivity(&total_categories);
+
+    AggregatedStats {
+        total_categories,
+        time_breakdown: total_breakdown,
+        untracked_seconds: total_untracked,
+        productivity_vs_waste,
+        daily_trend: daily_trends,
+    }
+}
+
+/// Pick up edits to the category rules file before aggregating
+fn refresh_categories() {
+    if let Err(error) = category_service::refresh() {
+        tracing::warn!("카테고리 규칙 불러오기 실패, 기존 규칙 사용: {}", error);
+    }
+}
+
+/// Build week data from daily entries
+pub fn build_week_data(
+    history_state: &HistoryState,
+    week_dates: &[OffsetDateTime],
+    settings: &TimeTrackingSettings,
+) -> Result<(Vec<DailyEntry>, AggregatedStats), String> {
+    refresh_categories();
+
+    let feedback = feedback_index()?;
+    // Stats come from the per-day cache; the dump itself is still returned for display
+    let days =
+        stats_cache_service::cached_day_stats(history_state, week_dates, settings, &feedback)?;
+    let mut daily_entries = Vec::with_capacity(days.len());
+
+    for day in &days {
+        let dump_content = load_daily_dump(history_state, &day.date)?;
+        let ai_feedback = load_indexed_feedback(&feedback, &day.date_key);
+
+        // TODO: Load retrospect content from localStorage backup or file
+        let retrospect_content = None;
+        let sessions = session_service::parse_sessions(&dump_content, None);
 
         daily_entries.push(DailyEntry {
-            date: date_str,
+            date: day.date_key.clone(),
             dump_content,
             ai_feedback,
             retrospect_content,
-            categorized_time,
+            categorized_time: day.categorized_time.clone(),
+            time_breakdown: day.time_breakdown.clone(),
+            untracked_seconds: day.untracked_seconds,
+            entry_count: day.entry_count,
+            calendar_events: Vec::new(),
+            sessions,
         });
     }
 
-    // Calculate productivity stats
-    let productivity_vs_waste = classify_productivity(&total_categories);
+    Ok((daily_entries, aggregate_days(&days)))
+}
 
-    let aggregated_stats = AggregatedStats {
-        total_categories,
-        productivity_vs_waste,
-        daily_trend: daily_trends,
+/// Period presets supported by the analytics views
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodPreset {
+    Week,
+    Month,
+    Year,
+    Custom,
+}
+
+/// Parse period preset from string
+pub fn parse_period_preset(preset: &str) -> Result<PeriodPreset, String> {
+    match preset.to_lowercase().as_str() {
+        "week" => Ok(PeriodPreset::Week),
+        "month" => Ok(PeriodPreset::Month),
+        "year" => Ok(PeriodPreset::Year),
+        "custom" => Ok(PeriodPreset::Custom),
+        _ => Err(format!("Invalid period preset: {}", preset)),
+    }
+}
+
+/// Parse a YYYY-MM-DD date into local midnight
+pub fn parse_iso_date(value: &str) -> Result<OffsetDateTime, String> {
+    let date = Date::parse(value, &time::macros::format_description!("[year]-[month]-[day]"))
+        .map_err(|e| format!("Invalid date format '{}': {}", value, e))?;
+    Ok(local_midnight(date))
+}
+
+/// Midnight of a date in the current local offset
+pub fn local_midnight(date: Date) -> OffsetDateTime {
+    date.with_time(Time::MIDNIGHT)
+        .assume_offset(UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC))
+}
+
+/// All days from start to end (inclusive)
+pub fn get_range_dates(start: &OffsetDateTime, end: &OffsetDateTime) -> Vec<OffsetDateTime> {
+    let days = (*end - *start).whole_days().max(0);
+    (0..=days).map(|i| *start + Duration::days(i)).collect()
+}
+
+fn month_bounds(year: i32, month: Month) -> Result<(Date, Date), String> {
+    let start = Date::from_calendar_date(year, month, 1).map_err(|e| e.to_string())?;
+    let next_year = if month == Month::December { year + 1 } else { year };
+    let next_start =
+        Date::from_calendar_date(next_year, month.next(), 1).map_err(|e| e.to_string())?;
+    Ok((start, next_start - Duration::days(1)))
+}
+
+/// Resolve the first and last day of the period containing `date`
+///
+/// For `Custom` the period runs from `date` to `end_date`.
+pub fn resolve_period(
+    preset: PeriodPreset,
+    date: &OffsetDateTime,
+    end_date: Option<&OffsetDateTime>,
+    week_start_day: Weekday,
+) -> Result<(OffsetDateTime, OffsetDateTime), String> {
+    match preset {
+        PeriodPreset::Week => {
+            let start = get_week_start(date, week_start_day);
+            Ok((start, start + Duration::days(6)))
+        }
+        PeriodPreset::Month => {
+            let (start, end) = month_bounds(date.year(), date.month())?;
+            Ok((local_midnight(start), local_midnight(end)))
+        }
+        PeriodPreset::Year => {
+            let (start, _) = month_bounds(date.year(), Month::January)?;
+            let (_, end) = month_bounds(date.year(), Month::December)?;
+            Ok((local_midnight(start), local_midnight(end)))
+        }
+        PeriodPreset::Custom => {
+            let end = end_date.ok_or_else(|| "사용자 지정 기간에는 종료일이 필요합니다".to_string())?;
+            if end < date {
+                return Err("종료일이 시작일보다 빠릅니다".to_string());
+            }
+            if (*end - *date).whole_days() > 366 * 5 {
+                return Err("기간은 최대 5년까지 조회할 수 있습니다".to_string());
+            }
+            Ok((*date, *end))
+        }
+    }
+}
+
+/// The previous equivalent period: last week, last calendar month, last year,
+/// or a range of the same length right before a custom range
+pub fn previous_period(
+    preset: PeriodPreset,
+    start: &OffsetDateTime,
+    end: &OffsetDateTime,
+) -> Result<(OffsetDateTime, OffsetDateTime), String> {
+    let previous_end = *start - Duration::days(1);
+    match preset {
+        PeriodPreset::Week => Ok((*start - Duration::days(7), previous_end)),
+        PeriodPreset::Month => {
+            let (month_start, _) = month_bounds(previous_end.year(), previous_end.month())?;
+            Ok((local_midnight(month_start), previous_end))
+        }
+        PeriodPreset::Year => {
+            let (year_start, _) = month_bounds(previous_end.year(), Month::January)?;
+            Ok((local_midnight(year_start), previous_end))
+        }
+        PeriodPreset::Custom => {
+            let length = (*end - *start).whole_days();
+            Ok((previous_end - Duration::days(length), previous_end))
+        }
+    }
+}
+
+/// Sum a group of days into a rollup
+pub fn rollup_days(days: &[DayStats]) -> PeriodRollup {
+    let (first, last) = match (days.first(), days.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return PeriodRollup::default(),
+    };
+
+    let matcher = category_service::current_matcher();
+    let mut rollup = PeriodRollup {
+        start_date: first.date_key.clone(),
+        end_date: last.date_key.clone(),
+        ..PeriodRollup::default()
     };
 
-    Ok((daily_entries, aggregated_stats))
+    for day in days {
+        for (category, seconds) in &day.categorized_time {
+            *rollup.categories.entry(category.clone()).or_insert(0) += seconds;
+            rollup.total_seconds += seconds;
+            match matcher.productivity_of(category) {
+                ProductivityClass::Productive => rollup.productive_seconds += seconds,
+                ProductivityClass::Waste => rollup.waste_seconds += seconds,
+                ProductivityClass::Neutral => {}
+            }
+        }
+        rollup.untracked_seconds += day.untracked_seconds;
+        rollup.entry_count += day.entry_count;
+        if day.has_feedback {
+            rollup.feedback_days += 1;
+        }
+    }
+
+    rollup
+}
+
+/// Group consecutive days into weeks starting on `week_start_day`
+pub fn rollup_weeks(days: &[DayStats], week_start_day: Weekday) -> Vec<PeriodRollup> {
+    let mut weeks = Vec::new();
+    let mut current: Vec<DayStats> = Vec::new();
+
+    for day in days {
+        if day.date.weekday() == week_start_day && !current.is_empty() {
+            weeks.push(rollup_days(&current));
+            current.clear();
+        }
+        current.push(day.clone());
+    }
+    if !current.is_empty() {
+        weeks.push(rollup_days(&current));
+    }
+
+    weeks
+}
+
+/// Per-category deltas, largest current categories first
+pub fn compare_categories(
+    current: &HashMap<String, i64>,
+    previous: &HashMap<String, i64>,
+) -> Vec<CategoryDelta> {
+    let mut categories: Vec<&String> = current.keys().chain(previous.keys()).collect();
+    categories.sort();
+    categories.dedup();
+
+    let mut deltas: Vec<CategoryDelta> = categories
+        .into_iter()
+        .map(|category| {
+            let current_seconds = current.get(category).copied().unwrap_or(0);
+            let previous_seconds = previous.get(category).copied().unwrap_or(0);
+            let delta_seconds = current_seconds - previous_seconds;
+            CategoryDelta {
+                category: category.clone(),
+                current_seconds,
+                previous_seconds,
+                delta_seconds,
+                delta_percentage: (previous_seconds > 0)
+                    .then(|| delta_seconds as f64 / previous_seconds as f64 * 100.0),
+            }
+        })
+        .collect();
+
+    deltas.sort_by(|a, b| {
+        b.current_seconds
+            .cmp(&a.current_seconds)
+            .then(b.previous_seconds.cmp(&a.previous_seconds))
+    });
+    deltas
+}
+
+/// Build statistics for any period and compare them with the previous equivalent period
+pub fn build_period_data(
+    history_state: &HistoryState,
+    preset: PeriodPreset,
+    start: &OffsetDateTime,
+    end: &OffsetDateTime,
+    week_start_day: Weekday,
+    settings: &TimeTrackingSettings,
+) -> Result<PeriodData, String> {
+    refresh_categories();
+
+    let days = collect_day_stats(history_state, &get_range_dates(start, end), settings)?;
+    let (previous_start, previous_end) = previous_period(preset, start, end)?;
+    let previous_days = collect_day_stats(
+        history_state,
+        &get_range_dates(&previous_start, &previous_end),
+        settings,
+    )?;
+
+    let aggregated_stats = aggregate_days(&days);
+    let previous_stats = aggregate_days(&previous_days);
+
+    let daily = days
+        .iter()
+        .map(|day| rollup_days(std::slice::from_ref(day)))
+        .collect();
+    let weekly = rollup_weeks(&days, week_start_day);
+
+    let current_productivity = &aggregated_stats.productivity_vs_waste;
+    let previous_productivity = previous_stats.productivity_vs_waste.clone();
+    let comparison = PeriodComparison {
+        previous_start_date: format_date_key(&previous_start)?,
+        previous_end_date: format_date_key(&previous_end)?,
+        category_deltas: compare_categories(
+            &aggregated_stats.total_categories,
+            &previous_stats.total_categories,
+        ),
+        productive_percentage_delta: current_productivity.productive_percentage
+            - previous_productivity.productive_percentage,
+        waste_percentage_delta: current_productivity.waste_percentage
+            - previous_productivity.waste_percentage,
+        previous_total_categories: previous_stats.total_categories,
+        previous_productivity,
+    };
+
+    let preset_name = match preset {
+        PeriodPreset::Week => "week",
+        PeriodPreset::Month => "month",
+        PeriodPreset::Year => "year",
+        PeriodPreset::Custom => "custom",
+    };
+
+    Ok(PeriodData {
+        preset: preset_name.to_string(),
+        start_date: format_date_key(start)?,
+        end_date: format_date_key(end)?,
+        daily,
+        weekly,
+        aggregated_stats,
+        comparison,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakdown(content: &str) -> HashMap<String, TimeBreakdown> {
+        summarize_time_blocks(&parse_time_blocks(content, &TimeTrackingSettings::default())).1
+    }
+
+    #[test]
+    fn infers_gap_until_next_entry() {
+        let content = "# 제목\n\n- 코딩 (09:00:00)\n- 점심 식사 (12:00:00)\n";
+        let time = parse_categorized_time(content, &TimeTrackingSettings::default());
+        assert_eq!(time.get("개발"), Some(&(3 * 3600)));
+        assert_eq!(time.get("식사"), Some(&3600));
+    }
+
+    #[test]
+    fn long_gap_is_capped_and_reported_as_untracked() {
+        let content = "- 코딩 (09:00:00)\n- 점심 식사 (13:00:00)\n";
+        let settings = TimeTrackingSettings {
+            max_gap_minutes: Some(120),
+            ..TimeTrackingSettings::default()
+        };
+        let day = parse_day_time(content, &settings);
+        let (time, _) = summarize_time_blocks(&day.blocks);
+        assert_eq!(time.get("개발"), Some(&(2 * 3600)));
+        // 09:00-13:00 gap minus the 2h cap
+        assert_eq!(day.untracked_seconds, 2 * 3600);
+    }
+
+    #[test]
+    fn huge_settings_do_not_overflow() {
+        let settings = TimeTrackingSettings {
+            max_gap_minutes: Some(u32::MAX),
+            default_tail_minutes: u32::MAX,
+            ..TimeTrackingSettings::default()
+        };
+        let day = parse_day_time("- 코딩 (09:00:00)\n- 점심 식사 (13:00:00)\n", &settings);
+        let (time, _) = summarize_time_blocks(&day.blocks);
+        assert_eq!(time.get("개발"), Some(&(4 * 3600)));
+        assert_eq!(time.get("식사"), Some(&(DAY_SECONDS as i64)));
+    }
+
+    #[test]
+    fn sleep_window_is_excluded_from_inference() {
+        let settings = TimeTrackingSettings {
+            sleep_start: Some("23:00".to_string()),
+            sleep_end: Some("07:00".to_string()),
+            ..TimeTrackingSettings::default()
+        };
+        let content = "- 유튜브 (22:30:00)\n";
+        let day = parse_day_time(content, &settings);
+        assert_eq!(day.blocks[0].duration_seconds, 30 * 60);
+        assert_eq!(day.untracked_seconds, 0);
+    }
+
+    #[test]
+    fn session_header_ends_inferred_gap() {
+        let content = "- 코딩 (09:00:00)\n\n## 세션: 오후 (10:00:00)\n\n- 회의 준비 (10:30:00)\n";
+        let settings = TimeTrackingSettings {
+            session_boundaries: true,
+            ..TimeTrackingSettings::default()
+        };
+        let day = parse_day_time(content, &settings);
+        assert_eq!(day.blocks[0].duration_seconds, 3600);
+        assert_eq!(day.untracked_seconds, 30 * 60);
+
+        let day = parse_day_time(content, &TimeTrackingSettings::default());
+        assert_eq!(day.blocks[0].duration_seconds, 90 * 60);
+        assert_eq!(day.untracked_seconds, 0);
+    }
+
+    #[test]
+    fn explicit_duration_is_measured() {
+        let content = "- 회의 (~45m) (10:00:00)\n- 코딩 (11:00:00)\n";
+        let split = breakdown(content);
+        assert_eq!(split["회의"].measured_seconds, 45 * 60);
+        assert_eq!(split["회의"].inferred_seconds, 0);
+        assert_eq!(split["개발"].inferred_seconds, 3600);
+
+        let content = "- 회의 (~1h30m) (10:00:00)\n";
+        assert_eq!(breakdown(content)["회의"].measured_seconds, 90 * 60);
+    }
+
+    #[test]
+    fn oversized_duration_is_clamped_to_a_day() {
+        let content = "- 회의 (~2000000h) (10:00:00)\n";
+        assert_eq!(breakdown(content)["회의"].measured_seconds, DAY_SECONDS as i64);
+
+        let content = "- 회의 (~99999999999m) (10:00:00)\n";
+        assert_eq!(breakdown(content)["회의"].measured_seconds, DAY_SECONDS as i64);
+    }
+
+    #[test]
+    fn time_range_does_not_need_timestamp() {
+        let content = "- 회의 (14:00-15:30)\n";
+        let blocks = parse_time_blocks(content, &TimeTrackingSettings::default());
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].start_seconds, 14 * 3600);
+        assert_eq!(blocks[0].duration_seconds, 90 * 60);
+        assert_eq!(blocks[0].source, TimeSource::Measured);
+    }
+
+    #[test]
+    fn start_stop_markers_override_inferred_gaps() {
+        let content = "- ▶ 개발 작업 시작 (09:00:00)\n- 커피 (09:30:00)\n- ■ 개발 작업 끝 (11:00:00)\n- 점심 (12:00:00)\n";
+        let split = breakdown(content);
+        assert_eq!(split["개발"].measured_seconds, 2 * 3600);
+        assert_eq!(split["식사"].inferred_seconds, 3600);
+    }
+
+    #[test]
+    fn inferred_gap_is_clipped_by_measured_block() {
+        let content = "- 코딩 (09:00:00)\n- 회의 (10:00-10:30)\n- 리팩토링 (11:00:00)\n";
+        let split = breakdown(content);
+        // 09:00-10:00 inferred, 11:00-12:00 inferred for the last entry
+        assert_eq!(split["개발"].inferred_seconds, 2 * 3600);
+        assert_eq!(split["회의"].measured_seconds, 30 * 60);
+    }
+
+    #[test]
+    fn focus_timer_block_is_measured_before_its_timestamp() {
+        let content = "- 코딩 (09:00:00)\n- ⚡ 방해: 전화 (09:40:00)\n- 🍅 API 설계 25m 완료 (09:55:00)\n- 점심 (11:00:00)\n";
+        let blocks = parse_time_blocks(content, &TimeTrackingSettings::default());
+        let focus = blocks.iter().find(|b| b.text == "API 설계").unwrap();
+        assert_eq!(focus.start_seconds, 9 * 3600 + 30 * 60);
+        assert_eq!(focus.duration_seconds, 25 * 60);
+        assert_eq!(focus.source, TimeSource::Measured);
+        // The interruption inside the block is only credited with the time after it
+        let interruption = blocks.iter().find(|b| b.text.contains("방해")).unwrap();
+        assert_eq!(interruption.duration_seconds, 65 * 60);
+        assert_eq!(blocks[0].duration_seconds, 30 * 60);
+    }
+
+    fn day(y: i32, m: Month, d: u8) -> OffsetDateTime {
+        Date::from_calendar_date(y, m, d).unwrap().midnight().assume_utc()
+    }
+
+    #[test]
+    fn month_and_year_periods_compare_with_previous_calendar_period() {
+        let date = day(2024, Month::March, 15);
+
+        let (start, end) = resolve_period(PeriodPreset::Month, &date, None, Weekday::Monday).unwrap();
+        assert_eq!((start.day(), end.day(), end.month()), (1, 31, Month::March));
+        let (prev_start, prev_end) = previous_period(PeriodPreset::Month, &start, &end).unwrap();
+        assert_eq!((prev_start.month(), prev_start.day()), (Month::February, 1));
+        assert_eq!(prev_end.day(), 29);
+
+        let (start, end) = resolve_period(PeriodPreset::Year, &date, None, Weekday::Monday).unwrap();
+        assert_eq!(get_range_dates(&start, &end).len(), 366);
+        let (prev_start, prev_end) = previous_period(PeriodPreset::Year, &start, &end).unwrap();
+        assert_eq!((prev_start.year(), prev_end.year(), prev_end.day()), (2023, 2023, 31));
+
+        let custom_end = day(2024, Month::March, 20);
+        let (start, end) =
+            resolve_period(PeriodPreset::Custom, &date, Some(&custom_end), Weekday::Monday).unwrap();
+        let (prev_start, prev_end) = previous_period(PeriodPreset::Custom, &start, &end).unwrap();
+        assert_eq!((prev_start.day(), prev_end.day()), (9, 14));
+    }
+
+    #[test]
+    fn rollups_and_deltas() {
+        let settings = TimeTrackingSettings::default();
+        let days: Vec<DayStats> = (4..=11)
+            .map(|d| compute_day_stats(&day(2024, Month::March, d), "- 코딩 (09:00:00)\n", &settings).unwrap())
+            .collect();
+
+        // 2024-03-04 is a Monday: 7 days + 1 day
+        let weeks = rollup_weeks(&days, Weekday::Monday);
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].categories["개발"], 7 * 3600);
+        assert_eq!(weeks[0].productive_seconds, 7 * 3600);
+        assert_eq!(weeks[1].start_date, "20240311");
+
+        let current = HashMap::from([("개발".to_string(), 11800), ("운동".to_string(), 3600)]);
+        let previous = HashMap::from([("개발".to_string(), 10000), ("오락".to_string(), 600)]);
+        let deltas = compare_categories(&current, &previous);
+        assert_eq!(deltas[0].category, "개발");
+        assert_eq!(deltas[0].delta_percentage, Some(18.0));
+        assert_eq!(deltas[1].delta_percentage, None);
+        assert_eq!(deltas[2].delta_seconds, -600);
+    }
 }
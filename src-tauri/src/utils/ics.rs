@@ -0,0 +1,486 @@
+// utils/ics.rs
+// Minimal iCalendar (RFC 5545) writer and reader
+
+use time::macros::format_description;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// Event written as a VEVENT
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsEvent {
+    pub uid: String,
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+    pub summary: String,
+    pub description: Option<String>,
+    pub categories: Vec<String>,
+}
+
+/// Escape TEXT values (backslash, semicolon, comma, newline)
+pub fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fold a content line at 75 octets without splitting UTF-8 characters
+pub fn fold_line(line: &str) -> String {
+    const LIMIT: usize = 75;
+
+    let mut folded = String::with_capacity(line.len() + line.len() / LIMIT * 3);
+    let mut line_octets = 0;
+    for c in line.chars() {
+        // Continuation lines start with a space, which counts towards the limit
+        if line_octets + c.len_utf8() > LIMIT {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+        folded.push(c);
+        line_octets += c.len_utf8();
+    }
+    folded
+}
+
+/// Format a date-time as UTC ("20240102T090000Z")
+pub fn format_utc(date: &OffsetDateTime) -> String {
+    date.to_offset(UtcOffset::UTC)
+        .format(&format_description!("[year][month][day]T[hour][minute][second]Z"))
+        .unwrap_or_default()
+}
+
+/// Serialize events into a VCALENDAR document with CRLF line endings
+pub fn write_calendar(calendar_name: &str, events: &[IcsEvent], stamp: &OffsetDateTime) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Hoego//Hoego Time Export//KO".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", escape_text(&event.uid)));
+        lines.push(format!("DTSTAMP:{}", format_utc(stamp)));
+        lines.push(format!("DTSTART:{}", format_utc(&event.start)));
+        lines.push(format!("DTEND:{}", format_utc(&event.end)));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        if !event.categories.is_empty() {
+            let categories: Vec<String> = event.categories.iter().map(|c| escape_text(c)).collect();
+            lines.push(format!("CATEGORIES:{}", categories.join(",")));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut output = String::new();
+    for line in lines {
+        output.push_str(&fold_line(&line));
+        output.push_str("\r\n");
+    }
+    output
+}
+
+/// DTSTART/DTEND value of an imported event
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IcsTime {
+    /// All-day date (`VALUE=DATE`)
+    Date(Date),
+    /// UTC date-time ("20240102T090000Z")
+    Utc(OffsetDateTime),
+    /// Floating or `TZID` date-time, taken as local wall-clock time
+    Local(PrimitiveDateTime),
+}
+
+impl IcsTime {
+    /// Wall-clock time in the given local offset (dates map to midnight)
+    pub fn to_local(self, offset: UtcOffset) -> PrimitiveDateTime {
+        match self {
+            IcsTime::Date(date) => date.midnight(),
+            IcsTime::Utc(date) => {
+                let local = date.to_offset(offset);
+                PrimitiveDateTime::new(local.date(), local.time())
+            }
+            IcsTime::Local(date) => date,
+        }
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(self, IcsTime::Date(_))
+    }
+
+    /// `None` when the result is outside the supported date range
+    fn checked_add(self, duration: Duration) -> Option<IcsTime> {
+        Some(match self {
+            IcsTime::Date(date) => IcsTime::Date(date.checked_add(duration)?),
+            IcsTime::Utc(date) => IcsTime::Utc(date.checked_add(duration)?),
+            IcsTime::Local(date) => IcsTime::Local(date.checked_add(duration)?),
+        })
+    }
+}
+
+/// VEVENT read from an imported calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedEvent {
+    pub uid: String,
+    pub summary: String,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub start: IcsTime,
+    /// Exclusive end; `None` when the event has neither DTEND nor DURATION
+    pub end: Option<IcsTime>,
+}
+
+/// Undo `escape_text`
+pub fn unescape_text(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Join folded continuation lines and drop empty ones
+pub fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in content.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let Some(rest) = raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(rest);
+                continue;
+            }
+        }
+        if !raw.is_empty() {
+            lines.push(raw.to_string());
+        }
+    }
+    lines
+}
+
+/// Property parameters as (upper-cased name, value)
+type Params = Vec<(String, String)>;
+
+/// Split "NAME;PARAM=x:value" into upper-cased name, parameters and value
+///
+/// Quoted parameter values may contain colons.
+fn split_content_line(line: &str) -> Option<(String, Params, &str)> {
+    let mut in_quotes = false;
+    let mut colon = None;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => {
+                colon = Some(index);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let colon = colon?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            Some((key.trim().to_ascii_uppercase(), value.trim_matches('"').to_string()))
+        })
+        .collect();
+    Some((name, params, value))
+}
+
+fn parse_time(params: &[(String, String)], value: &str) -> Option<IcsTime> {
+    let value = value.trim();
+    let is_date = params.iter().any(|(k, v)| k == "VALUE" && v.eq_ignore_ascii_case("DATE"))
+        || value.len() == 8;
+
+    let date = Date::parse(value.get(..8)?, &format_description!("[year][month][day]")).ok()?;
+    if is_date {
+        return Some(IcsTime::Date(date));
+    }
+
+    let clock = value.get(9..15)?;
+    let time = Time::parse(clock, &format_description!("[hour][minute][second]")).ok()?;
+    let date_time = PrimitiveDateTime::new(date, time);
+    if value.ends_with('Z') {
+        Some(IcsTime::Utc(date_time.assume_utc()))
+    } else {
+        // Without a timezone database TZID values are read as local time
+        Some(IcsTime::Local(date_time))
+    }
+}
+
+/// Parse a DURATION value ("PT1H30M", "P1D", "P2W")
+///
+/// `Ok(None)` for values that are not durations (ignored like before); `Err` for durations
+/// too large to represent.
+fn parse_duration(value: &str) -> Result<Option<Duration>, String> {
+    let overflow = || format!("DURATION이 너무 큽니다: {}", value.trim());
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let Some(value) = value.strip_prefix('P') else {
+        return Ok(None);
+    };
+
+    let mut total = Duration::ZERO;
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in value.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let Ok(amount) = number.parse::<i64>() else {
+                    // 자릿수가 너무 많은 숫자도 표현할 수 없는 기간
+                    return if number.is_empty() { Ok(None) } else { Err(overflow()) };
+                };
+                number.clear();
+                let unit_seconds: i64 = match (unit, in_time) {
+                    ('W', false) => 7 * 24 * 3600,
+                    ('D', false) => 24 * 3600,
+                    ('H', true) => 3600,
+                    ('M', true) => 60,
+                    ('S', true) => 1,
+                    _ => return Ok(None),
+                };
+                let seconds = amount.checked_mul(unit_seconds).ok_or_else(overflow)?;
+                total = total
+                    .checked_add(Duration::seconds(seconds))
+                    .ok_or_else(overflow)?;
+            }
+        }
+    }
+
+    Ok(Some(if negative { -total } else { total }))
+}
+
+#[derive(Default)]
+struct EventBuilder {
+    uid: Option<String>,
+    summary: Option<String>,
+    location: Option<String>,
+    description: Option<String>,
+    start: Option<IcsTime>,
+    end: Option<IcsTime>,
+    duration: Option<Duration>,
+    cancelled: bool,
+    /// Why the event cannot be used (e.g. an out-of-range DURATION)
+    error: Option<String>,
+}
+
+impl EventBuilder {
+    fn build(self) -> Option<ParsedEvent> {
+        if self.cancelled {
+            return None;
+        }
+        let start = self.start?;
+        let summary = self.summary.unwrap_or_default();
+        if let Some(error) = self.error {
+            tracing::warn!("일정 건너뜀 ({}): {}", summary, error);
+            return None;
+        }
+        let end = match (self.end, self.duration) {
+            (Some(end), _) => Some(end),
+            (None, Some(duration)) => {
+                let Some(end) = start.checked_add(duration) else {
+                    tracing::warn!("일정 건너뜀 ({}): 종료 시각이 범위를 벗어납니다", summary);
+                    return None;
+                };
+                Some(end)
+            }
+            (None, None) => None,
+        };
+        let uid = self.uid.unwrap_or_else(|| format!("{:?}-{}", start, summary));
+
+        Some(ParsedEvent {
+            uid,
+            summary,
+            location: self.location.filter(|l| !l.trim().is_empty()),
+            description: self.description.filter(|d| !d.trim().is_empty()),
+            start,
+            end,
+        })
+    }
+}
+
+/// Read the VEVENTs of an iCalendar document
+///
+/// Cancelled events are skipped and recurrence rules are not expanded, so only the
+/// first occurrence of a recurring event is returned.
+pub fn parse_calendar(content: &str) -> Vec<ParsedEvent> {
+    let mut events = Vec::new();
+    let mut current: Option<EventBuilder> = None;
+    // Depth of components nested inside the current VEVENT (e.g. VALARM)
+    let mut nested = 0usize;
+
+    for line in unfold_lines(content) {
+        let Some((name, params, value)) = split_content_line(&line) else {
+            continue;
+        };
+
+        match name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VEVENT") && current.is_none() => {
+                current = Some(EventBuilder::default());
+                nested = 0;
+            }
+            "BEGIN" if current.is_some() => nested += 1,
+            "END" if current.is_some() && nested > 0 => nested -= 1,
+            "END" if value.eq_ignore_ascii_case("VEVENT") => {
+                if let Some(event) = current.take().and_then(EventBuilder::build) {
+                    events.push(event);
+                }
+            }
+            _ => {
+                let Some(event) = current.as_mut().filter(|_| nested == 0) else {
+                    continue;
+                };
+                match name.as_str() {
+                    "UID" => event.uid = Some(value.trim().to_string()),
+                    "SUMMARY" => event.summary = Some(unescape_text(value).trim().to_string()),
+                    "LOCATION" => event.location = Some(unescape_text(value).trim().to_string()),
+                    "DESCRIPTION" => event.description = Some(unescape_text(value)),
+                    "DTSTART" => event.start = parse_time(&params, value),
+                    "DTEND" => event.end = parse_time(&params, value),
+                    "DURATION" => match parse_duration(value) {
+                        Ok(duration) => event.duration = duration,
+                        Err(error) => event.error = Some(error),
+                    },
+                    "STATUS" => event.cancelled = value.trim().eq_ignore_ascii_case("CANCELLED"),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(escape_text("회의; A, B\\C\n다음"), "회의\\; A\\, B\\\\C\\n다음");
+    }
+
+    #[test]
+    fn folds_long_lines_on_character_boundaries() {
+        let line = format!("SUMMARY:{}", "가".repeat(40));
+        let folded = fold_line(&line);
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= 75);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn writes_events_in_utc() {
+        let event = IcsEvent {
+            uid: "20240102-32400@hoego".to_string(),
+            start: datetime!(2024-01-02 09:00 +09:00),
+            end: datetime!(2024-01-02 10:30 +09:00),
+            summary: "코딩".to_string(),
+            description: None,
+            categories: vec!["개발".to_string()],
+        };
+        let ics = write_calendar("Hoego", &[event], &datetime!(2024-01-03 00:00 UTC));
+        assert!(ics.contains("DTSTART:20240102T000000Z\r\n"));
+        assert!(ics.contains("DTEND:20240102T013000Z\r\n"));
+        assert!(ics.contains("CATEGORIES:개발\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn parses_events_with_folding_and_nested_alarms() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:a@example.com\r\n\
+DTSTART:20240102T010000Z\r\n\
+DTEND:20240102T020000Z\r\n\
+SUMMARY:주간 회의\\, 기획\r\n\
+LOCATION:3층 회\r\n \
+의실\r\n\
+BEGIN:VALARM\r\n\
+DESCRIPTION:알림\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:b@example.com\r\n\
+DTSTART;TZID=\"Asia/Seoul\":20240102T140000\r\n\
+DURATION:PT1H30M\r\n\
+SUMMARY:1:1\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:c@example.com\r\n\
+DTSTART;VALUE=DATE:20240103\r\n\
+SUMMARY:휴가\r\n\
+STATUS:CANCELLED\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+        let events = parse_calendar(ics);
+        assert_eq!(events.len(), 2);
+
+        assert_eq!(events[0].summary, "주간 회의, 기획");
+        assert_eq!(events[0].location.as_deref(), Some("3층 회의실"));
+        assert_eq!(events[0].description, None);
+        let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+        assert_eq!(events[0].start.to_local(offset), datetime!(2024-01-02 10:00));
+
+        assert_eq!(events[1].start, IcsTime::Local(datetime!(2024-01-02 14:00)));
+        assert_eq!(events[1].end, Some(IcsTime::Local(datetime!(2024-01-02 15:30))));
+    }
+
+    #[test]
+    fn huge_durations_drop_the_event() {
+        assert_eq!(parse_duration("PT1H30M"), Ok(Some(Duration::minutes(90))));
+        assert_eq!(parse_duration("-P1D"), Ok(Some(Duration::days(-1))));
+        assert_eq!(parse_duration("1 hour"), Ok(None));
+        assert!(parse_duration("P1000000000000000W").is_err());
+        assert!(parse_duration("PT99999999999999999999S").is_err());
+
+        let event = |duration: &str| {
+            format!(
+                "BEGIN:VEVENT\r\nUID:x\r\nDTSTART:20240102T090000Z\r\nDURATION:{}\r\nEND:VEVENT\r\n",
+                duration
+            )
+        };
+        // 문법은 맞지만 날짜 범위를 넘는 기간
+        assert!(parse_calendar(&event("P1000000W")).is_empty());
+        assert!(parse_calendar(&event("P1000000000000000W")).is_empty());
+        assert_eq!(parse_calendar(&event("P1W")).len(), 1);
+    }
+
+    #[test]
+    fn round_trips_escaped_text() {
+        let text = "회의; A, B\\C\n다음";
+        assert_eq!(unescape_text(&escape_text(text)), text);
+    }
+}
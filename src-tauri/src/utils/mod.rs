@@ -2,6 +2,7 @@
 // Utility functions and helpers
 
 pub mod datetime;
+pub mod ics;
 pub mod link_preview;
 pub mod pii_masker;
 
@@ -12,6 +12,8 @@ import {
 } from '@/components/ui/select';
 import {
   getAllModelOptions,
+  getEndpointModelOptions,
+  getEndpointUrl,
   getSelectedModel,
   setSelectedModel,
 } from '@/lib/model-selection';
@@ -20,11 +22,18 @@ interface ModelSelectorProps {
   isDarkMode: boolean;
 }
 
+const getValueString = (option: ModelOption) => {
+  return `${option.type}:${option.id}${option.provider ? `:${option.provider}` : ''}`;
+};
+
 export const ModelSelector: React.FC<ModelSelectorProps> = ({ isDarkMode }) => {
   const [selectedModel, setSelectedModelState] =
     useState<SelectedModel>(getSelectedModel());
   const [modelOptions, setModelOptions] = useState<ModelOption[]>([]);
   const [loading, setLoading] = useState(true);
+  const [endpointUrl, setEndpointUrl] = useState(getEndpointUrl());
+  const [endpointError, setEndpointError] = useState<string | null>(null);
+  const [endpointLoading, setEndpointLoading] = useState(false);
 
   useEffect(() => {
     void loadModelOptions();
@@ -41,15 +50,29 @@ export const ModelSelector: React.FC<ModelSelectorProps> = ({ isDarkMode }) => {
     }
   };
 
+  // Replace the endpoint models with the ones served at the entered URL
+  const loadEndpointModels = async () => {
+    setEndpointLoading(true);
+    setEndpointError(null);
+    try {
+      const endpointOptions = await getEndpointModelOptions(endpointUrl.trim());
+      setModelOptions((options) => [
+        ...options.filter((opt) => opt.type !== 'endpoint'),
+        ...endpointOptions,
+      ]);
+      if (endpointOptions.length === 0) {
+        setEndpointError('서버에 모델이 없습니다');
+      }
+    } catch (error) {
+      setEndpointError(String(error));
+    } finally {
+      setEndpointLoading(false);
+    }
+  };
+
   const handleSelectModel = async (value: string) => {
-    // value format: "type:id:provider" or "type:id"
-    const [type, id, provider] = value.split(':');
-    const option = modelOptions.find(
-      (opt) =>
-        opt.id === id &&
-        opt.type === type &&
-        (opt.provider === provider || (!opt.provider && !provider))
-    );
+    // Endpoint model ids and URLs contain ':', so match the whole value string
+    const option = modelOptions.find((opt) => getValueString(opt) === value);
 
     if (option) {
       const newSelection: SelectedModel = {
@@ -57,6 +80,7 @@ export const ModelSelector: React.FC<ModelSelectorProps> = ({ isDarkMode }) => {
         modelId: option.id,
         provider: option.provider,
         displayName: option.displayName,
+        baseUrl: option.baseUrl,
       };
       await setSelectedModel(newSelection);
       setSelectedModelState(newSelection);
@@ -72,6 +96,7 @@ export const ModelSelector: React.FC<ModelSelectorProps> = ({ isDarkMode }) => {
 
   // Group models by type
   const localModels = modelOptions.filter((opt) => opt.type === 'local');
+  const endpointModels = modelOptions.filter((opt) => opt.type === 'endpoint');
   const cloudModels = modelOptions.filter((opt) => opt.type === 'cloud');
 
   // Group cloud models by provider
@@ -88,9 +113,6 @@ export const ModelSelector: React.FC<ModelSelectorProps> = ({ isDarkMode }) => {
     }
   });
 
-  const getValueString = (option: ModelOption) => {
-    return `${option.type}:${option.id}${option.provider ? `:${option.provider}` : ''}`;
-  };
 
   const currentValueString = currentOption ? getValueString(currentOption) : '';
 
@@ -149,6 +171,67 @@ export const ModelSelector: React.FC<ModelSelectorProps> = ({ isDarkMode }) => {
           </SelectGroup>
         )}
 
+        {/* Endpoint Section: models on an external OpenAI-compatible server */}
+        <SelectGroup>
+          <SelectLabel
+            className={`text-[9px] uppercase tracking-wider pl-2 ${
+              isDarkMode ? 'text-slate-500' : 'text-slate-400'
+            }`}
+          >
+            로컬 서버
+          </SelectLabel>
+          {endpointModels.map((option) => (
+            <SelectItem
+              key={getValueString(option)}
+              value={getValueString(option)}
+              hideIndicator
+              className={`pr-3 py-1.5 text-xs cursor-pointer ${
+                currentValueString === getValueString(option)
+                  ? isDarkMode
+                    ? 'bg-[#5c8a6c]/20 text-matcha-300'
+                    : 'bg-matcha-50 text-matcha-600'
+                  : ''
+              }`}
+            >
+              {option.displayName}
+            </SelectItem>
+          ))}
+          <div className="flex items-center gap-1 px-2 py-1">
+            <input
+              value={endpointUrl}
+              onChange={(e) => setEndpointUrl(e.target.value)}
+              // Keep the select's typeahead from taking the keystrokes
+              onKeyDown={(e) => {
+                e.stopPropagation();
+                if (e.key === 'Enter') void loadEndpointModels();
+              }}
+              placeholder="http://localhost:11434"
+              className={`h-6 w-[150px] rounded px-1.5 text-[11px] outline-none ${
+                isDarkMode
+                  ? 'bg-white/5 text-slate-300 placeholder:text-slate-600'
+                  : 'bg-slate-100 text-slate-600 placeholder:text-slate-400'
+              }`}
+            />
+            <button
+              type="button"
+              onClick={() => void loadEndpointModels()}
+              disabled={endpointLoading || !endpointUrl.trim()}
+              className={`h-6 rounded px-1.5 text-[11px] disabled:opacity-50 ${
+                isDarkMode
+                  ? 'text-slate-400 hover:text-slate-200'
+                  : 'text-slate-500 hover:text-slate-700'
+              }`}
+            >
+              {endpointLoading ? '…' : '불러오기'}
+            </button>
+          </div>
+          {endpointError && (
+            <div className="max-w-[200px] px-2 pb-1 text-[10px] text-red-400">
+              {endpointError}
+            </div>
+          )}
+        </SelectGroup>
+
         {/* TODO: Beta 테스트 후 클라우드 모델 활성화 */}
         {/* {localModels.length > 0 &&
           Object.keys(cloudModelsByProvider).length > 0 && <SelectSeparator />}
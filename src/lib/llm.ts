@@ -87,6 +87,53 @@ export interface NoteSummary {
   summary: string;
 }
 
+export type EngineStatus =
+  | 'starting'
+  | 'loading'
+  | 'ready'
+  | 'crashed'
+  | 'stopped';
+
+export interface EngineState {
+  status: EngineStatus;
+  port?: number | null;
+  pid?: number | null;
+  restarts: number;
+  error?: string | null;
+}
+
+export interface EngineLogLine {
+  timestamp: string; // RFC 3339
+  source: 'stdout' | 'stderr' | 'supervisor';
+  text: string;
+}
+
+export interface ModelProfile {
+  model_id: string; // GGUF file name without .gguf
+  max_context: number;
+  max_tokens: number;
+  cpu_threads?: number | null; // null: physical core count
+  gpu_layers: number;
+  temperature: number;
+  chat_template?: string | null;
+  jinja: boolean;
+  extra_args: string[];
+  customized: boolean; // false for shipped presets
+}
+
+export interface EngineConfig {
+  model: string;
+  max_context: number;
+  temperature: number;
+  max_tokens: number;
+  cpu_threads: number;
+  gpu_layers: number;
+  port?: number | null;
+  chat_template?: string | null;
+  jinja: boolean;
+  extra_args: string[];
+}
+
 // API Functions
 export const llmApi = {
   // Model Management
@@ -118,6 +165,42 @@ export const llmApi = {
     return invoke('llm_set_default_model', { modelId });
   },
 
+  // Local server status
+  async getEngineStatus(): Promise<EngineState> {
+    return invoke('llm_get_engine_status');
+  },
+
+  async getEngineLogs(limit?: number): Promise<EngineLogLine[]> {
+    return invoke('llm_get_engine_logs', { limit });
+  },
+
+  async getEngineConfig(): Promise<EngineConfig> {
+    return invoke('llm_get_engine_config');
+  },
+
+  // Model runtime profiles
+  async listModelProfiles(): Promise<ModelProfile[]> {
+    return invoke('llm_list_model_profiles');
+  },
+
+  async saveModelProfile(profile: ModelProfile): Promise<ModelProfile> {
+    return invoke('llm_save_model_profile', { profile });
+  },
+
+  async resetModelProfile(modelId: string): Promise<ModelProfile> {
+    return invoke('llm_reset_model_profile', { modelId });
+  },
+
+  // External OpenAI-compatible servers (Ollama, LM Studio)
+  async listEndpointModels(baseUrl: string): Promise<string[]> {
+    return invoke('llm_list_endpoint_models', { baseUrl });
+  },
+
+  // Rejects with the reason when the server is down or does not serve the model
+  async checkEndpoint(baseUrl: string, model: string): Promise<void> {
+    return invoke('llm_check_endpoint', { baseUrl, model });
+  },
+
   // Summarization
   async summarizeNote(request: SummaryRequest): Promise<SummaryResult> {
     return invoke('summarize_note', { request });
@@ -161,6 +244,14 @@ export const llmApi = {
       callback(event.payload);
     });
   },
+
+  onStatusChanged(
+    callback: (state: EngineState) => void
+  ): Promise<() => void> {
+    return listen<EngineState>('llm_status_changed', (event) => {
+      callback(event.payload);
+    });
+  },
 };
 
 // Helper functions
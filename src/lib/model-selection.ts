@@ -10,6 +10,8 @@ import type { SelectedModel, ModelOption } from '@/types/model-selection';
 
 import {
   SELECTED_MODEL_KEY,
+  ENDPOINT_URL_KEY,
+  DEFAULT_ENDPOINT_URL,
   DEFAULT_MODEL,
   // CLOUD_MODELS, // TODO: Beta 후 활성화
 } from '@/types/model-selection';
@@ -51,6 +53,7 @@ export async function setSelectedModel(model: SelectedModel): Promise<void> {
         modelId: model.modelId,
         provider: model.provider || null,
         displayName: model.displayName || null,
+        baseUrl: model.baseUrl || null,
       },
     });
   } catch (error) {
@@ -59,7 +62,38 @@ export async function setSelectedModel(model: SelectedModel): Promise<void> {
 }
 
 /**
- * Get all available model options (local + cloud)
+ * Endpoint URL to list models from: the selected endpoint, else the last one entered
+ */
+export function getEndpointUrl(): string {
+  const selected = getSelectedModel();
+  if (selected.type === 'endpoint' && selected.baseUrl) {
+    return selected.baseUrl;
+  }
+  return localStorage.getItem(ENDPOINT_URL_KEY) || DEFAULT_ENDPOINT_URL;
+}
+
+/**
+ * Models served by an OpenAI-compatible server (Ollama, LM Studio)
+ * Rejects when the server cannot be reached
+ */
+export async function getEndpointModelOptions(
+  baseUrl: string
+): Promise<ModelOption[]> {
+  const models = await llmApi.listEndpointModels(baseUrl);
+  localStorage.setItem(ENDPOINT_URL_KEY, baseUrl);
+
+  return models.map((id): ModelOption => ({
+    id,
+    type: 'endpoint',
+    displayName: id,
+    description: `로컬 서버 • ${baseUrl}`,
+    isAvailable: true,
+    baseUrl,
+  }));
+}
+
+/**
+ * Get all available model options (local + endpoint + cloud)
  */
 export async function getAllModelOptions(): Promise<ModelOption[]> {
   const options: ModelOption[] = [];
@@ -77,6 +111,17 @@ export async function getAllModelOptions(): Promise<ModelOption[]> {
       });
     }
 
+    // Models on the remembered endpoint; skipped quietly while the server is down
+    const endpointUrl = localStorage.getItem(ENDPOINT_URL_KEY);
+    const selected = getSelectedModel();
+    if (endpointUrl || selected.type === 'endpoint') {
+      try {
+        options.push(...(await getEndpointModelOptions(getEndpointUrl())));
+      } catch (error) {
+        console.warn('[Model Selection] Endpoint unavailable:', error);
+      }
+    }
+
     // TODO: Beta 테스트 후 클라우드 모델 활성화
     // const providers = ['openai', 'gemini'] as const;
     //
@@ -141,6 +186,17 @@ function formatBytes(bytes: number): string {
 export async function autoSelectModel(): Promise<SelectedModel> {
   const current = getSelectedModel();
 
+  // Keep an endpoint selection while the server answers
+  if (current.type === 'endpoint' && current.baseUrl) {
+    try {
+      await llmApi.checkEndpoint(current.baseUrl, current.modelId);
+      await setSelectedModel(current);
+      return current;
+    } catch (error) {
+      console.warn('[Model Selection] Endpoint unavailable:', error);
+    }
+  }
+
   // If already selected and valid, return it
   const currentOption = await getSelectedModelOption();
   if (currentOption && currentOption.isAvailable) {
@@ -157,6 +213,7 @@ export async function autoSelectModel(): Promise<SelectedModel> {
       modelId: firstAvailable.id,
       provider: firstAvailable.provider,
       displayName: firstAvailable.displayName,
+      baseUrl: firstAvailable.baseUrl,
     };
     void setSelectedModel(newSelection);
     return newSelection;
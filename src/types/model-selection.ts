@@ -3,13 +3,14 @@
  * Manages both local and cloud LLM model selection
  */
 
-export type ModelType = 'local' | 'cloud';
+export type ModelType = 'local' | 'endpoint' | 'cloud';
 
 export interface SelectedModel {
   type: ModelType;
-  modelId: string; // local: model file ID, cloud: "gpt-4-turbo", "gemini-pro", etc.
+  modelId: string; // local: model file ID, endpoint: model name on the server, cloud: "gpt-4-turbo", "gemini-pro", etc.
   provider?: string; // Only for cloud: "openai", "gemini"
   displayName?: string; // Human-readable name for UI
+  baseUrl?: string; // Only for endpoint: OpenAI-compatible server, e.g. "http://localhost:11434"
 }
 
 export interface ModelOption {
@@ -18,12 +19,19 @@ export interface ModelOption {
   displayName: string;
   provider?: string;
   description?: string;
-  isAvailable: boolean; // true if model is downloaded (local) or API key exists (cloud)
+  isAvailable: boolean; // true if model is downloaded (local), served (endpoint) or API key exists (cloud)
+  baseUrl?: string; // Only for endpoint
 }
 
 // Storage key for selected model
 export const SELECTED_MODEL_KEY = 'hoego_selected_model';
 
+// Storage key for the last endpoint URL entered in the selector
+export const ENDPOINT_URL_KEY = 'hoego_endpoint_url';
+
+// Ollama's default address
+export const DEFAULT_ENDPOINT_URL = 'http://localhost:11434';
+
 // Default model configuration
 export const DEFAULT_MODEL: SelectedModel = {
   type: 'local',
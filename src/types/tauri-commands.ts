@@ -33,6 +33,25 @@ export interface TodayMarkdown {
   headerTitle: string;
   filePath: string;
   content: string;
+  calendarEvents: CalendarEvent[];
+}
+
+export interface CalendarEvent {
+  uid: string;
+  title: string;
+  location?: string | null;
+  date: string; // YYYYMMDD
+  startTime?: string | null; // "HH:MM", null for all-day events
+  endTime?: string | null; // "HH:MM"
+  allDay: boolean;
+  source: string; // calendar file name
+}
+
+export interface CalendarSource {
+  name: string;
+  path: string;
+  eventCount: number;
+  watched: boolean;
 }
 
 // ============================================================================
@@ -51,6 +70,123 @@ export interface WindowPositionPayload {
 export interface AppSettings {
   quickNoteShortcut: string;
   documentsPath: string;
+  carryOverTasks?: boolean;
+  timeTracking?: TimeTrackingSettings;
+  llmCategorization?: boolean;
+  calendar?: CalendarSettings;
+  git?: GitSettings;
+  snippets?: Snippet[];
+  llmServerPort?: number | null; // bundled llama-server port, free port when unset
+  llmEndpoint?: LlmEndpointSettings | null; // external server used instead of llama-server
+}
+
+export interface LlmEndpointSettings {
+  baseUrl: string; // e.g. "http://localhost:11434"
+  model: string; // model name on the server
+}
+
+export interface Snippet {
+  trigger: string; // e.g. ";mtg"
+  expansion: string; // e.g. "회의: "
+}
+
+export interface GitSettings {
+  repositories: string[];
+  author?: string | null; // git --author pattern
+  syncIntervalMinutes: number; // 0 disables the background import
+}
+
+export interface GitCommit {
+  hash: string;
+  repo: string;
+  subject: string;
+  date: string; // YYYYMMDD
+  time: string; // HH:MM:SS
+}
+
+export interface GitSyncResult {
+  date: string; // YYYYMMDD
+  added: GitCommit[];
+  skipped: number;
+  errors: string[];
+}
+
+export interface WorkSession {
+  title: string;
+  startedAt: string; // HH:MM:SS
+  endedAt?: string | null; // HH:MM:SS, null while open
+  activeSeconds: number; // excluding pauses
+  pausedSeconds: number;
+  entryCount: number;
+  paused: boolean;
+}
+
+export type FocusPhase = 'focus' | 'break';
+
+export interface FocusInterruption {
+  at: string; // HH:MM:SS
+  note?: string | null;
+}
+
+export interface FocusTimer {
+  phase: FocusPhase;
+  intent: string; // "휴식" for breaks
+  plannedSeconds: number;
+  startedAt: number; // unix seconds
+  endsAt: number; // unix seconds
+  interruptions: FocusInterruption[];
+}
+
+/** Payload of the `focus_timer_completed` event and `stop_focus_timer` */
+export interface FocusRecord {
+  phase: FocusPhase;
+  intent: string;
+  minutes: number;
+  completed: boolean; // false when stopped early
+  interruptions: number;
+  line: string; // e.g. "- 🍅 작업명 25m 완료 (14:25:00)"
+}
+
+export type HookEvent =
+  | 'entry_appended'
+  | 'day_created'
+  | 'feedback_generated'
+  | 'retro_saved'
+  | 'session_ended';
+
+/** Entry of hooks.json; the event payload is written to the command's stdin as JSON */
+export interface HookConfig {
+  name: string;
+  event: HookEvent;
+  command: string;
+  args: string[];
+  timeoutSeconds?: number | null; // default 10
+  enabled: boolean;
+}
+
+export interface HookRun {
+  name: string;
+  event: HookEvent;
+  command: string;
+  startedAt: string; // RFC 3339
+  durationMs: number;
+  exitCode?: number | null; // null when it failed to start, timed out or was killed
+  timedOut: boolean;
+  error?: string | null;
+  stderr: string; // tail
+}
+
+export interface CalendarSettings {
+  watchFolder?: string | null;
+  includeInFeedback: boolean;
+}
+
+export interface TimeTrackingSettings {
+  maxGapMinutes?: number | null; // null: no cap
+  defaultTailMinutes: number;
+  sleepStart?: string | null; // "HH:MM"
+  sleepEnd?: string | null; // "HH:MM"
+  sessionBoundaries: boolean;
 }
 
 // ============================================================================
@@ -188,10 +324,22 @@ export interface DailyEntry {
   aiFeedback?: string | null;
   retrospectContent?: string | null;
   categorizedTime: Record<string, number>; // category -> seconds
+  timeBreakdown: Record<string, TimeBreakdown>; // category -> measured/inferred split
+  untrackedSeconds: number;
+  entryCount: number;
+  calendarEvents: CalendarEvent[];
+  sessions: WorkSession[];
+}
+
+export interface TimeBreakdown {
+  measuredSeconds: number;
+  inferredSeconds: number;
 }
 
 export interface AggregatedStats {
   totalCategories: Record<string, number>; // category -> total seconds
+  timeBreakdown: Record<string, TimeBreakdown>;
+  untrackedSeconds: number;
   productivityVsWaste: ProductivityStats;
   dailyTrend: DailyTrend[];
 }
@@ -208,6 +356,53 @@ export interface DailyTrend {
   categories: Record<string, number>; // category -> seconds for this day
 }
 
+export interface GetPeriodDataPayload {
+  preset: 'week' | 'month' | 'year' | 'custom';
+  date: string; // YYYY-MM-DD: any day in the period, or start of a custom range
+  endDate?: string; // YYYY-MM-DD: end of a custom range (inclusive)
+  weekStartDay?: 'sunday' | 'monday';
+}
+
+export interface PeriodData {
+  preset: 'week' | 'month' | 'year' | 'custom';
+  startDate: string;
+  endDate: string;
+  daily: PeriodRollup[];
+  weekly: PeriodRollup[];
+  aggregatedStats: AggregatedStats;
+  comparison: PeriodComparison;
+}
+
+export interface PeriodRollup {
+  startDate: string;
+  endDate: string;
+  categories: Record<string, number>; // category -> seconds
+  totalSeconds: number;
+  untrackedSeconds: number;
+  productiveSeconds: number;
+  wasteSeconds: number;
+  entryCount: number;
+  feedbackDays: number; // days with at least one AI feedback
+}
+
+export interface PeriodComparison {
+  previousStartDate: string;
+  previousEndDate: string;
+  previousTotalCategories: Record<string, number>;
+  previousProductivity: ProductivityStats;
+  categoryDeltas: CategoryDelta[];
+  productivePercentageDelta: number; // percentage points
+  wastePercentageDelta: number; // percentage points
+}
+
+export interface CategoryDelta {
+  category: string;
+  currentSeconds: number;
+  previousSeconds: number;
+  deltaSeconds: number;
+  deltaPercentage?: number | null; // null when new in this period
+}
+
 export interface WeeklyActionItem {
   id: string;
   text: string;
@@ -224,6 +419,39 @@ export interface WeeklyActionsData {
   actions: WeeklyActionItem[];
 }
 
+// ============================================================================
+// Category Rule Types (src-tauri/src/models/category.rs)
+// ============================================================================
+
+export type ProductivityClass = 'productive' | 'neutral' | 'waste';
+
+export interface CategoryRule {
+  id: string; // empty string creates a new rule
+  name: string;
+  keywords: string[]; // matched at the start of a word
+  patterns: string[]; // case-insensitive regular expressions
+  priority: number; // higher runs first
+  productivity: ProductivityClass;
+  color?: string | null;
+  enabled: boolean;
+}
+
+export interface CategoryMatch {
+  category: string;
+  productivity: ProductivityClass;
+  ruleId?: string | null;
+  matchedBy?: string | null;
+  matchKind?: 'keyword' | 'pattern' | 'user' | 'llm' | null;
+}
+
+export interface EntryClassification {
+  text: string;
+  category: string;
+  source: 'llm' | 'user';
+  model?: string | null;
+  updatedAt: string;
+}
+
 // ============================================================================
 // Link Preview Types (src-tauri/src/link_preview.rs)
 // ============================================================================
@@ -292,6 +520,8 @@ export type TauriCommands = {
 
   // Weekly dashboard commands
   get_week_data: (payload: GetWeekDataPayload) => Promise<WeekData>;
+  get_period_data: (payload: GetPeriodDataPayload) => Promise<PeriodData>;
+  rebuild_stats_cache: () => Promise<number>;
   generate_weekly_summary: (weekData: WeekData) => Promise<void>;
   save_weekly_actions: (
     weekId: string,
@@ -299,6 +529,63 @@ export type TauriCommands = {
   ) => Promise<void>;
   get_weekly_actions: (weekId: string) => Promise<WeeklyActionsData | null>;
 
+  // Calendar commands
+  export_calendar_ics: (
+    startDate: string,
+    endDate: string,
+    mergeCategories?: boolean,
+    outputPath?: string
+  ) => Promise<string>;
+  import_calendar_file: (path: string) => Promise<CalendarSource>;
+  list_calendars: () => Promise<CalendarSource[]>;
+  remove_calendar: (name: string) => Promise<void>;
+  materialize_calendar_events: (date: string) => Promise<number>;
+
+  // Git commands
+  sync_git_commits: (date?: string) => Promise<GitSyncResult>;
+
+  // Work session commands
+  start_work_session: (title: string) => Promise<WorkSession | null>;
+  pause_work_session: () => Promise<WorkSession | null>;
+  resume_work_session: () => Promise<WorkSession | null>;
+  end_work_session: () => Promise<WorkSession | null>;
+  get_current_work_session: () => Promise<WorkSession | null>;
+  list_work_sessions: (date?: string) => Promise<WorkSession[]>;
+
+  // Focus timer commands
+  start_focus_timer: (intent: string, minutes?: number) => Promise<FocusTimer>;
+  start_focus_break: (minutes?: number) => Promise<FocusTimer>;
+  interrupt_focus_timer: (note?: string) => Promise<FocusTimer>;
+  stop_focus_timer: () => Promise<FocusRecord>;
+  get_focus_timer: () => Promise<FocusTimer | null>;
+
+  // Event hook commands
+  list_hooks: () => Promise<HookConfig[]>;
+  save_hooks: (hooks: HookConfig[]) => Promise<void>;
+  list_hook_runs: (limit?: number) => Promise<HookRun[]>;
+
+  // Snippet commands
+  list_snippets: () => Promise<Snippet[]>;
+  expand_snippet_text: (text: string) => Promise<string>;
+
+  // Category rule commands
+  list_category_rules: () => Promise<CategoryRule[]>;
+  save_category_rule: (rule: CategoryRule) => Promise<CategoryRule[]>;
+  save_category_rules: (rules: CategoryRule[]) => Promise<void>;
+  delete_category_rule: (ruleId: string) => Promise<CategoryRule[]>;
+  reset_category_rules: () => Promise<CategoryRule[]>;
+  test_categorize: (text: string) => Promise<CategoryMatch>;
+  categorize_entries_with_llm: (
+    startDate: string,
+    endDate: string
+  ) => Promise<number>;
+  list_entry_classifications: () => Promise<EntryClassification[]>;
+  override_entry_classification: (
+    text: string,
+    category: string
+  ) => Promise<EntryClassification>;
+  remove_entry_classification: (text: string) => Promise<void>;
+
   // Link preview commands
   fetch_link_metadata: (url: string) => Promise<LinkMetadata>;
 };